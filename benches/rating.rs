@@ -0,0 +1,95 @@
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use rate_my_sudoku::{StrategyResult, Sudoku};
+use std::hint::black_box;
+
+type Detector = fn(&Sudoku) -> StrategyResult;
+
+/// Puzzles from easy to beyond the implemented strategies.
+const CORPUS: [&str; 8] = [
+    "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+    "610040000090008273000705146100000000400609051005480900000010009740000300020000600",
+    "300000790906001000000579000000102000060000504048050001080400005052000009003800060",
+    "020000689000500001400000020100005708030016090000007003003002157009700842200800000",
+    "030120008000060000006000005020400500701800043058000100690080200010000000000610709",
+    "027004008100092000000000090500010700306200000002800004001000007403060152000000640",
+    "200100000000060000093007400000005000304601000500280013007340200002000039030010600",
+    "000100078100020000083000000304000200070201060008790500041005080090007006030000900",
+];
+
+/// Grids that take the backtracker many guesses.
+const HARD: [&str; 2] = [
+    "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    "000000010400000000020000000000050407008000300001090000300400200050100000000806000",
+];
+
+fn rating(c: &mut Criterion) {
+    c.bench_function("rate corpus", |b| {
+        b.iter_batched(
+            || CORPUS.map(Sudoku::from_string),
+            |mut sudokus| {
+                for sudoku in &mut sudokus {
+                    sudoku.solve_human_like();
+                    black_box(sudoku.difficulty());
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn detectors(c: &mut Criterion) {
+    let detectors: [(&str, Detector); 9] = [
+        ("last digit", Sudoku::find_last_digit),
+        ("obvious single", Sudoku::find_obvious_single),
+        ("hidden single", Sudoku::find_hidden_single),
+        ("pointing pair", Sudoku::find_pointing_pair),
+        ("claiming pair", Sudoku::find_claiming_pair),
+        ("obvious pair", Sudoku::find_obvious_pair),
+        ("hidden pair", Sudoku::find_hidden_pair),
+        ("x-wing", Sudoku::find_xwing),
+        ("law of leftovers", Sudoku::find_law_of_leftovers),
+    ];
+    let mut sudoku = Sudoku::from_string(CORPUS[CORPUS.len() - 1]);
+    sudoku.calc_all_notes();
+    let mut group = c.benchmark_group("detectors");
+    for (name, find) in detectors {
+        group.bench_function(name, |b| b.iter(|| find(black_box(&sudoku))));
+    }
+    group.finish();
+}
+
+fn backtracking(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backtracking");
+    for (i, grid) in HARD.iter().enumerate() {
+        let sudoku = Sudoku::from_string(grid);
+        group.bench_function(format!("hard {}", i + 1), |b| {
+            b.iter_batched(
+                || sudoku.clone(),
+                |mut sudoku| sudoku.solve_by_backtracking(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.bench_function("count solutions", |b| {
+        let sudoku = Sudoku::from_string(HARD[0]);
+        b.iter(|| black_box(&sudoku).count_solutions(2))
+    });
+    #[cfg(feature = "fast-verify")]
+    group.bench_function("verify unique, bit-parallel", |b| {
+        b.iter(|| rate_my_sudoku::verify_unique(black_box(HARD[0])))
+    });
+    group.finish();
+}
+
+fn parsing(c: &mut Criterion) {
+    c.bench_function("parse", |b| {
+        b.iter(|| {
+            for grid in CORPUS {
+                black_box(black_box(grid).parse::<Sudoku>().unwrap());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, rating, detectors, backtracking, parsing);
+criterion_main!(benches);
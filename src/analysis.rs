@@ -0,0 +1,306 @@
+//! Finding every applicable step at once, e.g. to measure how many techniques a
+//! position offers, and explaining why a candidate is impossible. The detectors report
+//! their steps to [`Hits`], which either stops them at the first step (for solving) or
+//! collects all of them (for analysis).
+
+use crate::parallel::find_first;
+use crate::{Candidate, Coord, EMPTY, Parity, Strategy, StrategyResult, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::ops::ControlFlow;
+
+/// Steps reported by a detector.
+pub(crate) struct Hits {
+    all: bool,
+    found: Vec<StrategyResult>,
+}
+
+/// A detector reporting its steps to [`Hits`].
+pub(crate) type Search = fn(&Sudoku, &mut Hits) -> ControlFlow<()>;
+
+impl Hits {
+    pub(crate) fn first() -> Hits {
+        Hits {
+            all: false,
+            found: Vec::new(),
+        }
+    }
+
+    pub(crate) fn all() -> Hits {
+        Hits {
+            all: true,
+            found: Vec::new(),
+        }
+    }
+
+    /// Record the step and leave an empty result of the same strategy in its place, so
+    /// that the detector can go on looking. Breaks if only the first step is wanted.
+    /// A step making the same changes as one recorded before, e.g. a hidden single
+    /// found in its row and again in its box, is only recorded once.
+    pub(crate) fn report(&mut self, result: &mut StrategyResult) -> ControlFlow<()> {
+        let empty = StrategyResult::new(result.strategy.clone());
+        let result = std::mem::replace(result, empty);
+        if !self.found.iter().any(|found| found.same_changes(&result)) {
+            self.found.push(result);
+        }
+        if self.all {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+
+    /// Run `search` on each item. If only the first step is wanted, the items are
+    /// searched in parallel with the `parallel` feature, still returning the step of the
+    /// first item that has one.
+    pub(crate) fn search_each<T: Send>(
+        &mut self,
+        items: Vec<T>,
+        search: impl Fn(T, &mut Hits) -> ControlFlow<()> + Sync + Send,
+    ) -> ControlFlow<()> {
+        if self.all {
+            for item in items {
+                search(item, self)?;
+            }
+            return ControlFlow::Continue(());
+        }
+        let first = find_first(items, |item| {
+            let mut hits = Hits::first();
+            let _ = search(item, &mut hits);
+            hits.found.pop()
+        });
+        match first {
+            Some(mut result) => self.report(&mut result),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+
+impl StrategyResult {
+    /// Whether both steps place the same digit, if any, and remove the same candidates.
+    fn same_changes(&self, other: &StrategyResult) -> bool {
+        self.removals.sets_cell == other.removals.sets_cell
+            && self.removals.candidates_about_to_be_removed
+                == other.removals.candidates_about_to_be_removed
+    }
+
+    /// Whether the step removes the candidate other than by placing it.
+    pub(crate) fn eliminates(&self, candidate: &Candidate) -> bool {
+        self.removals
+            .candidates_about_to_be_removed
+            .contains(candidate)
+            && self.removals.sets_cell.as_ref().is_none_or(|cell| {
+                (cell.row, cell.col, cell.num) != (candidate.row, candidate.col, candidate.num)
+            })
+    }
+}
+
+/// The detectors in the order the solver tries them.
+pub(crate) const SEARCHES: [(Strategy, Search); 19] = [
+    (Strategy::LastDigit, Sudoku::search_last_digit),
+    (Strategy::ObviousSingle, Sudoku::search_obvious_single),
+    (Strategy::HiddenSingle, Sudoku::search_hidden_single),
+    (Strategy::XV, Sudoku::search_xv),
+    (Strategy::Kropki, Sudoku::search_kropki),
+    (Strategy::Thermometer, Sudoku::search_thermometer),
+    (Strategy::Arrow, Sudoku::search_arrow),
+    (Strategy::CageCombination, Sudoku::search_cage_combination),
+    (Strategy::InniesOuties, Sudoku::search_innies_outies),
+    (Strategy::PointingPair, Sudoku::search_pointing_pair),
+    (Strategy::ClaimingPair, Sudoku::search_claiming_pair),
+    (Strategy::LawOfLeftovers, Sudoku::search_law_of_leftovers),
+    (Strategy::ObviousPair, Sudoku::search_obvious_pair),
+    (Strategy::HiddenPair, Sudoku::search_hidden_pair),
+    (Strategy::ObviousTriple, Sudoku::search_obvious_triple),
+    (Strategy::HiddenTriple, Sudoku::search_hidden_triple),
+    (Strategy::ObviousQuad, Sudoku::search_obvious_quad),
+    (Strategy::XWing, Sudoku::search_xwing),
+    (Strategy::HiddenQuad, Sudoku::search_hidden_quad),
+];
+
+/// Maximum number of steps leading up to the removal of a candidate in
+/// [`Sudoku::why_not`].
+const MAX_CHAIN_STEPS: usize = 20;
+
+/// Reason why a digit can't go into a cell.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Explanation {
+    /// The cell already holds the given digit
+    Filled(u8),
+    /// The odd/even marking of the cell excludes the digit
+    Parity(Parity),
+    /// The digit is already placed in the given cell, which shares a unit or a
+    /// constraint with the cell
+    SeenFrom(Coord),
+    /// Solving steps from the givens, the last one removing the candidate
+    Steps(Vec<StrategyResult>),
+}
+
+/// All steps applicable in a position, grouped by strategy.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Analysis {
+    /// Strategies with at least one step, easiest first, in the order the solver tries
+    /// them
+    pub strategies: Vec<(Strategy, Vec<StrategyResult>)>,
+}
+
+impl Analysis {
+    /// Steps of the strategy, empty if it doesn't apply.
+    pub fn steps(&self, strategy: &Strategy) -> &[StrategyResult] {
+        self.strategies
+            .iter()
+            .find(|(s, _)| s == strategy)
+            .map_or(&[], |(_, steps)| steps)
+    }
+
+    /// Number of different strategies that apply.
+    pub fn technique_count(&self) -> usize {
+        self.strategies.len()
+    }
+
+    /// Number of steps over all strategies.
+    pub fn step_count(&self) -> usize {
+        self.strategies.iter().map(|(_, steps)| steps.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strategies.is_empty()
+    }
+}
+
+impl Sudoku {
+    /// Run a detector until it finds its first step.
+    pub(crate) fn first_hit(&self, strategy: Strategy, search: Search) -> StrategyResult {
+        let mut hits = Hits::first();
+        let _ = search(self, &mut hits);
+        hits.found
+            .pop()
+            .unwrap_or_else(|| StrategyResult::new(strategy))
+    }
+
+    /// Find every step each strategy could take now, based on the current notes. Nothing
+    /// is changed, and no rating is recorded.
+    pub fn analyze(&self) -> Analysis {
+        let strategies = SEARCHES
+            .into_iter()
+            .filter_map(|(strategy, search)| {
+                let mut hits = Hits::all();
+                let _ = search(self, &mut hits);
+                (!hits.found.is_empty()).then_some((strategy, hits.found))
+            })
+            .collect();
+        Analysis { strategies }
+    }
+
+    /// Find a reason why `num` can't go into the cell, e.g. to check a player's notes.
+    /// Starting from the placed digits, the strategies are applied until one of them
+    /// removes the candidate, for at most 20 steps. Returns `None` if `num` is the
+    /// digit of the cell, or no reason is found.
+    pub fn why_not(&self, row: usize, col: usize, num: u8) -> Option<Explanation> {
+        if self.board[row][col] != EMPTY {
+            return (self.board[row][col] != num)
+                .then_some(Explanation::Filled(self.board[row][col]));
+        }
+        let parity = self.parity[row][col];
+        if !parity.allows(num) {
+            return Some(Explanation::Parity(parity));
+        }
+        let seen_from = self
+            .groups()
+            .into_iter()
+            .filter(|group| group.contains(row, col))
+            .flat_map(|group| group.cells)
+            .chain(self.constraint_peers(row, col))
+            .find(|&(r, c)| self.board[r][c] == num);
+        if let Some((r, c)) = seen_from {
+            return Some(Explanation::SeenFrom(Coord::new(r, c)));
+        }
+        let candidate = Candidate { row, col, num };
+        let mut sudoku = self.clone();
+        sudoku.calc_all_notes();
+        let mut steps = Vec::new();
+        while steps.len() < MAX_CHAIN_STEPS && sudoku.board[row][col] == EMPTY {
+            // Prefer the easiest step removing the candidate right away
+            let removing = sudoku
+                .analyze()
+                .strategies
+                .into_iter()
+                .flat_map(|(_, steps)| steps)
+                .find(|step| step.eliminates(&candidate));
+            if let Some(step) = removing {
+                steps.push(step);
+                return Some(Explanation::Steps(steps));
+            }
+            let step = sudoku.next_step();
+            if step.strategy == Strategy::None {
+                break;
+            }
+            sudoku
+                .apply(&step)
+                .expect("steps found on the board fit it");
+            steps.push(step);
+        }
+        None
+    }
+
+    /// Check whether placing `num` into the cell follows from the current position, e.g.
+    /// to tell a player whether their move was a deduction or a guess. Returns the
+    /// strategy of the easiest step placing the digit, or of an elimination step after
+    /// which the digit is a single in the cell or in one of its units. Expects the notes
+    /// to be up to date.
+    pub fn is_forced(&self, row: usize, col: usize, num: u8) -> Option<Strategy> {
+        if self.board[row][col] != EMPTY || !self.candidates[row][col].contains(&num) {
+            return None;
+        }
+        let steps: Vec<StrategyResult> = self
+            .analyze()
+            .strategies
+            .into_iter()
+            .flat_map(|(_, steps)| steps)
+            .collect();
+        let placing = steps.iter().find(|step| {
+            step.removals
+                .sets_cell
+                .as_ref()
+                .is_some_and(|cell| (cell.row, cell.col, cell.num) == (row, col, num))
+        });
+        if let Some(step) = placing {
+            return Some(step.strategy.clone());
+        }
+        steps
+            .iter()
+            .filter(|step| step.removals.sets_cell.is_none())
+            .find(|step| self.single_after(row, col, num, step))
+            .map(|step| step.strategy.clone())
+    }
+
+    /// Whether `num` is the only candidate left in the cell, or the only place left for
+    /// `num` in a unit containing the cell, once the eliminations of the step are done.
+    fn single_after(&self, row: usize, col: usize, num: u8, step: &StrategyResult) -> bool {
+        let removed = &step.removals.candidates_about_to_be_removed;
+        let remains = |row: usize, col: usize, num: u8| {
+            self.board[row][col] == EMPTY
+                && self.candidates[row][col].contains(&num)
+                && !removed.contains(&Candidate { row, col, num })
+        };
+        if !remains(row, col, num) {
+            return false;
+        }
+        let naked = self.candidates[row][col]
+            .iter()
+            .all(|other| other == num || !remains(row, col, other));
+        naked
+            || self
+                .complete_groups()
+                .into_iter()
+                .filter(|group| group.contains(row, col))
+                .any(|group| {
+                    group
+                        .cells
+                        .iter()
+                        .all(|&(r, c)| (r, c) == (row, col) || !remains(r, c, num))
+                })
+    }
+}
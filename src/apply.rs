@@ -0,0 +1,112 @@
+//! Applying solver steps to the board, after checking that they fit the position.
+
+use crate::{BoardDiff, Candidate, Cell, Coord, EMPTY, Resolution, StrategyResult, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+/// Reason why a step can't be applied to the current position, e.g. because it was
+/// found on another board or the board changed since.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum ApplyError {
+    /// A cell of the step lies outside the grid
+    OutOfBounds(Coord),
+    /// The candidate to be removed isn't a note of its cell
+    MissingCandidate(Candidate),
+    /// The digit to be placed isn't in the range of the grid
+    InvalidDigit(Cell),
+    /// The cell to be placed in already holds a digit
+    Occupied(Cell),
+}
+
+impl fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApplyError::OutOfBounds(coord) => write!(f, "Cell {} lies outside the grid", coord),
+            ApplyError::MissingCandidate(candidate) => write!(
+                f,
+                "{} isn't a candidate of {}",
+                candidate.num,
+                candidate.coord()
+            ),
+            ApplyError::InvalidDigit(cell) => write!(f, "Invalid digit {}", cell.num),
+            ApplyError::Occupied(cell) => {
+                write!(f, "Cell {} already holds a digit", cell.coord())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApplyError {}
+
+impl Sudoku {
+    fn check_in_grid(&self, row: usize, col: usize) -> Result<(), ApplyError> {
+        if row >= self.size() || col >= self.size() {
+            return Err(ApplyError::OutOfBounds(Coord::new(row, col)));
+        }
+        Ok(())
+    }
+
+    /// Check that the step fits the current position: every candidate it removes is
+    /// a note of its cell, and the cell it places a digit in is empty.
+    pub fn validate(&self, strategy_result: &StrategyResult) -> Result<(), ApplyError> {
+        let removals = &strategy_result.removals;
+        for candidate in &removals.candidates_about_to_be_removed {
+            self.check_in_grid(candidate.row, candidate.col)?;
+            if !self.candidates[candidate.row][candidate.col].contains(&candidate.num) {
+                return Err(ApplyError::MissingCandidate(candidate.clone()));
+            }
+        }
+        if let Some(cell) = &removals.sets_cell {
+            self.check_in_grid(cell.row, cell.col)?;
+            if cell.num == EMPTY || cell.num as usize > self.size() {
+                return Err(ApplyError::InvalidDigit(cell.clone()));
+            }
+            if self.board[cell.row][cell.col] != EMPTY {
+                return Err(ApplyError::Occupied(cell.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// What applying the step would change, without changing anything.
+    pub fn dry_run(&self, strategy_result: &StrategyResult) -> Result<BoardDiff, ApplyError> {
+        self.validate(strategy_result)?;
+        let removals = &strategy_result.removals;
+        let mut removed_candidates: Vec<Candidate> = removals
+            .candidates_about_to_be_removed
+            .iter()
+            .cloned()
+            .collect();
+        removed_candidates.sort_by_key(|candidate| (candidate.row, candidate.col, candidate.num));
+        Ok(BoardDiff {
+            placed: removals.sets_cell.iter().cloned().collect(),
+            removed_candidates,
+            ..BoardDiff::default()
+        })
+    }
+
+    /// Apply the strategy result to the Sudoku board. Nothing is changed if the step
+    /// doesn't fit the current position, see [`Sudoku::validate`].
+    pub fn apply(&mut self, strategy_result: &StrategyResult) -> Result<Resolution, ApplyError> {
+        log::info!("Applying strategy: {:?}", strategy_result.strategy);
+        self.validate(strategy_result)?;
+        self.push_undo();
+        let result = Resolution {
+            nums_removed: strategy_result
+                .removals
+                .candidates_about_to_be_removed
+                .len(),
+            strategy: strategy_result.strategy.clone(),
+        };
+        for note in &strategy_result.removals.candidates_about_to_be_removed {
+            self.candidates[note.row][note.col].remove(&note.num);
+        }
+        if let Some(cell) = &strategy_result.removals.sets_cell {
+            self.board[cell.row][cell.col] = cell.num;
+            self.add_to_rating(&strategy_result.strategy, 1);
+        }
+        Ok(result)
+    }
+}
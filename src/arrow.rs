@@ -0,0 +1,182 @@
+//! Arrow Sudoku: the digit in the circle equals the sum of the digits along its arrow.
+
+use crate::analysis::Hits;
+use crate::coord::parse_cell;
+use crate::{Candidate, DigitSet, EMPTY, Strategy, StrategyResult, Sudoku, SudokuError};
+use std::ops::ControlFlow;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arrow {
+    pub circle: (usize, usize),
+    /// Cells along the arrow, starting next to the circle
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Arrow {
+    /// Parse arrows given as whitespace separated paths of cells joined by `-`, starting
+    /// with the circle, e.g. `r1c1-r1c2-r2c3 r9c9-r8c9`. Consecutive cells must touch,
+    /// diagonally or orthogonally.
+    pub fn parse_arrows(s: &str) -> Result<Vec<Arrow>, SudokuError> {
+        s.split_whitespace()
+            .map(|path| {
+                let cells = path
+                    .split('-')
+                    .map(|cell| {
+                        parse_cell(cell)
+                            .ok_or_else(|| SudokuError::Parse(format!("Invalid cell '{}'", cell)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if cells.len() < 2 {
+                    return Err(SudokuError::Parse(format!("Arrow '{}' is too short", path)));
+                }
+                let touching = cells.windows(2).all(|pair| {
+                    let (a, b) = (pair[0], pair[1]);
+                    a != b && a.0.abs_diff(b.0) <= 1 && a.1.abs_diff(b.1) <= 1
+                });
+                if !touching {
+                    return Err(SudokuError::Parse(format!(
+                        "Cells of arrow '{}' don't touch",
+                        path
+                    )));
+                }
+                Ok(Arrow {
+                    circle: cells[0],
+                    cells: cells[1..].to_vec(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Sudoku {
+    pub fn set_arrows(&mut self, arrows: Vec<Arrow>) {
+        self.arrows = arrows;
+    }
+
+    /// Parse arrows with [`Arrow::parse_arrows`] and attach them to the Sudoku.
+    pub fn set_arrows_string(&mut self, s: &str) -> Result<(), SudokuError> {
+        let arrows = Arrow::parse_arrows(s)?;
+        let size = self.size();
+        if arrows
+            .iter()
+            .flat_map(|arrow| std::iter::once(&arrow.circle).chain(arrow.cells.iter()))
+            .any(|&(row, col)| row >= size || col >= size)
+        {
+            return Err(SudokuError::InvalidBoard(
+                "Arrow lies outside the grid".to_string(),
+            ));
+        }
+        self.arrows = arrows;
+        Ok(())
+    }
+
+    /// Check if `num` in the given cell keeps the sums of all arrows through it
+    /// reachable, assuming every empty cell on an arrow takes at least a 1.
+    pub(crate) fn arrows_allow(&self, row: usize, col: usize, num: u8) -> bool {
+        let digit = |cell: (usize, usize)| {
+            if cell == (row, col) {
+                num
+            } else {
+                self.board[cell.0][cell.1]
+            }
+        };
+        for arrow in &self.arrows {
+            if arrow.circle != (row, col) && !arrow.cells.contains(&(row, col)) {
+                continue;
+            }
+            let circle = digit(arrow.circle) as usize;
+            let mut sum = 0;
+            let mut empty = 0;
+            for &cell in &arrow.cells {
+                match digit(cell) {
+                    EMPTY => empty += 1,
+                    d => sum += d as usize,
+                }
+            }
+            let max = if circle == EMPTY as usize {
+                self.size()
+            } else {
+                circle
+            };
+            if sum + empty > max || (empty == 0 && circle != EMPTY as usize && sum != circle) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Narrow down the candidates on arrows: the circle must lie between the smallest
+    /// and largest possible sum along the arrow, and each cell on the arrow must leave
+    /// a sum that some digit in the circle can match.
+    pub fn find_arrow(&self) -> StrategyResult {
+        self.first_hit(Strategy::Arrow, Sudoku::search_arrow)
+    }
+
+    pub(crate) fn search_arrow(&self, hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::Arrow);
+        let digits_of = |(row, col): (usize, usize)| -> DigitSet {
+            if self.board[row][col] != EMPTY {
+                DigitSet::from([self.board[row][col]])
+            } else {
+                self.candidates[row][col]
+            }
+        };
+        for arrow in &self.arrows {
+            let circle_digits = digits_of(arrow.circle);
+            let cell_digits: Vec<DigitSet> =
+                arrow.cells.iter().map(|&cell| digits_of(cell)).collect();
+            if circle_digits.is_empty() || cell_digits.iter().any(|digits| digits.is_empty()) {
+                continue;
+            }
+            let lows: Vec<usize> = cell_digits
+                .iter()
+                .map(|digits| digits.iter().min().unwrap() as usize)
+                .collect();
+            let highs: Vec<usize> = cell_digits
+                .iter()
+                .map(|digits| digits.iter().max().unwrap() as usize)
+                .collect();
+            let min_sum: usize = lows.iter().sum();
+            let max_sum: usize = highs.iter().sum();
+            let (row, col) = arrow.circle;
+            if self.board[row][col] == EMPTY {
+                for num in self.candidates[row][col] {
+                    if (num as usize) < min_sum || num as usize > max_sum {
+                        result
+                            .removals
+                            .candidates_about_to_be_removed
+                            .insert(Candidate { row, col, num });
+                    }
+                }
+            }
+            for (i, &(row, col)) in arrow.cells.iter().enumerate() {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                // Range of the sum of the other cells on the arrow
+                let others_min = min_sum - lows[i];
+                let others_max = max_sum - highs[i];
+                for num in self.candidates[row][col] {
+                    let fits = circle_digits.iter().any(|c| {
+                        let c = c as usize;
+                        c >= num as usize + others_min && c <= num as usize + others_max
+                    });
+                    if !fits {
+                        result
+                            .removals
+                            .candidates_about_to_be_removed
+                            .insert(Candidate { row, col, num });
+                    }
+                }
+            }
+            if result.removals.will_remove_candidates() {
+                self.add_pattern_cells(
+                    &mut result.removals,
+                    std::iter::once(arrow.circle).chain(arrow.cells.iter().cloned()),
+                );
+                hits.report(&mut result)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
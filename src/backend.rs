@@ -0,0 +1,34 @@
+//! Brute-force solving behind a trait, so that the uniqueness checks and the
+//! completion of puzzles beyond the strategies can use another solver, e.g. Dancing
+//! Links on [`Sudoku::exact_cover`], a SAT solver on [`Sudoku::to_dimacs`], or a solver
+//! in another language.
+
+use crate::Sudoku;
+use std::fmt;
+
+/// Finds the solutions of a Sudoku by search. Implementations must respect all rules
+/// of the Sudoku, including its variant clues, or reject the solutions breaking them.
+pub trait BruteForceBackend: fmt::Debug + Send + Sync {
+    /// Up to `max_count` boards completing the digits of the Sudoku, one row of digits
+    /// after the other. The notes don't matter.
+    fn solutions(&self, sudoku: &Sudoku, max_count: usize) -> Vec<Vec<Vec<u8>>>;
+
+    /// Number of solutions, counted up to `max_count`.
+    fn count_solutions(&self, sudoku: &Sudoku, max_count: usize) -> usize {
+        self.solutions(sudoku, max_count).len()
+    }
+}
+
+/// The built-in recursive backtracker, see [`Sudoku::solutions`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Backtracking;
+
+impl BruteForceBackend for Backtracking {
+    fn solutions(&self, sudoku: &Sudoku, max_count: usize) -> Vec<Vec<Vec<u8>>> {
+        sudoku.solutions().take(max_count).collect()
+    }
+
+    fn count_solutions(&self, sudoku: &Sudoku, max_count: usize) -> usize {
+        sudoku.count_solutions(max_count)
+    }
+}
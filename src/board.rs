@@ -0,0 +1,425 @@
+//! The board with its digits and notes, and the rules they have to follow.
+
+use crate::{
+    Arrow, Cage, Constraints, Coord, DigitSet, Edge, GridSpec, Metadata, Parity, SearchOrder,
+    Strategy, Thermometer,
+};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+pub const EMPTY: u8 = 0;
+/// Digits of a classic 9×9 Sudoku
+pub const ALL_DIGITS: DigitSet = DigitSet::all(9);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Candidate {
+    pub row: usize,
+    pub col: usize,
+    pub num: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Cell {
+    pub row: usize,
+    pub col: usize,
+    pub num: u8,
+}
+
+/// Digits of a grid and which of them are givens, without the notes, rating and rules
+/// the solver works with. A [`Sudoku`] is created from a board with
+/// [`Sudoku::from_board`], which leaves the board untouched, so the solver can be run
+/// on the same board any number of times.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Board {
+    pub spec: GridSpec,
+    /// Digit of each cell, or [`EMPTY`]
+    pub values: Vec<Vec<u8>>,
+    /// Whether each cell holds a given
+    pub givens: Vec<Vec<bool>>,
+    /// ID, source and the like, passed on to the [`Sudoku`] and its rating
+    pub metadata: Metadata,
+}
+
+impl Board {
+    /// Create an empty board of the given grid size.
+    pub fn new(spec: GridSpec) -> Board {
+        let size = spec.size();
+        Board {
+            spec,
+            values: vec![vec![EMPTY; size]; size],
+            givens: vec![vec![false; size]; size],
+            metadata: Metadata::new(),
+        }
+    }
+
+    /// Create a board whose digits are all givens.
+    pub fn from_givens(spec: GridSpec, values: Vec<Vec<u8>>) -> Board {
+        let givens = values
+            .iter()
+            .map(|row| row.iter().map(|&num| num != EMPTY).collect())
+            .collect();
+        Board {
+            spec,
+            values,
+            givens,
+            metadata: Metadata::new(),
+        }
+    }
+
+    /// The board with the metadata attached.
+    pub fn with_metadata(self, metadata: Metadata) -> Board {
+        Board { metadata, ..self }
+    }
+
+    /// Number of rows, columns, boxes and digits.
+    pub fn size(&self) -> usize {
+        self.spec.size()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> u8 {
+        self.values[row][col]
+    }
+
+    pub fn is_given(&self, row: usize, col: usize) -> bool {
+        self.givens[row][col]
+    }
+
+    /// Every cell with its digit, or [`EMPTY`], in row-major order.
+    pub fn cells(&self) -> impl Iterator<Item = (Coord, u8)> + '_ {
+        self.values.iter().enumerate().flat_map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .map(move |(col, &num)| (Coord::new(row, col), num))
+        })
+    }
+
+    /// The givens alone, with all other cells empty.
+    pub fn given_values(&self) -> Vec<Vec<u8>> {
+        self.values
+            .iter()
+            .zip(&self.givens)
+            .map(|(values, givens)| {
+                values
+                    .iter()
+                    .zip(givens)
+                    .map(|(&num, &given)| if given { num } else { EMPTY })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Sudoku {
+    pub spec: GridSpec,
+    pub board: Vec<Vec<u8>>,
+    pub original_board: Vec<Vec<u8>>,
+    pub candidates: Vec<Vec<DigitSet>>,
+    pub rating: BTreeMap<Strategy, usize>,
+    pub undo_stack: Vec<Sudoku>,
+    pub cages: Vec<Cage>,
+    /// XV and Kropki clues between adjacent cells
+    pub edges: Vec<Edge>,
+    pub thermometers: Vec<Thermometer>,
+    pub arrows: Vec<Arrow>,
+    /// Additional sets of cells whose digits must all differ
+    pub custom_units: Vec<Vec<(usize, usize)>>,
+    /// Index of the box (or jigsaw region) each cell belongs to
+    pub regions: Vec<Vec<usize>>,
+    /// Cells of each box (or jigsaw region), in row-major order
+    pub region_cells: Vec<Vec<(usize, usize)>>,
+    pub constraints: Constraints,
+    /// Odd/even restriction of each cell
+    pub parity: Vec<Vec<Parity>>,
+    /// Order in which the strategies are tried
+    pub search_order: SearchOrder,
+    /// ID, source and the like, which the solver leaves alone
+    pub metadata: Metadata,
+}
+
+impl Default for Sudoku {
+    fn default() -> Self {
+        Sudoku::new()
+    }
+}
+
+impl Sudoku {
+    pub fn new() -> Sudoku {
+        Sudoku::with_spec(GridSpec::CLASSIC)
+    }
+
+    /// Create an empty Sudoku of the given grid size.
+    pub fn with_spec(spec: GridSpec) -> Sudoku {
+        let size = spec.size();
+        Sudoku {
+            spec,
+            board: vec![vec![EMPTY; size]; size],
+            original_board: vec![vec![EMPTY; size]; size],
+            candidates: vec![vec![DigitSet::new(); size]; size],
+            rating: BTreeMap::new(),
+            undo_stack: Vec::new(),
+            cages: Vec::new(),
+            edges: Vec::new(),
+            thermometers: Vec::new(),
+            arrows: Vec::new(),
+            custom_units: Vec::new(),
+            regions: spec.standard_regions(),
+            region_cells: spec.standard_region_cells(),
+            constraints: Constraints::NONE,
+            parity: vec![vec![Parity::Any; size]; size],
+            search_order: SearchOrder::default(),
+            metadata: Metadata::new(),
+        }
+    }
+
+    /// Create a Sudoku to solve from the board. Cells that aren't givens keep their
+    /// digits as progress made so far.
+    pub fn from_board(board: &Board) -> Sudoku {
+        let mut sudoku = Sudoku::with_spec(board.spec);
+        sudoku.board = board.values.clone();
+        sudoku.original_board = board.given_values();
+        sudoku.metadata = board.metadata.clone();
+        sudoku
+    }
+
+    /// The Sudoku with the metadata attached.
+    pub fn with_metadata(self, metadata: Metadata) -> Sudoku {
+        Sudoku { metadata, ..self }
+    }
+
+    /// The digits and givens of the Sudoku, without notes and rating.
+    pub fn to_board(&self) -> Board {
+        Board {
+            spec: self.spec,
+            values: self.board.clone(),
+            givens: self
+                .original_board
+                .iter()
+                .map(|row| row.iter().map(|&num| num != EMPTY).collect())
+                .collect(),
+            metadata: self.metadata.clone(),
+        }
+    }
+
+    /// Number of rows, columns, boxes and digits.
+    pub fn size(&self) -> usize {
+        self.spec.size()
+    }
+
+    pub fn clear(&mut self) {
+        let size = self.size();
+        self.candidates = vec![vec![DigitSet::new(); size]; size];
+        self.board = vec![vec![EMPTY; size]; size];
+        self.rating.clear();
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(state) = self.undo_stack.pop() {
+            self.board = state.board;
+            self.candidates = state.candidates;
+            self.rating = state.rating;
+        }
+    }
+
+    /// Check if the cell holds one of the digits the puzzle started with.
+    pub fn is_given(&self, row: usize, col: usize) -> bool {
+        self.original_board[row][col] != EMPTY
+    }
+
+    pub fn givens_count(&self) -> usize {
+        self.given_cells().count()
+    }
+
+    fn cells_where(&self, pred: impl Fn(usize, usize) -> bool) -> impl Iterator<Item = Coord> {
+        let size = self.size();
+        (0..size * size)
+            .map(move |idx| Coord::new(idx / size, idx % size))
+            .filter(move |coord| pred(coord.row, coord.col))
+    }
+
+    /// Every cell with its digit, or [`EMPTY`], in row-major order.
+    pub fn cells(&self) -> impl Iterator<Item = (Coord, u8)> + '_ {
+        self.cells_where(|_, _| true)
+            .map(|coord| (coord, self.board[coord.row][coord.col]))
+    }
+
+    /// Cells holding a given, in row-major order.
+    pub fn given_cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells_where(|row, col| self.is_given(row, col))
+    }
+
+    /// Cells without a digit, in row-major order.
+    pub fn empty_cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells_where(|row, col| self.board[row][col] == EMPTY)
+    }
+
+    /// Cells filled in while solving, i.e. holding a digit that isn't a given, in
+    /// row-major order.
+    pub fn solved_cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells_where(|row, col| self.board[row][col] != EMPTY && !self.is_given(row, col))
+    }
+
+    pub fn unsolved(&self) -> bool {
+        self.board.iter().any(|row| row.contains(&EMPTY))
+    }
+
+    pub fn is_solved(&self) -> bool {
+        !self.unsolved()
+    }
+
+    pub fn calc_all_notes(&mut self) {
+        let size = self.size();
+        for row in 0..size {
+            for col in 0..size {
+                if self.board[row][col] == EMPTY {
+                    let parity = self.parity[row][col];
+                    self.candidates[row][col] =
+                        (1..=size as u8).filter(|&num| parity.allows(num)).collect();
+                }
+            }
+        }
+        // Remove the digits already placed in a unit from the notes of its empty cells
+        for group in self.groups() {
+            let nums: Vec<u8> = group
+                .cells
+                .iter()
+                .map(|&(row, col)| self.board[row][col])
+                .filter(|&num| num != EMPTY)
+                .collect();
+            for &(row, col) in &group.cells {
+                if self.board[row][col] == EMPTY {
+                    for num in &nums {
+                        self.candidates[row][col].remove(num);
+                    }
+                }
+            }
+        }
+        for row in 0..size {
+            for col in 0..size {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                for (r, c) in self.constraint_peers(row, col) {
+                    let num = self.board[r][c];
+                    self.candidates[row][col].remove(&num);
+                }
+            }
+        }
+    }
+
+    /// Check if `num` can be placed in row `row` and column `col`
+    pub fn can_place(&self, row: usize, col: usize, num: u8) -> bool {
+        if self.board[row][col] != EMPTY || !self.parity[row][col].allows(num) {
+            return false;
+        }
+        for i in 0..self.size() {
+            // this is faster than using `nums_in_row`, `nums_in_col`, and `nums_in_box`
+            // because these sets have to be recalculated every time a number is placed,
+            // and backtracked when a number is removed
+            if self.board[row][i] == num {
+                return false;
+            }
+            if self.board[i][col] == num {
+                return false;
+            }
+            let (box_row, box_col) = self.region_cells[self.regions[row][col]][i];
+            if self.board[box_row][box_col] == num {
+                return false;
+            }
+        }
+        if !self.cages.is_empty() && !self.cage_allows(row, col, num) {
+            return false;
+        }
+        if self.extra_groups().iter().any(|group| {
+            group.contains(row, col) && group.cells.iter().any(|&(r, c)| self.board[r][c] == num)
+        }) {
+            return false;
+        }
+        if !self.edges_allow(row, col, num)
+            || !self.thermometers_allow(row, col, num)
+            || !self.arrows_allow(row, col, num)
+        {
+            return false;
+        }
+        if !self.constraints.is_empty()
+            && self
+                .constraint_peers(row, col)
+                .iter()
+                .any(|&(r, c)| self.board[r][c] == num)
+        {
+            return false;
+        }
+        true
+    }
+
+    /// Check if any of the filled-in digits clash with another one in the same unit,
+    /// or break one of the variant rules.
+    pub fn has_conflicts(&self) -> bool {
+        for group in self.groups() {
+            let mut seen = DigitSet::new();
+            for &(row, col) in &group.cells {
+                let num = self.board[row][col];
+                if num != EMPTY && !seen.insert(num) {
+                    return true;
+                }
+            }
+        }
+        for (Coord { row, col }, num) in self.cells() {
+            if num == EMPTY {
+                continue;
+            }
+            if !self.parity[row][col].allows(num) {
+                return true;
+            }
+            if self
+                .constraint_peers(row, col)
+                .iter()
+                .any(|&(r, c)| self.board[r][c] == num)
+            {
+                return true;
+            }
+            if !self.edges_allow(row, col, num)
+                || !self.thermometers_allow(row, col, num)
+                || !self.arrows_allow(row, col, num)
+            {
+                return true;
+            }
+        }
+        for cage in &self.cages {
+            let sum: u32 = cage
+                .cells
+                .iter()
+                .map(|&(row, col)| self.board[row][col] as u32)
+                .sum();
+            let complete = cage
+                .cells
+                .iter()
+                .all(|&(row, col)| self.board[row][col] != EMPTY);
+            if sum > cage.sum || (complete && sum != cage.sum) {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn get_num(&self, row: usize, col: usize) -> u8 {
+        self.board[row][col]
+    }
+
+    #[allow(dead_code)]
+    pub fn get_notes(&self, row: usize, col: usize) -> DigitSet {
+        self.candidates[row][col]
+    }
+
+    /// Save the current state so that the next change can be undone.
+    pub(crate) fn push_undo(&mut self) {
+        let mut clone = self.clone();
+        clone.undo_stack = Vec::new(); // Don't clone the undo stack
+        self.undo_stack.push(clone);
+    }
+}
@@ -0,0 +1,259 @@
+//! Fitting the efforts of the strategies to an external difficulty scale, e.g. the
+//! ratings of another solver or of a puzzle community.
+
+use crate::{Strategy, Sudoku, SudokuError};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Pull of the built-in efforts on the fitted ones, relative to the number of puzzles.
+/// Keeps strategies that are rare in the reference set close to their scaled efforts.
+const REGULARIZATION: f64 = 1e-2;
+
+/// Effort of each strategy, as used to compute the difficulty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weights {
+    efforts: BTreeMap<Strategy, f64>,
+}
+
+impl Default for Weights {
+    /// The built-in efforts, see [`Sudoku::difficulty`].
+    fn default() -> Self {
+        Weights {
+            efforts: Strategy::ALL
+                .iter()
+                .map(|strategy| (strategy.clone(), strategy.difficulty() as f64))
+                .collect(),
+        }
+    }
+}
+
+impl Weights {
+    pub fn effort(&self, strategy: &Strategy) -> f64 {
+        self.efforts.get(strategy).copied().unwrap_or_default()
+    }
+
+    pub fn set_effort(&mut self, strategy: Strategy, effort: f64) {
+        self.efforts.insert(strategy, effort);
+    }
+}
+
+impl fmt::Display for Weights {
+    /// The weights profile: one line per strategy with its identifier and effort.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for strategy in &Strategy::ALL {
+            writeln!(f, "{:?} {}", strategy, self.effort(strategy))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Weights {
+    type Err = String;
+    /// Parse a weights profile as written by [`Weights`]'s `Display`. Strategies not
+    /// mentioned keep their built-in effort. Empty lines and lines starting with `#`
+    /// are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = Weights::default();
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, effort) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("Invalid line in weights profile: {}", line))?;
+            let strategy = Strategy::ALL
+                .iter()
+                .find(|strategy| format!("{:?}", strategy) == name)
+                .ok_or_else(|| format!("Unknown strategy: {}", name))?;
+            let effort = effort
+                .trim()
+                .parse::<f64>()
+                .map_err(|e| format!("Invalid effort for {}: {}", name, e))?;
+            weights.set_effort(strategy.clone(), effort);
+        }
+        Ok(weights)
+    }
+}
+
+/// A puzzle with a difficulty on the reference scale.
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub puzzle: String,
+    pub rating: f64,
+}
+
+/// Parse a reference set: one puzzle per line, preceded by its rating and whitespace.
+/// Empty lines and lines starting with `#` are ignored.
+pub fn parse_references(s: &str) -> Result<Vec<Reference>, SudokuError> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (rating, puzzle) = line.split_once(char::is_whitespace).ok_or_else(|| {
+                SudokuError::Parse(format!("Invalid line in reference set: {}", line))
+            })?;
+            let rating = rating
+                .parse::<f64>()
+                .map_err(|e| SudokuError::Parse(format!("Invalid rating {}: {}", rating, e)))?;
+            Ok(Reference {
+                puzzle: puzzle.trim().to_string(),
+                rating,
+            })
+        })
+        .collect()
+}
+
+/// Result of fitting the efforts to a reference set.
+#[derive(Debug, Clone)]
+pub struct Calibration {
+    pub weights: Weights,
+    /// Number of reference puzzles the human-like solver could solve, except complete
+    /// grids, which are the ones the fit is based on
+    pub puzzles: usize,
+    /// Pearson correlation between the reference ratings and the difficulties with the
+    /// built-in efforts
+    pub correlation_before: f64,
+    /// The same correlation with the fitted efforts
+    pub correlation_after: f64,
+}
+
+impl Sudoku {
+    /// Difficulty as in [`Sudoku::difficulty`], but with the given efforts.
+    pub fn difficulty_with(&self, weights: &Weights) -> f64 {
+        let candidates_removed = self.rating.values().sum::<usize>();
+        let total: f64 = self
+            .rating
+            .iter()
+            .map(|(strategy, &count)| weights.effort(strategy) * count as f64)
+            .sum();
+        total / candidates_removed as f64
+    }
+}
+
+/// Fit the efforts so that the difficulty approximates the reference ratings, by least
+/// squares. The difficulty is the average effort per removed candidate, so it's linear in
+/// the efforts. Strategies missing from the reference set keep their built-in efforts,
+/// scaled to the reference scale. Puzzles the strategies don't solve are left out, and
+/// so are complete grids, which have no difficulty.
+pub fn calibrate(references: &[Reference]) -> Result<Calibration, SudokuError> {
+    // Share of removed candidates per strategy, and the reference rating
+    let mut shares: Vec<Vec<f64>> = Vec::new();
+    let mut ratings: Vec<f64> = Vec::new();
+    for reference in references {
+        let mut sudoku = reference
+            .puzzle
+            .parse::<Sudoku>()
+            .map_err(SudokuError::Parse)?;
+        if !sudoku.solve_human_like().is_solved() {
+            continue;
+        }
+        let total = sudoku.rating.values().sum::<usize>() as f64;
+        if total == 0.0 {
+            continue;
+        }
+        shares.push(
+            Strategy::ALL
+                .iter()
+                .map(|strategy| {
+                    sudoku.rating.get(strategy).copied().unwrap_or_default() as f64 / total
+                })
+                .collect(),
+        );
+        ratings.push(reference.rating);
+    }
+    if ratings.len() < 2 {
+        return Err(SudokuError::InvalidBoard(
+            "Not enough solvable puzzles in the reference set".to_string(),
+        ));
+    }
+    let difficulties = |weights: &[f64]| -> Vec<f64> {
+        shares
+            .iter()
+            .map(|share| share.iter().zip(weights).map(|(s, w)| s * w).sum())
+            .collect()
+    };
+    let built_in: Vec<f64> = Strategy::ALL
+        .iter()
+        .map(|strategy| strategy.difficulty() as f64)
+        .collect();
+    let before = difficulties(&built_in);
+    // Scale of the reference ratings relative to the built-in difficulties
+    let scale = before.iter().zip(&ratings).map(|(d, r)| d * r).sum::<f64>()
+        / before.iter().map(|d| d * d).sum::<f64>();
+    let prior: Vec<f64> = built_in.iter().map(|effort| effort * scale).collect();
+    // Ridge regression towards the scaled efforts: (SᵀS + λI) w = Sᵀr + λ·prior
+    let n = Strategy::ALL.len();
+    let lambda = REGULARIZATION * ratings.len() as f64;
+    let mut matrix = vec![vec![0.0; n + 1]; n];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for j in 0..n {
+            row[j] = shares.iter().map(|share| share[i] * share[j]).sum();
+        }
+        row[i] += lambda;
+        row[n] = shares
+            .iter()
+            .zip(&ratings)
+            .map(|(share, rating)| share[i] * rating)
+            .sum::<f64>()
+            + lambda * prior[i];
+    }
+    let fitted = solve_linear(matrix)
+        .ok_or_else(|| SudokuError::InvalidBoard("Reference set can't be fitted".to_string()))?;
+    let mut weights = Weights::default();
+    for (strategy, &effort) in Strategy::ALL.iter().zip(&fitted) {
+        weights.set_effort(strategy.clone(), effort);
+    }
+    Ok(Calibration {
+        weights,
+        puzzles: ratings.len(),
+        correlation_before: correlation(&before, &ratings),
+        correlation_after: correlation(&difficulties(&fitted), &ratings),
+    })
+}
+
+/// Solve the linear system given as augmented matrix by Gaussian elimination with
+/// partial pivoting.
+fn solve_linear(mut matrix: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let n = matrix.len();
+    for col in 0..n {
+        let pivot =
+            (col..n).max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))?;
+        if matrix[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        let (upper, lower) = matrix.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    let mut solution = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (matrix[row][n] - sum) / matrix[row][row];
+    }
+    Some(solution)
+}
+
+/// Pearson correlation coefficient, 0 if one of the series is constant.
+pub(crate) fn correlation(xs: &[f64], ys: &[f64]) -> f64 {
+    let n = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = ys.iter().sum::<f64>() / n;
+    let cov: f64 = xs
+        .iter()
+        .zip(ys)
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let var_x: f64 = xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+    let var_y: f64 = ys.iter().map(|y| (y - mean_y).powi(2)).sum();
+    if var_x == 0.0 || var_y == 0.0 {
+        0.0
+    } else {
+        cov / (var_x * var_y).sqrt()
+    }
+}
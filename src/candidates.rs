@@ -0,0 +1,78 @@
+//! Queries on the notes that chain strategies and analysis tools need again and again:
+//! how many candidates are left, which cells have two or three, and where a digit can
+//! still go.
+
+use crate::{Coord, Sudoku, Unit};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// The only two cells of a unit that have a digit as a candidate, so that the digit
+/// goes into one of them: a strong link between the two candidates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ConjugatePair {
+    pub unit: Unit,
+    /// Index of the unit among those of its kind, counted from 0
+    pub index: usize,
+    pub num: u8,
+    /// The two cells, in the order of the unit
+    pub cells: [Coord; 2],
+}
+
+impl Sudoku {
+    /// Number of candidates left in the empty cells.
+    pub fn candidate_count(&self) -> usize {
+        self.empty_cells()
+            .map(|c| self.candidates[c.row][c.col].len())
+            .sum()
+    }
+
+    /// Empty cells with exactly `count` candidates, in row-major order.
+    fn cells_with_candidate_count(&self, count: usize) -> impl Iterator<Item = Coord> + '_ {
+        self.empty_cells()
+            .filter(move |c| self.candidates[c.row][c.col].len() == count)
+    }
+
+    /// Empty cells with exactly two candidates, in row-major order.
+    pub fn bivalue_cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells_with_candidate_count(2)
+    }
+
+    /// Empty cells with exactly three candidates, in row-major order.
+    pub fn trivalue_cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells_with_candidate_count(3)
+    }
+
+    /// Empty cells that have the digit as a candidate, in row-major order.
+    pub fn cells_with_candidate(&self, num: u8) -> impl Iterator<Item = Coord> + '_ {
+        self.empty_cells()
+            .filter(move |c| self.candidates[c.row][c.col].contains(&num))
+    }
+
+    /// The conjugate pairs of the digit: units in which it's a candidate of exactly two
+    /// cells. Only units that must contain every digit count, so no cages or custom
+    /// units with fewer cells. Two cells sharing a row and a box form a pair in each of
+    /// them. The pairs come in the order of [`Sudoku::groups`].
+    pub fn conjugate_pairs(&self, num: u8) -> Vec<ConjugatePair> {
+        self.complete_groups()
+            .into_iter()
+            .filter_map(|group| {
+                let places: Vec<Coord> = group
+                    .cells
+                    .iter()
+                    .filter(|&&(row, col)| self.candidates[row][col].contains(&num))
+                    .map(|&(row, col)| Coord::new(row, col))
+                    .collect();
+                let [first, second] = places[..] else {
+                    return None;
+                };
+                Some(ConjugatePair {
+                    unit: group.unit,
+                    index: group.index,
+                    num,
+                    cells: [first, second],
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,222 @@
+//! Canonical form of a Sudoku under the transformations that keep it a valid Sudoku of
+//! the same difficulty: permuting bands, stacks, and the rows and columns within them,
+//! transposing (for square boxes), and relabeling the digits. Two puzzles are
+//! isomorphic if and only if their canonical forms are equal.
+
+use crate::{EMPTY, Parity, Sudoku, SudokuError};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// All orderings of `0..n`.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![Vec::new()];
+    }
+    let mut result = Vec::new();
+    for perm in permutations(n - 1) {
+        for pos in 0..=perm.len() {
+            let mut longer = perm.clone();
+            longer.insert(pos, n - 1);
+            result.push(longer);
+        }
+    }
+    result
+}
+
+/// Search for the lexicographically smallest arrangement of the rows for a fixed
+/// column order, relabeling the digits in the order of their first appearance.
+struct RowSearch<'a> {
+    /// Cells in the chosen column order, row by row
+    grid: &'a [Vec<u8>],
+    size: usize,
+    band_height: usize,
+    best: Vec<u8>,
+}
+
+impl RowSearch<'_> {
+    /// Extend `prefix`, the cells of the rows chosen so far, by the rows at position `pos`
+    /// onwards. `less` tells whether the prefix is already smaller than the beginning of
+    /// `best`. Returns whether `best` was replaced.
+    fn extend(
+        &mut self,
+        pos: usize,
+        prefix: &mut Vec<u8>,
+        chosen: &mut Vec<usize>,
+        labels: &[u8],
+        mut less: bool,
+    ) -> bool {
+        if pos == self.size {
+            self.best.clone_from(prefix);
+            return true;
+        }
+        let rows: Vec<usize> = if pos.is_multiple_of(self.band_height) {
+            // Start a band that hasn't been used yet
+            (0..self.size)
+                .filter(|&row| !chosen.contains(&row))
+                .collect()
+        } else {
+            let start = chosen[pos - 1] / self.band_height * self.band_height;
+            (start..start + self.band_height)
+                .filter(|row| !chosen.contains(row))
+                .collect()
+        };
+        let mut options: Vec<(Vec<u8>, Vec<u8>, usize)> = rows
+            .into_iter()
+            .map(|row| {
+                let mut labels = labels.to_vec();
+                let mut next = labels.iter().filter(|&&label| label != EMPTY).count() as u8 + 1;
+                let cells = self.grid[row]
+                    .iter()
+                    .map(|&num| {
+                        if num == EMPTY {
+                            return EMPTY;
+                        }
+                        if labels[num as usize] == EMPTY {
+                            labels[num as usize] = next;
+                            next += 1;
+                        }
+                        labels[num as usize]
+                    })
+                    .collect();
+                (cells, labels, row)
+            })
+            .collect();
+        options.sort();
+        let segment = pos * self.size..(pos + 1) * self.size;
+        let mut replaced = false;
+        for (cells, labels, row) in options {
+            if !less {
+                match cells.as_slice().cmp(&self.best[segment.clone()]) {
+                    Ordering::Greater => break,
+                    Ordering::Less => less = true,
+                    Ordering::Equal => {}
+                }
+            }
+            prefix.extend_from_slice(&cells);
+            chosen.push(row);
+            if self.extend(pos + 1, prefix, chosen, &labels, less) {
+                replaced = true;
+                // The prefix now is the beginning of the best arrangement
+                less = false;
+            }
+            chosen.pop();
+            prefix.truncate(segment.start);
+        }
+        replaced
+    }
+}
+
+impl Sudoku {
+    /// Whether the Sudoku follows the classic rules only, without variant elements
+    /// that transformations would have to carry along.
+    pub(crate) fn is_classic(&self) -> bool {
+        !self.is_jigsaw()
+            && self.cages.is_empty()
+            && self.edges.is_empty()
+            && self.thermometers.is_empty()
+            && self.arrows.is_empty()
+            && self.custom_units.is_empty()
+            && self.constraints.is_empty()
+            && self
+                .parity
+                .iter()
+                .flatten()
+                .all(|&parity| parity == Parity::Any)
+    }
+
+    /// Minimal serialization of the board, with empty cells as the smallest digit,
+    /// among all isomorphic boards. Available for classic Sudokus up to 9×9.
+    pub fn canonical_form(&self) -> Result<String, SudokuError> {
+        if !self.is_classic() {
+            return Err(SudokuError::VariantUnsupported(
+                "canonical forms need a classic Sudoku".to_string(),
+            ));
+        }
+        let size = self.size();
+        if size > 9 {
+            return Err(SudokuError::VariantUnsupported(
+                "canonical forms need a grid of at most 9×9 cells".to_string(),
+            ));
+        }
+        let (band_height, stack_width) = (self.spec.box_rows, self.spec.box_cols);
+        let mut orientations = vec![self.board.clone()];
+        if band_height == stack_width {
+            orientations.push(
+                (0..size)
+                    .map(|col| (0..size).map(|row| self.board[row][col]).collect())
+                    .collect(),
+            );
+        }
+        let stack_orders = permutations(size / stack_width);
+        let column_orders = permutations(stack_width);
+        // The column orders within each stack, combined
+        let mut within: Vec<Vec<usize>> = vec![Vec::new()];
+        for _ in 0..size / stack_width {
+            within = within
+                .iter()
+                .flat_map(|chosen| {
+                    column_orders.iter().map(move |order| {
+                        let mut chosen = chosen.clone();
+                        chosen.extend(order);
+                        chosen
+                    })
+                })
+                .collect();
+        }
+        let mut search_best = vec![u8::MAX; size * size];
+        for board in &orientations {
+            for stacks in &stack_orders {
+                for orders in &within {
+                    let columns: Vec<usize> = stacks
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, &stack)| {
+                            orders[i * stack_width..(i + 1) * stack_width]
+                                .iter()
+                                .map(move |&col| stack * stack_width + col)
+                        })
+                        .collect();
+                    let grid: Vec<Vec<u8>> = board
+                        .iter()
+                        .map(|row| columns.iter().map(|&col| row[col]).collect())
+                        .collect();
+                    let mut search = RowSearch {
+                        grid: &grid,
+                        size,
+                        band_height,
+                        best: search_best.clone(),
+                    };
+                    let mut prefix = Vec::with_capacity(size * size);
+                    let labels = vec![EMPTY; size + 1];
+                    if search.extend(0, &mut prefix, &mut Vec::new(), &labels, false) {
+                        search_best = search.best;
+                    }
+                }
+            }
+        }
+        Ok(search_best
+            .iter()
+            .map(|&num| self.spec.digit_char(num))
+            .collect())
+    }
+}
+
+/// Groups of isomorphic puzzles, as indices into `puzzles`. Only groups with more than
+/// one puzzle are returned, ordered by their first puzzle.
+pub fn find_duplicates<S: AsRef<str>>(puzzles: &[S]) -> Result<Vec<Vec<usize>>, SudokuError> {
+    let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (i, puzzle) in puzzles.iter().enumerate() {
+        let canonical = puzzle
+            .as_ref()
+            .parse::<Sudoku>()
+            .map_err(SudokuError::Parse)?
+            .canonical_form()?;
+        groups.entry(canonical).or_default().push(i);
+    }
+    let mut duplicates: Vec<Vec<usize>> = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect();
+    duplicates.sort();
+    Ok(duplicates)
+}
@@ -0,0 +1,67 @@
+//! Progress of long batch jobs, e.g. rating millions of puzzles, saved so that an
+//! interrupted run can resume where it stopped instead of starting over.
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// How far a batch job got: the input is processed up to `line`, and the output
+/// written so far is `output_len` bytes long. Output beyond that length was written
+/// after the checkpoint and is discarded on resuming, so no record appears twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Number of the last input line processed, counted from 1
+    pub line: usize,
+    pub output_len: u64,
+}
+
+impl fmt::Display for Checkpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "line {}", self.line)?;
+        writeln!(f, "output {}", self.output_len)
+    }
+}
+
+impl std::str::FromStr for Checkpoint {
+    type Err = String;
+    /// Parse a checkpoint as written by [`Checkpoint`]'s `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut checkpoint = Checkpoint::default();
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (key, value) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("Invalid line in checkpoint: {}", line))?;
+            let invalid = |e: std::num::ParseIntError| format!("Invalid {}: {}", key, e);
+            match key {
+                "line" => checkpoint.line = value.parse().map_err(invalid)?,
+                "output" => checkpoint.output_len = value.parse().map_err(invalid)?,
+                _ => return Err(format!("Unknown key in checkpoint: {}", key)),
+            }
+        }
+        Ok(checkpoint)
+    }
+}
+
+impl Checkpoint {
+    /// Read the checkpoint file, or return `None` if there's none.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Option<Checkpoint>> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Write the checkpoint file. It's replaced in one go, so an interruption leaves
+    /// either the old or the new checkpoint behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+        std::fs::write(&temp, self.to_string())?;
+        std::fs::rename(&temp, path)
+    }
+}
@@ -0,0 +1,77 @@
+//! Breeding hard puzzles by hill climbing: mutate the givens at random and keep the
+//! mutations that raise the rating.
+
+use crate::{DigitSet, EMPTY, Sudoku};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+impl Sudoku {
+    /// Difficulty of the board as a puzzle, if it has a unique solution that the
+    /// human-like solver finds.
+    fn rate_puzzle(&self, board: &[Vec<u8>]) -> Option<f64> {
+        let mut sudoku = self.clone();
+        sudoku.board = board.to_vec();
+        sudoku.original_board = board.to_vec();
+        if sudoku.count_solutions(2) != 1 {
+            return None;
+        }
+        sudoku
+            .solve_human_like()
+            .is_solved()
+            .then(|| sudoku.difficulty())
+    }
+
+    /// Make the puzzle harder by hill climbing. Each iteration removes a random given,
+    /// adds one from the solution, or does both, and keeps the change if the puzzle
+    /// still has a unique solution, can still be solved with the implemented strategies,
+    /// and is rated higher than before. Returns `None` if the puzzle itself doesn't meet
+    /// these conditions.
+    pub fn harden<R: Rng + ?Sized>(&self, rng: &mut R, iterations: usize) -> Option<Sudoku> {
+        let mut board = self.board.clone();
+        let mut difficulty = self.rate_puzzle(&board)?;
+        let solution = self.solutions().next()?;
+        let cells: Vec<(usize, usize)> = (0..self.size())
+            .flat_map(|row| (0..self.size()).map(move |col| (row, col)))
+            .collect();
+        for _ in 0..iterations {
+            let mut mutated = board.clone();
+            let (remove, add) = match rng.random_range(0..3) {
+                0 => (true, false),
+                1 => (false, true),
+                _ => (true, true),
+            };
+            if remove {
+                let givens: Vec<&(usize, usize)> = cells
+                    .iter()
+                    .filter(|&&(row, col)| mutated[row][col] != EMPTY)
+                    .collect();
+                if let Some(&&(row, col)) = givens.choose(rng) {
+                    mutated[row][col] = EMPTY;
+                }
+            }
+            if add {
+                let empty: Vec<&(usize, usize)> = cells
+                    .iter()
+                    .filter(|&&(row, col)| board[row][col] == EMPTY)
+                    .collect();
+                if let Some(&&(row, col)) = empty.choose(rng) {
+                    mutated[row][col] = solution[row][col];
+                }
+            }
+            if let Some(rating) = self.rate_puzzle(&mutated)
+                && rating > difficulty
+            {
+                log::info!("Raised difficulty from {:.2} to {:.2}", difficulty, rating);
+                board = mutated;
+                difficulty = rating;
+            }
+        }
+        let mut hardened = self.clone();
+        hardened.board = board.clone();
+        hardened.original_board = board;
+        hardened.candidates = vec![vec![DigitSet::new(); self.size()]; self.size()];
+        hardened.rating.clear();
+        hardened.undo_stack.clear();
+        Some(hardened)
+    }
+}
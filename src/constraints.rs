@@ -0,0 +1,103 @@
+//! Optional global constraints on top of the classic rules, e.g. anti-knight and anti-king.
+
+use crate::Sudoku;
+use std::ops::{BitOr, BitOrAssign};
+
+/// Set of additional constraints as bit flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Constraints(u8);
+
+impl Constraints {
+    pub const NONE: Constraints = Constraints(0);
+    /// Identical digits must not be a chess knight's move apart
+    pub const ANTI_KNIGHT: Constraints = Constraints(1 << 0);
+    /// Identical digits must not be a chess king's move apart
+    pub const ANTI_KING: Constraints = Constraints(1 << 1);
+    /// Adjacent cells without an X or V clue must not add up to 10 or 5
+    pub const NEGATIVE_XV: Constraints = Constraints(1 << 2);
+    /// Adjacent cells without a Kropki dot must be neither consecutive nor in a 1:2 ratio
+    pub const NEGATIVE_KROPKI: Constraints = Constraints(1 << 3);
+    /// Digits must not repeat along the two main diagonals
+    pub const DIAGONAL: Constraints = Constraints(1 << 4);
+    /// Digits must not repeat within the windows of Hyper Sudoku, the box-sized
+    /// areas one cell away from the border and from each other
+    pub const WINDOWS: Constraints = Constraints(1 << 5);
+
+    pub fn contains(self, other: Constraints) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for Constraints {
+    type Output = Constraints;
+    fn bitor(self, rhs: Constraints) -> Constraints {
+        Constraints(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Constraints {
+    fn bitor_assign(&mut self, rhs: Constraints) {
+        self.0 |= rhs.0;
+    }
+}
+
+const KNIGHT_MOVES: [(isize, isize); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, -2),
+    (-1, 2),
+    (1, -2),
+    (1, 2),
+    (2, -1),
+    (2, 1),
+];
+
+const KING_MOVES: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl Sudoku {
+    pub fn set_constraints(&mut self, constraints: Constraints) {
+        self.constraints = constraints;
+    }
+
+    /// Cells that must not contain the same digit as the given cell because of the
+    /// additional constraints. Cells in the same row, column or box aren't excluded.
+    pub fn constraint_peers(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut peers = Vec::new();
+        if !self.constraints.contains(Constraints::ANTI_KNIGHT)
+            && !self.constraints.contains(Constraints::ANTI_KING)
+        {
+            return peers;
+        }
+        let mut add_moves = |moves: &[(isize, isize)]| {
+            for &(dr, dc) in moves {
+                let (Some(r), Some(c)) = (row.checked_add_signed(dr), col.checked_add_signed(dc))
+                else {
+                    continue;
+                };
+                if r < self.size() && c < self.size() && !peers.contains(&(r, c)) {
+                    peers.push((r, c));
+                }
+            }
+        };
+        if self.constraints.contains(Constraints::ANTI_KNIGHT) {
+            add_moves(&KNIGHT_MOVES);
+        }
+        if self.constraints.contains(Constraints::ANTI_KING) {
+            add_moves(&KING_MOVES);
+        }
+        peers
+    }
+}
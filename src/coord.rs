@@ -0,0 +1,161 @@
+//! Cell coordinates and the notations human solvers use for them: `r3c5` (row 3,
+//! column 5) and `C5` (row C, column 5), both counted from 1.
+
+use crate::{Candidate, Cell, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub fn new(row: usize, col: usize) -> Coord {
+        Coord { row, col }
+    }
+
+    /// Index of the box (or jigsaw region) containing the cell.
+    pub fn box_index(self, sudoku: &Sudoku) -> usize {
+        sudoku.regions[self.row][self.col]
+    }
+
+    /// Cells sharing a unit with this cell, or forbidden to hold the same digit by
+    /// one of the constraints, in row-major order.
+    pub fn peers(self, sudoku: &Sudoku) -> Vec<Coord> {
+        let mut peers: Vec<Coord> = sudoku
+            .groups()
+            .into_iter()
+            .filter(|group| group.contains(self.row, self.col))
+            .flat_map(|group| group.cells)
+            .chain(sudoku.constraint_peers(self.row, self.col))
+            .map(|(row, col)| Coord { row, col })
+            .filter(|&peer| peer != self)
+            .collect();
+        peers.sort();
+        peers.dedup();
+        peers
+    }
+
+    /// Check if both cells can't hold the same digit.
+    pub fn sees(self, other: Coord, sudoku: &Sudoku) -> bool {
+        self != other && self.peers(sudoku).contains(&other)
+    }
+
+    /// The cell in `A1` notation: the row as a letter, the column as a number.
+    pub fn to_a1(self) -> String {
+        format!("{}{}", (b'A' + self.row as u8) as char, self.col + 1)
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((row, col): (usize, usize)) -> Coord {
+        Coord { row, col }
+    }
+}
+
+impl From<Coord> for (usize, usize) {
+    fn from(coord: Coord) -> (usize, usize) {
+        (coord.row, coord.col)
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "r{}c{}", self.row + 1, self.col + 1)
+    }
+}
+
+impl std::str::FromStr for Coord {
+    type Err = String;
+
+    /// Parse a cell in `r3c5` or `C5` notation, case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid cell '{}'", s);
+        let (row, col) = if let Some(rest) = s.strip_prefix(['r', 'R']) {
+            let (row, col) = rest.split_once(['c', 'C']).ok_or_else(invalid)?;
+            (row.parse::<usize>().map_err(|_| invalid())?, col)
+        } else {
+            let mut chars = s.chars();
+            let letter = chars
+                .next()
+                .filter(|c| c.is_ascii_alphabetic())
+                .ok_or_else(invalid)?;
+            let row = (letter.to_ascii_uppercase() as u8 - b'A') as usize + 1;
+            (row, chars.as_str())
+        };
+        let col = col.parse::<usize>().map_err(|_| invalid())?;
+        match (row.checked_sub(1), col.checked_sub(1)) {
+            (Some(row), Some(col)) => Ok(Coord { row, col }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Cell {
+    pub fn coord(&self) -> Coord {
+        Coord::new(self.row, self.col)
+    }
+}
+
+impl Candidate {
+    pub fn coord(&self) -> Coord {
+        Coord::new(self.row, self.col)
+    }
+}
+
+/// A placed digit, e.g. `r3c5=7`.
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.coord(), self.num)
+    }
+}
+
+impl std::str::FromStr for Cell {
+    type Err = String;
+
+    /// Parse a placed digit like `r3c5=7` or `C5=7`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (coord, num) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid placement '{}'", s))?;
+        let Coord { row, col } = coord.trim().parse()?;
+        let num = num
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid digit '{}'", num.trim()))?;
+        Ok(Cell { row, col, num })
+    }
+}
+
+/// A candidate, e.g. `r3c5<>7` as used for eliminations.
+impl fmt::Display for Candidate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}<>{}", self.coord(), self.num)
+    }
+}
+
+impl std::str::FromStr for Candidate {
+    type Err = String;
+
+    /// Parse a candidate like `r3c5<>7` or `C5<>7`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (coord, num) = s
+            .split_once("<>")
+            .ok_or_else(|| format!("Invalid candidate '{}'", s))?;
+        let Coord { row, col } = coord.trim().parse()?;
+        let num = num
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid digit '{}'", num.trim()))?;
+        Ok(Candidate { row, col, num })
+    }
+}
+
+/// Parse a cell reference like `r1c2` or `A2` (rows and columns counted from 1).
+pub(crate) fn parse_cell(s: &str) -> Option<(usize, usize)> {
+    s.parse::<Coord>().ok().map(Into::into)
+}
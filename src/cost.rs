@@ -0,0 +1,123 @@
+//! Difficulty adjusted for the effort of finding each step. The same pattern is easy to
+//! spot among few notes and hard to spot among many, so besides the effort of its
+//! strategy, each step is weighted by how cluttered the houses around its pattern are.
+
+use crate::{EMPTY, StrategyResult, Sudoku, Weights};
+use std::collections::BTreeSet;
+
+/// Efforts of the strategies, and how much clutter adds to them.
+#[derive(Debug, Clone)]
+pub struct CostModel {
+    pub weights: Weights,
+    /// Factor the effort of a step grows by in the most cluttered houses, on top of 1.
+    /// With 0, the adjusted difficulty is the plain one.
+    pub noise_weight: f64,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        CostModel {
+            weights: Weights::default(),
+            noise_weight: 1.0,
+        }
+    }
+}
+
+impl CostModel {
+    pub fn new() -> CostModel {
+        CostModel::default()
+    }
+
+    pub fn with_weights(self, weights: Weights) -> CostModel {
+        CostModel { weights, ..self }
+    }
+
+    pub fn with_noise_weight(self, noise_weight: f64) -> CostModel {
+        CostModel {
+            noise_weight,
+            ..self
+        }
+    }
+}
+
+/// What it took to find a step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchEffort {
+    /// Empty cells in the rows, columns and boxes containing the pattern
+    pub cells_scanned: usize,
+    /// Share of the notes these cells could hold that they still hold beyond their
+    /// first one, from 0 (one note per cell) to 1 (no digit excluded yet)
+    pub noise: f64,
+}
+
+impl Sudoku {
+    /// Measure how cluttered the houses around the pattern of the step are, based on the
+    /// current notes, i.e. before the step is applied.
+    pub fn search_effort(&self, step: &StrategyResult) -> SearchEffort {
+        let removals = &step.removals;
+        let mut pattern: Vec<(usize, usize)> = removals
+            .cells_affected
+            .iter()
+            .chain(&removals.sets_cell)
+            .map(|cell| (cell.row, cell.col))
+            .collect();
+        if pattern.is_empty() {
+            pattern.extend(
+                removals
+                    .candidates_about_to_be_removed
+                    .iter()
+                    .map(|candidate| (candidate.row, candidate.col)),
+            );
+        }
+        let scanned: BTreeSet<(usize, usize)> = self
+            .houses()
+            .filter(|&house| {
+                pattern
+                    .iter()
+                    .any(|&(row, col)| self.house_contains(house, row, col))
+            })
+            .flat_map(|house| self.cells_of(house))
+            .filter(|&(row, col)| self.board[row][col] == EMPTY)
+            .collect();
+        let notes: usize = scanned
+            .iter()
+            .map(|&(row, col)| self.candidates[row][col].len().max(1))
+            .sum();
+        let size = self.size();
+        let noise = if scanned.is_empty() || size < 2 {
+            0.0
+        } else {
+            (notes - scanned.len()) as f64 / (scanned.len() * (size - 1)) as f64
+        };
+        SearchEffort {
+            cells_scanned: scanned.len(),
+            noise,
+        }
+    }
+
+    /// Solve a copy of the Sudoku from its current digits like the human-like solver,
+    /// and compute the difficulty as in [`Sudoku::difficulty_with`], with the effort
+    /// of each step raised by `1 + noise_weight × noise` of its [`SearchEffort`].
+    pub fn adjusted_difficulty(&self, model: &CostModel) -> f64 {
+        let mut sudoku = self.clone();
+        sudoku.calc_all_notes();
+        sudoku.rating.clear();
+        let (mut total, mut count) = (0.0, 0);
+        while sudoku.unsolved() && sudoku.find_contradiction().is_none() {
+            let step = sudoku.next_step();
+            if !step.removals.will_remove_candidates() {
+                break;
+            }
+            let effort = sudoku.search_effort(&step);
+            let removed = step.rating_count();
+            total += model.weights.effort(&step.strategy)
+                * (1.0 + model.noise_weight * effort.noise)
+                * removed as f64;
+            count += removed;
+            sudoku
+                .apply(&step)
+                .expect("steps found on the board fit it");
+        }
+        total / count as f64
+    }
+}
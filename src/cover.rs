@@ -0,0 +1,277 @@
+//! Encoding a position for external solvers: as an exact-cover matrix, e.g. for
+//! Knuth's Dancing Links, or as a CNF formula in the DIMACS format read by SAT
+//! solvers. The solutions these solvers find can be imported back into the Sudoku.
+//!
+//! Both encodings cover the digits and the units of the Sudoku: rows, columns, boxes,
+//! diagonals, windows, custom units, and cages, the latter without their sums. Other
+//! clues, e.g. thermometers or arrows, aren't encoded, so external solvers may
+//! find solutions that break them; [`Sudoku::import_cover`] and
+//! [`Sudoku::import_dimacs_model`] reject these.
+
+use crate::{Candidate, EMPTY, Sudoku, SudokuError};
+use std::fmt;
+
+/// An exact-cover problem: choose rows of the matrix such that each primary column
+/// holds exactly one 1, and each secondary column at most one.
+///
+/// Each row stands for a candidate, i.e. placing a digit in a cell. The columns are
+/// the cells, which need exactly one digit, and each pair of unit and digit. Units
+/// with fewer cells than the grid has rows, i.e. small cages or custom units, give
+/// secondary columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExactCover {
+    /// Names of the columns, the primary columns first
+    pub columns: Vec<String>,
+    /// Number of primary columns
+    pub primary: usize,
+    /// The candidate each row stands for
+    pub rows: Vec<Candidate>,
+    /// Columns holding a 1, per row, in ascending order
+    pub matrix: Vec<Vec<usize>>,
+}
+
+/// Writes the problem in the input format of Knuth's `dlx1`: a line with the names
+/// of the primary columns, `|` and the names of the secondary columns, followed by a
+/// line per row with the names of the columns it covers.
+impl fmt::Display for ExactCover {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (primary, secondary) = self.columns.split_at(self.primary);
+        write!(f, "{}", primary.join(" "))?;
+        if !secondary.is_empty() {
+            write!(f, " | {}", secondary.join(" "))?;
+        }
+        writeln!(f)?;
+        for row in &self.matrix {
+            let names: Vec<&str> = row.iter().map(|&col| self.columns[col].as_str()).collect();
+            writeln!(f, "{}", names.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Sudoku {
+    /// Number of the DIMACS variable which is true iff `num` is placed at `(row, col)`.
+    /// Variables are numbered from 1 in row-major order of the cells, and by digit
+    /// within each cell.
+    pub fn dimacs_variable(&self, row: usize, col: usize, num: u8) -> usize {
+        let size = self.size();
+        (row * size + col) * size + num as usize
+    }
+
+    /// Encode the position as an exact-cover problem. Filled cells get only the row of
+    /// their digit.
+    pub fn exact_cover(&self) -> ExactCover {
+        let size = self.size();
+        let mut primary = Vec::new();
+        let mut secondary = Vec::new();
+        // Column of each cell, followed by the columns of each unit and digit
+        let mut cell_columns = vec![vec![0; size]; size];
+        for (row, columns) in cell_columns.iter_mut().enumerate() {
+            for (col, column) in columns.iter_mut().enumerate() {
+                *column = primary.len();
+                primary.push(format!("r{}c{}", row + 1, col + 1));
+            }
+        }
+        let mut unit_columns = Vec::new();
+        for group in self.groups() {
+            let name = format!(
+                "{}{}",
+                group.unit.to_string().to_lowercase(),
+                group.index + 1
+            );
+            let complete = group.cells.len() == size;
+            let first = if complete {
+                primary.len()
+            } else {
+                secondary.len()
+            };
+            let names = (1..=size).map(|num| format!("{}#{}", name, num));
+            if complete {
+                primary.extend(names);
+            } else {
+                secondary.extend(names);
+            }
+            unit_columns.push((group.cells, complete, first));
+        }
+        let primary_count = primary.len();
+        let mut cover = ExactCover {
+            columns: primary.into_iter().chain(secondary).collect(),
+            primary: primary_count,
+            rows: Vec::new(),
+            matrix: Vec::new(),
+        };
+        for (row, columns) in cell_columns.iter().enumerate() {
+            for (col, &cell_column) in columns.iter().enumerate() {
+                for num in 1..=size as u8 {
+                    let filled = self.board[row][col];
+                    if filled != EMPTY && filled != num {
+                        continue;
+                    }
+                    let mut covered = vec![cell_column];
+                    for (cells, complete, first) in &unit_columns {
+                        if cells.contains(&(row, col)) {
+                            let offset = if *complete { 0 } else { primary_count };
+                            covered.push(offset + first + num as usize - 1);
+                        }
+                    }
+                    covered.sort_unstable();
+                    cover.rows.push(Candidate { row, col, num });
+                    cover.matrix.push(covered);
+                }
+            }
+        }
+        cover
+    }
+
+    /// Encode the position as a CNF formula in the DIMACS format, with the variables
+    /// of [`Sudoku::dimacs_variable`]. Every cell holds at least one digit, no cell
+    /// or unit holds a digit twice, units spanning a whole row's worth of cells hold
+    /// every digit, and filled cells are fixed by unit clauses.
+    pub fn to_dimacs(&self) -> String {
+        let size = self.size();
+        let mut clauses: Vec<Vec<i64>> = Vec::new();
+        let var = |row: usize, col: usize, num: u8| self.dimacs_variable(row, col, num) as i64;
+        let at_most_one = |clauses: &mut Vec<Vec<i64>>, vars: &[i64]| {
+            for (i, &a) in vars.iter().enumerate() {
+                for &b in &vars[i + 1..] {
+                    clauses.push(vec![-a, -b]);
+                }
+            }
+        };
+        for row in 0..size {
+            for col in 0..size {
+                let vars: Vec<i64> = (1..=size as u8).map(|num| var(row, col, num)).collect();
+                clauses.push(vars.clone());
+                at_most_one(&mut clauses, &vars);
+            }
+        }
+        for group in self.groups() {
+            for num in 1..=size as u8 {
+                let vars: Vec<i64> = group
+                    .cells
+                    .iter()
+                    .map(|&(row, col)| var(row, col, num))
+                    .collect();
+                if group.cells.len() == size {
+                    clauses.push(vars.clone());
+                }
+                at_most_one(&mut clauses, &vars);
+            }
+        }
+        for row in 0..size {
+            for col in 0..size {
+                let num = self.board[row][col];
+                if num != EMPTY {
+                    clauses.push(vec![var(row, col, num)]);
+                }
+            }
+        }
+        let mut dimacs = format!("p cnf {} {}\n", size * size * size, clauses.len());
+        for clause in clauses {
+            for literal in clause {
+                dimacs.push_str(&literal.to_string());
+                dimacs.push(' ');
+            }
+            dimacs.push_str("0\n");
+        }
+        dimacs
+    }
+
+    /// Fill the board with the solution chosen by an exact-cover solver, given as the
+    /// indices of the chosen rows of `cover`, which must have been created from this
+    /// position.
+    pub fn import_cover(&mut self, cover: &ExactCover, rows: &[usize]) -> Result<(), SudokuError> {
+        let candidates = rows
+            .iter()
+            .map(|&row| {
+                cover.rows.get(row).cloned().ok_or_else(|| {
+                    SudokuError::Parse(format!("Row {} isn't part of the exact cover", row))
+                })
+            })
+            .collect::<Result<Vec<Candidate>, SudokuError>>()?;
+        self.import_assignment(&candidates)
+    }
+
+    /// Fill the board with the model found by a SAT solver for the formula of
+    /// [`Sudoku::to_dimacs`]. The model is the solver's output: `v` lines with the
+    /// literals, optionally preceded by comments and the `s` status line, or just the
+    /// literals. A status other than `SATISFIABLE` means the Sudoku can't be solved.
+    pub fn import_dimacs_model(&mut self, model: &str) -> Result<(), SudokuError> {
+        let size = self.size();
+        let mut candidates = Vec::new();
+        for line in model.lines().map(str::trim) {
+            let literals = match line.split_once(' ') {
+                _ if line.is_empty() || line.starts_with('c') => continue,
+                Some(("s", status)) if status.trim() == "SATISFIABLE" => continue,
+                Some(("s", _)) => return Err(SudokuError::NotSolvable),
+                Some(("v", literals)) => literals,
+                _ => line,
+            };
+            for literal in literals.split_whitespace() {
+                let literal: i64 = literal
+                    .parse()
+                    .map_err(|_| SudokuError::Parse(format!("Invalid literal {}", literal)))?;
+                if literal <= 0 {
+                    continue;
+                }
+                let index = literal as usize - 1;
+                if index >= size * size * size {
+                    return Err(SudokuError::Parse(format!(
+                        "Variable {} is out of range",
+                        literal
+                    )));
+                }
+                candidates.push(Candidate {
+                    row: index / (size * size),
+                    col: index / size % size,
+                    num: (index % size) as u8 + 1,
+                });
+            }
+        }
+        self.import_assignment(&candidates)
+    }
+
+    /// Fill the board with the digits of the candidates, which must place exactly one
+    /// digit in each cell, agree with the filled cells and obey all rules.
+    fn import_assignment(&mut self, candidates: &[Candidate]) -> Result<(), SudokuError> {
+        let size = self.size();
+        let mut board = vec![vec![EMPTY; size]; size];
+        for candidate in candidates {
+            let &Candidate { row, col, num } = candidate;
+            if board[row][col] != EMPTY {
+                return Err(SudokuError::InvalidBoard(format!(
+                    "r{}c{} is assigned more than one digit",
+                    row + 1,
+                    col + 1
+                )));
+            }
+            let filled = self.board[row][col];
+            if filled != EMPTY && filled != num {
+                return Err(SudokuError::InvalidBoard(format!(
+                    "r{}c{} already holds {}",
+                    row + 1,
+                    col + 1,
+                    filled
+                )));
+            }
+            board[row][col] = num;
+        }
+        if let Some(row) = board.iter().position(|cells| cells.contains(&EMPTY)) {
+            let col = board[row].iter().position(|&num| num == EMPTY).unwrap();
+            return Err(SudokuError::InvalidBoard(format!(
+                "r{}c{} isn't assigned a digit",
+                row + 1,
+                col + 1
+            )));
+        }
+        let mut solved = self.clone();
+        solved.board = board;
+        if solved.has_conflicts() {
+            return Err(SudokuError::InvalidBoard(
+                "The assignment breaks the rules of the Sudoku".to_string(),
+            ));
+        }
+        self.board = solved.board;
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+//! Sequences of puzzles for tutorials, introducing one technique at a time.
+
+use crate::{EMPTY, RatedPuzzle, Strategy, Sudoku, SudokuError};
+use rand::Rng;
+use rand::seq::SliceRandom;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// Number of attempts per puzzle of a curriculum before giving up.
+const MAX_ATTEMPTS: usize = 100;
+
+/// Closed interval of difficulties as returned by [`Sudoku::difficulty`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DifficultyRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl DifficultyRange {
+    pub fn new(min: f64, max: f64) -> DifficultyRange {
+        DifficultyRange { min, max }
+    }
+
+    pub fn contains(&self, difficulty: f64) -> bool {
+        (self.min..=self.max).contains(&difficulty)
+    }
+}
+
+impl std::str::FromStr for DifficultyRange {
+    type Err = String;
+    /// Parse a range like `3.0..5.0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid difficulty range: {}", s);
+        let (min, max) = s.split_once("..").ok_or_else(invalid)?;
+        let min = min.trim().parse::<f64>().map_err(|_| invalid())?;
+        let max = max.trim().parse::<f64>().map_err(|_| invalid())?;
+        if min > max {
+            return Err(invalid());
+        }
+        Ok(DifficultyRange::new(min, max))
+    }
+}
+
+/// Puzzles of one level of a curriculum.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Lesson {
+    /// The technique introduced in this lesson, used by all of its puzzles
+    pub technique: Strategy,
+    pub range: DifficultyRange,
+    pub puzzles: Vec<RatedPuzzle>,
+}
+
+impl Sudoku {
+    /// Dig a puzzle for a lesson from a random solution, removing one given at a time
+    /// as long as the solution stays unique, until the rater finds the puzzle fits the
+    /// lesson. `technique` is the lesson's technique if already chosen, otherwise any
+    /// technique not `known` qualifies. Gives up once the puzzle gets too hard.
+    fn dig_lesson_puzzle<R: Rng + ?Sized>(
+        rng: &mut R,
+        range: &DifficultyRange,
+        known: &BTreeSet<Strategy>,
+        technique: Option<&Strategy>,
+    ) -> Result<Option<RatedPuzzle>, SudokuError> {
+        let mut sudoku = Sudoku::new();
+        let Some(solution) = sudoku.random_solution(rng) else {
+            return Ok(None);
+        };
+        sudoku.board = solution;
+        let mut cells: Vec<(usize, usize)> = (0..sudoku.size())
+            .flat_map(|row| (0..sudoku.size()).map(move |col| (row, col)))
+            .collect();
+        cells.shuffle(rng);
+        for (row, col) in cells {
+            let num = sudoku.board[row][col];
+            sudoku.board[row][col] = EMPTY;
+            if sudoku.count_solutions(2) != 1 {
+                sudoku.board[row][col] = num;
+                continue;
+            }
+            let rated = RatedPuzzle::rate(&sudoku.serialized())?;
+            if !rated.solved || rated.difficulty > range.max {
+                return Ok(None);
+            }
+            let new: Vec<&Strategy> = rated
+                .strategies
+                .keys()
+                .filter(|strategy| !known.contains(strategy))
+                .collect();
+            match new.as_slice() {
+                // Nothing new yet, keep digging
+                [] => {}
+                [strategy] if technique.is_none_or(|technique| technique == *strategy) => {
+                    if rated.difficulty >= range.min {
+                        return Ok(Some(rated));
+                    }
+                }
+                _ => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Generate a lesson for each difficulty range, with `per_level` puzzles each. Every
+    /// lesson introduces exactly one technique: its puzzles need that technique, and
+    /// otherwise only those of the lessons before. The rater verifies both, as well as
+    /// the difficulty. Fails if no fitting puzzle turns up after many attempts, e.g.
+    /// because a range only admits techniques introduced before. The same random number
+    /// generator state always leads to the same curriculum.
+    pub fn generate_curriculum<R: Rng + ?Sized>(
+        levels: &[DifficultyRange],
+        per_level: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Lesson>, SudokuError> {
+        let mut known: BTreeSet<Strategy> = BTreeSet::new();
+        let mut lessons = Vec::with_capacity(levels.len());
+        for (level, range) in levels.iter().enumerate() {
+            let mut technique: Option<Strategy> = None;
+            let mut puzzles = Vec::with_capacity(per_level);
+            let mut attempts = 0;
+            while puzzles.len() < per_level {
+                if attempts == MAX_ATTEMPTS {
+                    return Err(SudokuError::InvalidBoard(format!(
+                        "No puzzle found for level {} with a difficulty of {} to {}",
+                        level + 1,
+                        range.min,
+                        range.max
+                    )));
+                }
+                attempts += 1;
+                if let Some(rated) =
+                    Sudoku::dig_lesson_puzzle(rng, range, &known, technique.as_ref())?
+                {
+                    technique = rated
+                        .strategies
+                        .keys()
+                        .find(|strategy| !known.contains(strategy))
+                        .cloned();
+                    puzzles.push(rated);
+                    attempts = 0;
+                }
+            }
+            // No puzzles asked for, so nothing to introduce
+            let Some(technique) = technique else {
+                continue;
+            };
+            known.insert(technique.clone());
+            lessons.push(Lesson {
+                technique,
+                range: *range,
+                puzzles,
+            });
+        }
+        Ok(lessons)
+    }
+}
@@ -0,0 +1,211 @@
+//! Sets of digits as bitmasks, used for the notes of the cells. Unlike a `HashSet`,
+//! they need no allocation and no hashing, are `Copy`, and iterate in ascending order.
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Sub};
+
+/// A set of digits from 1 to 16, e.g. the notes of a cell.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DigitSet(u32);
+
+impl DigitSet {
+    /// The empty set.
+    pub const fn new() -> DigitSet {
+        DigitSet(0)
+    }
+
+    /// The digits from 1 to `size`.
+    pub const fn all(size: usize) -> DigitSet {
+        DigitSet(((1 << size) - 1) << 1)
+    }
+
+    /// The set with bit `num` set for each digit `num`; bit 0 is ignored.
+    pub const fn from_bits(bits: u32) -> DigitSet {
+        DigitSet(bits & !1)
+    }
+
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(&self, num: &u8) -> bool {
+        *num < 32 && self.0 & 1 << *num != 0
+    }
+
+    /// Add the digit. Returns whether it wasn't in the set before.
+    pub fn insert(&mut self, num: u8) -> bool {
+        let added = !self.contains(&num);
+        self.0 |= 1 << num;
+        added
+    }
+
+    /// Remove the digit. Returns whether it was in the set.
+    pub fn remove(&mut self, num: &u8) -> bool {
+        let removed = self.contains(num);
+        self.0 &= !(1 << *num);
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = 0;
+    }
+
+    /// Keep only the digits for which `keep` returns `true`.
+    pub fn retain(&mut self, mut keep: impl FnMut(&u8) -> bool) {
+        for num in *self {
+            if !keep(&num) {
+                self.remove(&num);
+            }
+        }
+    }
+
+    /// The digits in ascending order.
+    pub fn iter(&self) -> Digits {
+        Digits(self.0)
+    }
+
+    pub fn union(&self, other: &DigitSet) -> DigitSet {
+        DigitSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &DigitSet) -> DigitSet {
+        DigitSet(self.0 & other.0)
+    }
+
+    /// The digits of this set that aren't in `other`.
+    pub fn difference(&self, other: &DigitSet) -> DigitSet {
+        DigitSet(self.0 & !other.0)
+    }
+
+    pub fn is_subset(&self, other: &DigitSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn is_disjoint(&self, other: &DigitSet) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// The smallest digit, if any.
+    pub fn first(&self) -> Option<u8> {
+        self.iter().next()
+    }
+}
+
+/// Iterator over the digits of a [`DigitSet`], in ascending order.
+#[derive(Debug, Clone)]
+pub struct Digits(u32);
+
+impl Iterator for Digits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.0 == 0 {
+            return None;
+        }
+        let num = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Some(num)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Digits {}
+
+impl IntoIterator for DigitSet {
+    type Item = u8;
+    type IntoIter = Digits;
+
+    fn into_iter(self) -> Digits {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &DigitSet {
+    type Item = u8;
+    type IntoIter = Digits;
+
+    fn into_iter(self) -> Digits {
+        self.iter()
+    }
+}
+
+impl FromIterator<u8> for DigitSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> DigitSet {
+        let mut set = DigitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a u8> for DigitSet {
+    fn from_iter<I: IntoIterator<Item = &'a u8>>(iter: I) -> DigitSet {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl Extend<u8> for DigitSet {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for num in iter {
+            self.insert(num);
+        }
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for DigitSet {
+    fn from(digits: [u8; N]) -> DigitSet {
+        digits.into_iter().collect()
+    }
+}
+
+impl BitOr for DigitSet {
+    type Output = DigitSet;
+
+    fn bitor(self, other: DigitSet) -> DigitSet {
+        self.union(&other)
+    }
+}
+
+impl BitAnd for DigitSet {
+    type Output = DigitSet;
+
+    fn bitand(self, other: DigitSet) -> DigitSet {
+        self.intersection(&other)
+    }
+}
+
+impl Sub for DigitSet {
+    type Output = DigitSet;
+
+    fn sub(self, other: DigitSet) -> DigitSet {
+        self.difference(&other)
+    }
+}
+
+/// Writes the digits like a set, e.g. `{2, 5, 8}`.
+impl fmt::Debug for DigitSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Serialized as a sequence of digits in ascending order.
+#[cfg(feature = "serde")]
+impl Serialize for DigitSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
@@ -0,0 +1,257 @@
+//! Edge clues between orthogonally adjacent cells: XV sums and Kropki dots.
+
+use crate::analysis::Hits;
+use crate::coord::parse_cell;
+use crate::{
+    Candidate, Constraints, DigitSet, EMPTY, Strategy, StrategyResult, Sudoku, SudokuError,
+};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::ops::ControlFlow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum EdgeKind {
+    /// The two digits add up to 10
+    X,
+    /// The two digits add up to 5
+    V,
+    /// The two digits are consecutive
+    WhiteDot,
+    /// One digit is twice the other
+    BlackDot,
+}
+
+impl EdgeKind {
+    pub fn allows(self, a: u8, b: u8) -> bool {
+        match self {
+            EdgeKind::X => a + b == 10,
+            EdgeKind::V => a + b == 5,
+            EdgeKind::WhiteDot => a.abs_diff(b) == 1,
+            EdgeKind::BlackDot => a == 2 * b || b == 2 * a,
+        }
+    }
+
+    fn is_xv(self) -> bool {
+        matches!(self, EdgeKind::X | EdgeKind::V)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Edge {
+    pub cells: [(usize, usize); 2],
+    pub kind: EdgeKind,
+}
+
+impl Edge {
+    /// Parse edges given as whitespace separated clues `<kind>:<cell>-<cell>`, with the
+    /// kind being `X`, `V`, `W` (white dot) or `B` (black dot) and cells written as
+    /// `r1c2`, e.g. `X:r1c1-r1c2 B:r4c7-r5c7`.
+    pub fn parse_edges(s: &str) -> Result<Vec<Edge>, SudokuError> {
+        s.split_whitespace()
+            .map(|clue| {
+                let invalid = || SudokuError::Parse(format!("Invalid edge clue '{}'", clue));
+                let (kind, cells) = clue.split_once(':').ok_or_else(invalid)?;
+                let kind = match kind {
+                    "X" | "x" => EdgeKind::X,
+                    "V" | "v" => EdgeKind::V,
+                    "W" | "w" => EdgeKind::WhiteDot,
+                    "B" | "b" => EdgeKind::BlackDot,
+                    _ => return Err(invalid()),
+                };
+                let (a, b) = cells.split_once('-').ok_or_else(invalid)?;
+                let a = parse_cell(a).ok_or_else(invalid)?;
+                let b = parse_cell(b).ok_or_else(invalid)?;
+                if a.0.abs_diff(b.0) + a.1.abs_diff(b.1) != 1 {
+                    return Err(SudokuError::Parse(format!(
+                        "Cells of edge clue '{}' aren't adjacent",
+                        clue
+                    )));
+                }
+                Ok(Edge {
+                    cells: [a, b],
+                    kind,
+                })
+            })
+            .collect()
+    }
+
+    fn connects(&self, a: (usize, usize), b: (usize, usize)) -> bool {
+        self.cells == [a, b] || self.cells == [b, a]
+    }
+}
+
+impl Sudoku {
+    pub fn set_edges(&mut self, edges: Vec<Edge>) {
+        self.edges = edges;
+    }
+
+    /// Parse edges with [`Edge::parse_edges`] and attach them to the Sudoku.
+    pub fn set_edges_string(&mut self, s: &str) -> Result<(), SudokuError> {
+        let edges = Edge::parse_edges(s)?;
+        if let Some(edge) = edges.iter().find(|edge| {
+            edge.cells
+                .iter()
+                .any(|&(r, c)| r >= self.size() || c >= self.size())
+        }) {
+            return Err(SudokuError::InvalidBoard(format!(
+                "Edge {:?} lies outside the grid",
+                edge.cells
+            )));
+        }
+        self.edges = edges;
+        Ok(())
+    }
+
+    fn has_edge_rules(&self) -> bool {
+        !self.edges.is_empty()
+            || self.constraints.contains(Constraints::NEGATIVE_XV)
+            || self.constraints.contains(Constraints::NEGATIVE_KROPKI)
+    }
+
+    /// Kinds of the XV (`xv == true`) or Kropki clues between two adjacent cells.
+    fn edge_kinds_between(&self, a: (usize, usize), b: (usize, usize), xv: bool) -> Vec<EdgeKind> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.kind.is_xv() == xv && edge.connects(a, b))
+            .map(|edge| edge.kind)
+            .collect()
+    }
+
+    /// Check if two adjacent cells may hold `num_a` and `num_b` given the XV
+    /// (`xv == true`) or Kropki clues between them, or the negative constraint if
+    /// there's no such clue.
+    fn edge_kinds_allow(&self, kinds: &[EdgeKind], num_a: u8, num_b: u8, xv: bool) -> bool {
+        if !kinds.is_empty() {
+            return kinds.iter().all(|kind| kind.allows(num_a, num_b));
+        }
+        if xv && self.constraints.contains(Constraints::NEGATIVE_XV) {
+            return !EdgeKind::X.allows(num_a, num_b) && !EdgeKind::V.allows(num_a, num_b);
+        }
+        if !xv && self.constraints.contains(Constraints::NEGATIVE_KROPKI) {
+            return !EdgeKind::WhiteDot.allows(num_a, num_b)
+                && !EdgeKind::BlackDot.allows(num_a, num_b);
+        }
+        true
+    }
+
+    /// Orthogonally adjacent cells of the given cell.
+    fn neighbors(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut cells = Vec::with_capacity(4);
+        if row > 0 {
+            cells.push((row - 1, col));
+        }
+        if row + 1 < self.size() {
+            cells.push((row + 1, col));
+        }
+        if col > 0 {
+            cells.push((row, col - 1));
+        }
+        if col + 1 < self.size() {
+            cells.push((row, col + 1));
+        }
+        cells
+    }
+
+    /// Check if `num` in the given cell is compatible with the digits in the adjacent cells.
+    pub(crate) fn edges_allow(&self, row: usize, col: usize, num: u8) -> bool {
+        if !self.has_edge_rules() {
+            return true;
+        }
+        self.neighbors(row, col).into_iter().all(|(r, c)| {
+            let other = self.board[r][c];
+            other == EMPTY
+                || [true, false].into_iter().all(|xv| {
+                    let kinds = self.edge_kinds_between((row, col), (r, c), xv);
+                    self.edge_kinds_allow(&kinds, num, other, xv)
+                })
+        })
+    }
+
+    /// Digits the cell may contain: its digit if filled, otherwise its candidates.
+    fn digits_of(&self, row: usize, col: usize) -> DigitSet {
+        if self.board[row][col] != EMPTY {
+            DigitSet::from([self.board[row][col]])
+        } else {
+            self.candidates[row][col]
+        }
+    }
+
+    /// Remove candidates that have no partner in an adjacent cell satisfying the XV
+    /// (`xv == true`) or Kropki clue between the two cells, or its negative constraint.
+    fn search_edge_elimination(
+        &self,
+        strategy: Strategy,
+        xv: bool,
+        hits: &mut Hits,
+    ) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(strategy);
+        let negative = if xv {
+            Constraints::NEGATIVE_XV
+        } else {
+            Constraints::NEGATIVE_KROPKI
+        };
+        let all_pairs = self.constraints.contains(negative);
+        let size = self.size();
+        for row in 0..size {
+            for col in 0..size {
+                for other in [(row, col + 1), (row + 1, col)] {
+                    if other.0 >= size || other.1 >= size {
+                        continue;
+                    }
+                    let a = (row, col);
+                    let kinds = self.edge_kinds_between(a, other, xv);
+                    if !all_pairs && kinds.is_empty() {
+                        continue;
+                    }
+                    for (cell, partner) in [(a, other), (other, a)] {
+                        if self.board[cell.0][cell.1] != EMPTY {
+                            continue;
+                        }
+                        let partner_digits = self.digits_of(partner.0, partner.1);
+                        for num in self.candidates[cell.0][cell.1] {
+                            if !partner_digits
+                                .iter()
+                                .any(|p| self.edge_kinds_allow(&kinds, num, p, xv))
+                            {
+                                result
+                                    .removals
+                                    .candidates_about_to_be_removed
+                                    .insert(Candidate {
+                                        row: cell.0,
+                                        col: cell.1,
+                                        num,
+                                    });
+                            }
+                        }
+                    }
+                    if result.removals.will_remove_candidates() {
+                        self.add_pattern_cells(&mut result.removals, [a, other]);
+                        hits.report(&mut result)?;
+                    }
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Remove candidates incompatible with the X and V clues (sums of 10 and 5).
+    pub fn find_xv(&self) -> StrategyResult {
+        self.first_hit(Strategy::XV, Sudoku::search_xv)
+    }
+
+    pub(crate) fn search_xv(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_edge_elimination(Strategy::XV, true, hits)
+    }
+
+    /// Remove candidates incompatible with the Kropki dots (consecutive digits for
+    /// white dots, a 1:2 ratio for black dots).
+    pub fn find_kropki(&self) -> StrategyResult {
+        self.first_hit(Strategy::Kropki, Sudoku::search_kropki)
+    }
+
+    pub(crate) fn search_kropki(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_edge_elimination(Strategy::Kropki, false, hits)
+    }
+}
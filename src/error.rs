@@ -0,0 +1,89 @@
+//! The error type shared by the fallible operations on a [`Sudoku`](crate::Sudoku):
+//! loading boards, clues and sessions, importing solutions, and solving.
+//!
+//! The narrower errors of single operations, e.g. [`PlacementError`] or the
+//! [`Error`] of the one-shot API, convert into it, so that code combining several
+//! operations can use `?` throughout.
+//!
+//! Which error an API returns: the one-shot API, i.e. [`rate`](crate::rate),
+//! [`solve`](crate::solve), [`Solver`](crate::Solver), [`Rerater`](crate::Rerater),
+//! [`RatedPuzzle::rate`](crate::RatedPuzzle::rate) and `verify_unique`, returns
+//! [`Error`], which tells whether a puzzle can't be read or has no unique solution.
+//! Everything else that can fail, including the parsers of the variant clues, returns
+//! [`SudokuError`].
+
+use crate::{ApplyError, Contradiction, Error, PlacementError, SolveOutcome};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use thiserror::Error;
+
+/// Reason why an operation on a Sudoku failed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum SudokuError {
+    /// The input can't be read, e.g. a board with a wrong number of cells or a
+    /// malformed cage definition
+    #[error("Invalid input: {0}")]
+    Parse(String),
+    /// The input is well-formed but doesn't fit the Sudoku, e.g. a solution of
+    /// another size or a digit breaking the rules
+    #[error("Invalid board: {0}")]
+    InvalidBoard(String),
+    /// The position can't be solved anymore
+    #[error("The position can't be solved: {0}")]
+    Contradiction(#[from] Contradiction),
+    /// The strategies got stuck, or the Sudoku has no solution at all
+    #[error("The Sudoku can't be solved")]
+    NotSolvable,
+    /// The solver ran out of steps or time, or was cancelled
+    #[error("The solver ran out of its budget")]
+    BudgetExceeded,
+    /// The operation isn't available for the variant, e.g. canonical forms of a
+    /// Killer Sudoku
+    #[error("Not supported for this variant: {0}")]
+    VariantUnsupported(String),
+    /// Reading or writing outside the library failed, e.g. drawing on the terminal
+    #[error("I/O error: {0}")]
+    Io(String),
+}
+
+impl From<Error> for SudokuError {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Parse(e) => SudokuError::Parse(e),
+            Error::Conflict | Error::MultipleSolutions => SudokuError::InvalidBoard(e.to_string()),
+            Error::NoSolution => SudokuError::NotSolvable,
+        }
+    }
+}
+
+impl From<PlacementError> for SudokuError {
+    fn from(e: PlacementError) -> Self {
+        SudokuError::InvalidBoard(e.to_string())
+    }
+}
+
+impl From<ApplyError> for SudokuError {
+    fn from(e: ApplyError) -> Self {
+        SudokuError::InvalidBoard(e.to_string())
+    }
+}
+
+impl SolveOutcome {
+    /// `Ok` if the Sudoku was solved, otherwise why not.
+    pub fn into_result(self) -> Result<(), SudokuError> {
+        match self {
+            SolveOutcome::Solved => Ok(()),
+            SolveOutcome::StuckAt(_) => Err(SudokuError::NotSolvable),
+            SolveOutcome::BudgetExceeded | SolveOutcome::Cancelled => {
+                Err(SudokuError::BudgetExceeded)
+            }
+            SolveOutcome::Contradiction(contradiction) => {
+                Err(SudokuError::Contradiction(contradiction))
+            }
+            SolveOutcome::MultipleSolutions => Err(SudokuError::InvalidBoard(
+                "The Sudoku has more than one solution".to_string(),
+            )),
+        }
+    }
+}
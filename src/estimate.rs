@@ -0,0 +1,78 @@
+//! Guessing the difficulty of a puzzle from its givens and first notes, without solving
+//! it, e.g. to pick the likely hard puzzles out of a huge corpus before rating them.
+
+use crate::{DifficultyLevel, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Weights of the features in the predicted difficulty, fitted by least squares to the
+/// difficulties of generated 9×9 puzzles: the intercept, then the share of givens, the
+/// candidates per empty cell relative to the grid size, and the shares of empty cells
+/// with one and with two candidates.
+const WEIGHTS: [f64; 5] = [7.4, -1.0, 4.7, -23.4, -5.6];
+
+/// What the first notes of a puzzle look like, and the difficulty they suggest, see
+/// [`Sudoku::estimate`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Estimate {
+    pub givens: usize,
+    /// Average number of candidates of the empty cells
+    pub candidates_per_cell: f64,
+    /// Share of the empty cells with a single candidate, from 0 to 1
+    pub single_density: f64,
+    /// Share of the empty cells with exactly two candidates, from 0 to 1
+    pub bivalue_density: f64,
+    /// Predicted difficulty, on the scale of [`Sudoku::difficulty`]
+    pub difficulty: f64,
+}
+
+impl Estimate {
+    /// The level of the predicted difficulty.
+    pub fn level(&self) -> DifficultyLevel {
+        DifficultyLevel::of(self.difficulty)
+    }
+}
+
+impl Sudoku {
+    /// Predict the difficulty from the number of givens and the notes the givens leave,
+    /// without running a strategy. This takes a fraction of the time of a solve, but
+    /// only roughly agrees with the rating: about two thirds of the puzzles get the
+    /// level of their actual difficulty, and predictions stay closer to the middle of
+    /// the scale than the ratings. Digits placed since are ignored.
+    pub fn estimate(&self) -> Estimate {
+        let mut puzzle = self.clone();
+        puzzle.board = self.original_board.clone();
+        puzzle.calc_all_notes();
+        let size = puzzle.size();
+        let counts: Vec<usize> = puzzle
+            .empty_cells()
+            .map(|c| puzzle.candidates[c.row][c.col].len())
+            .collect();
+        let empty = counts.len().max(1) as f64;
+        let share = |count: usize| counts.iter().filter(|&&n| n == count).count() as f64 / empty;
+        let givens = puzzle.givens_count();
+        let candidates_per_cell = counts.iter().sum::<usize>() as f64 / empty;
+        let single_density = share(1);
+        let bivalue_density = share(2);
+        let features = [
+            1.0,
+            givens as f64 / (size * size) as f64,
+            candidates_per_cell / size as f64,
+            single_density,
+            bivalue_density,
+        ];
+        let difficulty = if counts.is_empty() {
+            0.0
+        } else {
+            WEIGHTS.iter().zip(features).map(|(w, x)| w * x).sum()
+        };
+        Estimate {
+            givens,
+            candidates_per_cell,
+            single_density,
+            bivalue_density,
+            difficulty,
+        }
+    }
+}
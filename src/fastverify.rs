@@ -0,0 +1,194 @@
+//! Bit-parallel uniqueness check for classic 9×9 puzzles, to verify large corpora
+//! quickly. The 81 cells fit into a `u128`, so each digit's possible cells are one
+//! word, and placing a digit or finding singles touches whole units at once.
+//!
+//! Enabled by the `fast-verify` feature. Only the rules of classic Sudoku apply; use
+//! [`Sudoku::count_solutions`](crate::Sudoku::count_solutions) for variants.
+
+use crate::oneshot::parse_board;
+use crate::{Error, GridSpec};
+use std::sync::LazyLock;
+
+const SIZE: usize = 9;
+const CELLS: usize = SIZE * SIZE;
+const ALL_CELLS: u128 = (1 << CELLS) - 1;
+
+/// Rows, columns and boxes as sets of cells.
+static UNITS: LazyLock<[u128; 3 * SIZE]> = LazyLock::new(|| {
+    let spec = GridSpec::CLASSIC;
+    let mut units = [0; 3 * SIZE];
+    for cell in 0..CELLS {
+        let (row, col) = (cell / SIZE, cell % SIZE);
+        units[row] |= 1 << cell;
+        units[SIZE + col] |= 1 << cell;
+        units[2 * SIZE + spec.box_index(row, col)] |= 1 << cell;
+    }
+    units
+});
+
+/// Cells sharing a unit with each cell, including the cell itself.
+static PEERS: LazyLock<[u128; CELLS]> = LazyLock::new(|| {
+    let mut peers = [0; CELLS];
+    for (cell, peers) in peers.iter_mut().enumerate() {
+        *peers = UNITS
+            .iter()
+            .filter(|&&unit| unit & 1 << cell != 0)
+            .fold(0, |peers, unit| peers | unit);
+    }
+    peers
+});
+
+/// Search state: for each digit the cells it may still go into, and the cells it's in.
+#[derive(Clone, Copy)]
+struct Grid {
+    allowed: [u128; SIZE],
+    placed: [u128; SIZE],
+    empty: u128,
+}
+
+impl Grid {
+    /// Returns `None` if the givens repeat a digit in a unit.
+    fn new(values: &[Vec<u8>]) -> Option<Grid> {
+        let mut grid = Grid {
+            allowed: [ALL_CELLS; SIZE],
+            placed: [0; SIZE],
+            empty: ALL_CELLS,
+        };
+        for (cell, &num) in values.iter().flatten().enumerate() {
+            if num != 0 {
+                let digit = num as usize - 1;
+                if grid.allowed[digit] & 1 << cell == 0 {
+                    return None;
+                }
+                grid.place(cell, digit);
+            }
+        }
+        Some(grid)
+    }
+
+    fn place(&mut self, cell: usize, digit: usize) {
+        let bit = 1 << cell;
+        self.empty &= !bit;
+        for allowed in &mut self.allowed {
+            *allowed &= !bit;
+        }
+        self.allowed[digit] &= !PEERS[cell];
+        self.placed[digit] |= bit;
+    }
+
+    /// Place naked and hidden singles until there are none left. Returns `false` on a
+    /// contradiction.
+    fn propagate(&mut self) -> bool {
+        loop {
+            // Cells with at least one, and with at least two digits, counted for all
+            // cells at once
+            let (mut once, mut twice) = (0u128, 0u128);
+            for &allowed in &self.allowed {
+                twice |= once & allowed;
+                once |= allowed;
+            }
+            if self.empty & !once != 0 {
+                return false;
+            }
+            let naked = self.empty & once & !twice;
+            if naked != 0 {
+                let cell = naked.trailing_zeros() as usize;
+                let digit = (0..SIZE)
+                    .find(|&digit| self.allowed[digit] & 1 << cell != 0)
+                    .unwrap();
+                self.place(cell, digit);
+                continue;
+            }
+            let mut progress = false;
+            for digit in 0..SIZE {
+                for &unit in UNITS.iter() {
+                    if self.placed[digit] & unit != 0 {
+                        continue;
+                    }
+                    let cells = self.allowed[digit] & unit;
+                    match cells.count_ones() {
+                        0 => return false,
+                        1 => {
+                            self.place(cells.trailing_zeros() as usize, digit);
+                            progress = true;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if !progress {
+                return true;
+            }
+        }
+    }
+
+    /// Count the solutions, stopping at `limit`.
+    fn count(mut self, limit: usize) -> usize {
+        if !self.propagate() {
+            return 0;
+        }
+        if self.empty == 0 {
+            return 1;
+        }
+        // Branch on the empty cell with the fewest digits
+        let mut best = (usize::MAX, 0);
+        let mut empty = self.empty;
+        while empty != 0 {
+            let cell = empty.trailing_zeros() as usize;
+            empty &= empty - 1;
+            let options = self
+                .allowed
+                .iter()
+                .filter(|&&allowed| allowed & 1 << cell != 0)
+                .count();
+            if options < best.0 {
+                best = (options, cell);
+            }
+        }
+        let cell = best.1;
+        let mut found = 0;
+        for digit in 0..SIZE {
+            if self.allowed[digit] & 1 << cell != 0 {
+                let mut branch = self;
+                branch.place(cell, digit);
+                found += branch.count(limit - found);
+                if found >= limit {
+                    break;
+                }
+            }
+        }
+        found
+    }
+}
+
+/// Check that the classic 9×9 puzzle, given in the format of
+/// [`Sudoku::set_board_string`](crate::Sudoku::set_board_string), has exactly one
+/// solution.
+pub fn verify_unique(puzzle: &str) -> Result<(), Error> {
+    let board = parse_board(puzzle)?;
+    if board.spec != GridSpec::CLASSIC {
+        return Err(Error::Parse("must be a 9×9 grid".to_string()));
+    }
+    let grid = Grid::new(&board.values).ok_or(Error::Conflict)?;
+    match grid.count(2) {
+        0 => Err(Error::NoSolution),
+        1 => Ok(()),
+        _ => Err(Error::MultipleSolutions),
+    }
+}
+
+/// Check each puzzle with [`verify_unique`], on all cores with the `parallel` feature.
+pub fn verify_batch(puzzles: &[&str]) -> Vec<Result<(), Error>> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        puzzles
+            .par_iter()
+            .map(|puzzle| verify_unique(puzzle))
+            .collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        puzzles.iter().map(|puzzle| verify_unique(puzzle)).collect()
+    }
+}
@@ -0,0 +1,227 @@
+//! Table-driven tests for the strategies: positions labeled with the step a detector
+//! must find in them, read from data files, so that every strategy gets regression
+//! tests by adding a few lines of data.
+//!
+//! A fixtures file holds blocks separated by empty lines, each made of `key: value`
+//! lines. Lines starting with `#` are comments.
+//!
+//! ```text
+//! name: claiming pair in row 2
+//! strategy: ClaimingPair
+//! board: 318005406000603810006080503864952137123476958795318264030500780000007305000039641
+//! eliminations: r3c2<>7
+//! ```
+//!
+//! - `strategy` is the name of the [`Strategy`] variant whose detector runs.
+//! - The position is either a `board`, whose notes are calculated from its digits, or a
+//!   `pencilmarks` grid on one line, see [`Sudoku::from_pencilmarks`]. Variant clues
+//!   can't be given, so the fixtures cover the strategies of classic Sudokus.
+//! - `eliminations` lists every candidate the first step found must remove, separated
+//!   by spaces or commas, or is `none` if the detector must not find a step.
+//! - `places` is the digit the step must place, e.g. `r1c5=3`. With it, the
+//!   eliminations may be left out to check the placement only.
+//! - `name` is optional and shows up in failures.
+
+use crate::analysis::SEARCHES;
+use crate::{Candidate, Cell, Strategy, Sudoku};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+/// A position with the step a detector must find in it.
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    /// The `name` of the fixture, or its line number
+    pub name: String,
+    /// Line of the file the fixture starts on, counted from 1
+    pub line: usize,
+    pub strategy: Strategy,
+    /// The position, with its notes
+    pub position: Sudoku,
+    /// Candidates the step removes, `None` if they aren't checked. Empty if the
+    /// detector must not find a step.
+    pub eliminations: Option<BTreeSet<Candidate>>,
+    /// Digit the step places, if any
+    pub places: Option<Cell>,
+}
+
+fn parse_list<T: std::str::FromStr<Err = String>>(value: &str) -> Result<Vec<T>, String> {
+    value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|item| !item.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+impl Fixture {
+    /// Read a fixture from its `key: value` lines, the first of them on line `line`.
+    fn parse(lines: &[(usize, &str)]) -> Result<Fixture, String> {
+        let line = lines[0].0;
+        let mut name = None;
+        let mut strategy = None;
+        let mut position = None;
+        let mut eliminations = None;
+        let mut places = None;
+        for &(number, text) in lines {
+            let error = |e: String| format!("Line {}: {}", number, e);
+            let (key, value) = text
+                .split_once(':')
+                .ok_or_else(|| error(format!("Expected 'key: value', found '{}'", text)))?;
+            let value = value.trim();
+            match key.trim() {
+                "name" => name = Some(value.to_string()),
+                "strategy" => {
+                    let found = Strategy::ALL
+                        .iter()
+                        .find(|strategy| format!("{:?}", strategy) == value)
+                        .ok_or_else(|| error(format!("Unknown strategy: {}", value)))?;
+                    strategy = Some(found.clone());
+                }
+                "board" => {
+                    let mut sudoku = value.parse::<Sudoku>().map_err(error)?;
+                    sudoku.calc_all_notes();
+                    position = Some(sudoku);
+                }
+                "pencilmarks" => {
+                    let sudoku =
+                        Sudoku::from_pencilmarks(value).map_err(|e| error(e.to_string()))?;
+                    position = Some(sudoku);
+                }
+                "eliminations" if value == "none" => eliminations = Some(BTreeSet::new()),
+                "eliminations" => {
+                    eliminations = Some(parse_list(value).map_err(error)?.into_iter().collect());
+                }
+                "places" => places = Some(value.parse::<Cell>().map_err(error)?),
+                other => return Err(error(format!("Unknown key: {}", other))),
+            }
+        }
+        let missing = |key: &str| format!("Line {}: fixture without {}", line, key);
+        if eliminations.is_none() && places.is_none() {
+            return Err(missing("eliminations or places"));
+        }
+        Ok(Fixture {
+            name: name.unwrap_or_else(|| format!("line {}", line)),
+            line,
+            strategy: strategy.ok_or_else(|| missing("strategy"))?,
+            position: position.ok_or_else(|| missing("board or pencilmarks"))?,
+            eliminations,
+            places,
+        })
+    }
+
+    /// Run the detector of the strategy on the position and compare the first step it
+    /// finds with the expected one. The error describes the difference.
+    pub fn check(&self) -> Result<(), String> {
+        let (_, search) = SEARCHES
+            .into_iter()
+            .find(|(strategy, _)| *strategy == self.strategy)
+            .expect("every strategy has a detector");
+        let result = self.position.first_hit(self.strategy.clone(), search);
+        let removals = &result.removals;
+        let found = removals.will_remove_candidates();
+        let format = |candidates: &BTreeSet<Candidate>| {
+            let candidates: Vec<String> = candidates.iter().map(ToString::to_string).collect();
+            candidates.join(" ")
+        };
+        let mut problems = String::new();
+        if let Some(expected) = &self.eliminations {
+            if expected.is_empty() && found {
+                let _ = write!(
+                    problems,
+                    " expected no step, found one eliminating {};",
+                    format(&removals.candidates_about_to_be_removed)
+                );
+            } else if !expected.is_empty() && !found {
+                let _ = write!(problems, " expected a step, found none;");
+            } else if *expected != removals.candidates_about_to_be_removed {
+                let missing: BTreeSet<Candidate> = expected
+                    .difference(&removals.candidates_about_to_be_removed)
+                    .cloned()
+                    .collect();
+                let extra: BTreeSet<Candidate> = removals
+                    .candidates_about_to_be_removed
+                    .difference(expected)
+                    .cloned()
+                    .collect();
+                if !missing.is_empty() {
+                    let _ = write!(problems, " eliminations missing: {};", format(&missing));
+                }
+                if !extra.is_empty() {
+                    let _ = write!(problems, " unexpected eliminations: {};", format(&extra));
+                }
+            }
+        }
+        if let Some(places) = self
+            .places
+            .as_ref()
+            .filter(|&places| removals.sets_cell.as_ref() != Some(places))
+        {
+            let _ = write!(
+                problems,
+                " expected to place {}, found {};",
+                places,
+                removals
+                    .sets_cell
+                    .as_ref()
+                    .map_or("no placement".to_string(), ToString::to_string)
+            );
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} ({:?}):{}",
+                self.name,
+                self.strategy,
+                problems.trim_end_matches(';')
+            ))
+        }
+    }
+}
+
+/// Read the fixtures of a file's text, see the [module documentation](self) for the
+/// format.
+pub fn parse_fixtures(text: &str) -> Result<Vec<Fixture>, String> {
+    let mut fixtures = Vec::new();
+    let mut block: Vec<(usize, &str)> = Vec::new();
+    for (number, line) in text.lines().map(str::trim).enumerate() {
+        if line.starts_with('#') {
+            continue;
+        }
+        if !line.is_empty() {
+            block.push((number + 1, line));
+        } else if !block.is_empty() {
+            fixtures.push(Fixture::parse(&block)?);
+            block.clear();
+        }
+    }
+    if !block.is_empty() {
+        fixtures.push(Fixture::parse(&block)?);
+    }
+    Ok(fixtures)
+}
+
+/// Read the fixtures in a file, see [`parse_fixtures`].
+pub fn load_fixtures(path: impl AsRef<Path>) -> Result<Vec<Fixture>, String> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Can't read {}: {}", path.display(), e))?;
+    parse_fixtures(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Check every fixture in the file, for use in a `#[test]`. Panics if the file can't be
+/// read, or with the failures of all fixtures that fail.
+pub fn assert_fixtures(path: impl AsRef<Path>) {
+    let fixtures = load_fixtures(path).unwrap_or_else(|e| panic!("{}", e));
+    let failures: Vec<String> = fixtures
+        .iter()
+        .filter_map(|fixture| fixture.check().err())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} fixtures failed:\n{}",
+        failures.len(),
+        fixtures.len(),
+        failures.join("\n")
+    );
+}
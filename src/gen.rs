@@ -8,11 +8,19 @@ fn main() {
     } else {
         default_filled_cells
     };
+    // Optionally make each puzzle harder by hill climbing
+    let iterations = args.get(2).and_then(|arg| arg.parse::<usize>().ok());
+    let mut rng = rand::rng();
     loop {
-        if let Some(sudoku) = Sudoku::generate(filled_cells) {
+        let generated = Sudoku::generate(filled_cells);
+        let generated = match iterations {
+            Some(iterations) => generated.and_then(|sudoku| sudoku.harden(&mut rng, iterations)),
+            None => generated,
+        };
+        if let Some(sudoku) = generated {
             let sudoku_string = sudoku.serialized();
             let mut sudoku = sudoku;
-            if sudoku.solve_human_like() {
+            if sudoku.solve_human_like().is_solved() {
                 println!("{:6.2} {}", sudoku.difficulty(), sudoku_string);
             } else {
                 println!("FAILED {}", sudoku_string);
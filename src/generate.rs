@@ -0,0 +1,206 @@
+//! Generating puzzles with a unique solution.
+
+use crate::{
+    Board, CancelToken, DifficultyRange, EMPTY, GridSpec, RatedPuzzle, Sudoku, SudokuError,
+    Symmetry,
+};
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// How [`Sudoku::dig`] turns a solution into a puzzle.
+#[derive(Debug, Clone, Default)]
+pub struct DigOptions {
+    /// Stop removing givens at this number. With 0, givens are removed as long as the
+    /// solution stays unique.
+    pub min_givens: usize,
+    pub symmetry: Symmetry,
+}
+
+impl DigOptions {
+    pub fn new() -> DigOptions {
+        DigOptions::default()
+    }
+
+    pub fn with_min_givens(self, min_givens: usize) -> DigOptions {
+        DigOptions { min_givens, ..self }
+    }
+
+    pub fn with_symmetry(self, symmetry: Symmetry) -> DigOptions {
+        DigOptions { symmetry, ..self }
+    }
+}
+
+impl Sudoku {
+    /// Generates a new Sudoku puzzle with a given number of filled cells.
+    /// The puzzle is guaranteed to have a unique solution.
+    pub fn generate(filled_cells: usize) -> Option<Self> {
+        Sudoku::generate_with_spec(GridSpec::CLASSIC, filled_cells)
+    }
+
+    /// Generates a new Sudoku puzzle of the given grid size with a given number of filled cells.
+    /// The puzzle is guaranteed to have a unique solution.
+    pub fn generate_with_spec(spec: GridSpec, filled_cells: usize) -> Option<Self> {
+        Sudoku::generate_cancellable(spec, filled_cells, &CancelToken::new())
+    }
+
+    /// Like [`Sudoku::generate_with_spec`], but gives up with `None` as soon as `cancel`
+    /// is triggered.
+    pub fn generate_cancellable(
+        spec: GridSpec,
+        filled_cells: usize,
+        cancel: &CancelToken,
+    ) -> Option<Self> {
+        let mut rng = rand::rng();
+        let mut sudoku = Sudoku::with_spec(spec);
+        let cell_count = spec.cell_count();
+        sudoku.board = sudoku.random_solution(&mut rng)?;
+
+        // Make a copy of the solved board
+        let solved_board = sudoku.board.clone();
+        sudoku.original_board = solved_board;
+
+        // Start with a fully solved puzzle and progressively remove cells
+        let mut cells_to_remove = cell_count.saturating_sub(filled_cells);
+        let mut removed_cells = Vec::new();
+
+        // Get all filled cells that haven't been removed yet
+        let mut available_cells: Vec<(usize, usize)> = (0..spec.size())
+            .flat_map(|row| (0..spec.size()).map(move |col| (row, col)))
+            .filter(|&(row, col)| {
+                sudoku.board[row][col] != EMPTY && !removed_cells.contains(&(row, col))
+            })
+            .collect();
+        while cells_to_remove > 0 {
+            if cancel.is_cancelled() {
+                return None;
+            }
+            // No more cells to remove
+            if available_cells.is_empty() {
+                break;
+            }
+
+            // Choose a random cell to remove
+            // No more cells to remove
+            if available_cells.is_empty() {
+                break;
+            }
+
+            // If this is the first iteration, shuffle all available cells
+            if cells_to_remove == cell_count.saturating_sub(filled_cells) {
+                available_cells.shuffle(&mut rng);
+            }
+
+            // Take the last cell from the shuffled list
+            let (row, col) = available_cells.pop().unwrap();
+
+            sudoku.board[row][col] = EMPTY;
+
+            // Check if the puzzle still has a unique solution
+            // We only need to know if there's exactly one solution
+            let solution_count = sudoku.count_solutions(2);
+
+            if solution_count == 1 {
+                // Cell can be safely removed
+                removed_cells.push((row, col));
+                cells_to_remove -= 1;
+            } else {
+                return None;
+            }
+        }
+
+        Some(sudoku)
+    }
+
+    /// Dig a puzzle out of a complete solution, e.g. a hand-crafted one: try to remove
+    /// the givens in random order, together with their images under the symmetry, and
+    /// keep each removal that leaves the solution unique, until no more givens can be
+    /// removed or the minimum is reached. Fails if the solution isn't a complete grid
+    /// obeying the rules of classic Sudoku.
+    pub fn dig<R: Rng + ?Sized>(
+        solution: &Board,
+        options: &DigOptions,
+        rng: &mut R,
+    ) -> Result<Sudoku, SudokuError> {
+        let mut sudoku = Sudoku::from_board(solution);
+        if !sudoku.is_valid_solution() {
+            return Err(SudokuError::InvalidBoard(
+                "The solution must be a complete, valid grid".to_string(),
+            ));
+        }
+        sudoku.dig_until(solution, options, rng, |_| false);
+        Ok(sudoku)
+    }
+
+    /// Generate a puzzle whose difficulty lies in the range: dig a random solution of
+    /// the grid size like [`Sudoku::dig`], rating the puzzle after each given removed,
+    /// until it gets too hard for the range or the strategies, and return the last
+    /// puzzle in the range, i.e. the one with the fewest givens. Returns `None` if there
+    /// was none; callers usually try again. The same random number generator state
+    /// always leads to the same puzzle.
+    pub fn generate_in_range<R: Rng + ?Sized>(
+        spec: GridSpec,
+        options: &DigOptions,
+        range: &DifficultyRange,
+        rng: &mut R,
+    ) -> Option<RatedPuzzle> {
+        let solution = Board::from_givens(spec, Sudoku::with_spec(spec).random_solution(rng)?);
+        let mut sudoku = Sudoku::from_board(&solution);
+        let mut found = None;
+        sudoku.dig_until(&solution, options, rng, |puzzle| {
+            let rated =
+                RatedPuzzle::rate(&puzzle.serialized()).expect("serialized boards can be parsed");
+            if !rated.solved || rated.difficulty > range.max {
+                return true;
+            }
+            if range.contains(rated.difficulty) {
+                found = Some(rated);
+            }
+            false
+        });
+        found
+    }
+
+    /// Remove givens in random order, together with their images under the symmetry,
+    /// keeping each removal that leaves the solution unique, until no more givens can be
+    /// removed, the minimum is reached, or `stop` returns true for the puzzle after a
+    /// removal. The givens are set to the digits left.
+    fn dig_until<R: Rng + ?Sized>(
+        &mut self,
+        solution: &Board,
+        options: &DigOptions,
+        rng: &mut R,
+        mut stop: impl FnMut(&Sudoku) -> bool,
+    ) {
+        let size = self.size();
+        let mut givens = size * size;
+        let mut cells: Vec<(usize, usize)> = (0..size)
+            .flat_map(|row| (0..size).map(move |col| (row, col)))
+            .collect();
+        cells.shuffle(rng);
+        for (row, col) in cells {
+            let orbit: Vec<(usize, usize)> = options
+                .symmetry
+                .orbit(size, row, col)
+                .into_iter()
+                .filter(|&(row, col)| self.board[row][col] != EMPTY)
+                .collect();
+            if orbit.is_empty() || givens < options.min_givens + orbit.len() {
+                continue;
+            }
+            for &(row, col) in &orbit {
+                self.board[row][col] = EMPTY;
+            }
+            if self.count_solutions(2) == 1 {
+                givens -= orbit.len();
+                if stop(self) {
+                    break;
+                }
+            } else {
+                for &(row, col) in &orbit {
+                    self.board[row][col] = solution.get(row, col);
+                }
+            }
+        }
+        self.original_board = self.board.clone();
+    }
+}
@@ -0,0 +1,169 @@
+//! Grading a player's solving path against the solver, for trainers.
+
+use crate::{Candidate, EMPTY, PlacementError, Strategy, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// A move of the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Move {
+    /// Place the digit in the cell
+    Place { row: usize, col: usize, num: u8 },
+    /// Remove the digit from the notes of the cell
+    Eliminate { row: usize, col: usize, num: u8 },
+}
+
+/// Assessment of a single move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Verdict {
+    /// A step of the strategy justifies the move
+    Deduced(Strategy),
+    /// No strategy justifies the move in this position. `correct` tells whether the
+    /// move agrees with the solution anyway.
+    Guess { correct: bool },
+    /// The move changes nothing, e.g. removing a candidate that's already gone
+    Redundant,
+    /// The move breaks the rules and was skipped
+    Invalid(PlacementError),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GradedMove {
+    pub played: Move,
+    pub verdict: Verdict,
+}
+
+/// Result of grading a solving path.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Grade {
+    /// The moves in the order they were played
+    pub moves: Vec<GradedMove>,
+    /// Number of steps the solver needs from the same position
+    pub optimal_steps: usize,
+    /// Ratio of the solver's steps to the moves that changed something, at most 1. Only
+    /// meaningful for a complete solve.
+    pub efficiency: f64,
+}
+
+impl Grade {
+    /// Number of moves no strategy justified.
+    pub fn guesses(&self) -> usize {
+        self.moves
+            .iter()
+            .filter(|graded| matches!(graded.verdict, Verdict::Guess { .. }))
+            .count()
+    }
+
+    /// Number of moves contradicting the solution.
+    pub fn mistakes(&self) -> usize {
+        self.moves
+            .iter()
+            .filter(|graded| graded.verdict == Verdict::Guess { correct: false })
+            .count()
+    }
+}
+
+impl Sudoku {
+    /// Grade the player's moves, starting from the current board with freshly calculated
+    /// notes. As in the solver, placing a digit removes it from the notes of its peers.
+    /// Each move is checked against all steps the strategies could take right before
+    /// it, and against the solution.
+    pub fn grade(&self, moves: &[Move]) -> Grade {
+        let mut sudoku = self.clone();
+        sudoku.calc_all_notes();
+        let solution = sudoku.solutions().next();
+        let optimal_steps = sudoku.solver_steps();
+        let mut graded = Vec::with_capacity(moves.len());
+        for &played in moves {
+            let verdict = sudoku.judge(played, solution.as_deref());
+            if !matches!(verdict, Verdict::Redundant | Verdict::Invalid(_)) {
+                match played {
+                    Move::Place { row, col, num } => {
+                        sudoku.set_cell(row, col, num).expect("valid placement");
+                    }
+                    Move::Eliminate { row, col, num } => {
+                        sudoku.candidates[row][col].remove(&num);
+                    }
+                }
+            }
+            graded.push(GradedMove { played, verdict });
+        }
+        let effective = graded
+            .iter()
+            .filter(|graded| !matches!(graded.verdict, Verdict::Redundant | Verdict::Invalid(_)))
+            .count();
+        let efficiency = if effective == 0 {
+            0.0
+        } else {
+            (optimal_steps as f64 / effective as f64).min(1.0)
+        };
+        Grade {
+            moves: graded,
+            optimal_steps,
+            efficiency,
+        }
+    }
+
+    /// Assess the move in the current position without making it.
+    fn judge(&self, played: Move, solution: Option<&[Vec<u8>]>) -> Verdict {
+        let (row, col, num, place) = match played {
+            Move::Place { row, col, num } => (row, col, num, true),
+            Move::Eliminate { row, col, num } => (row, col, num, false),
+        };
+        if row >= self.size() || col >= self.size() {
+            return Verdict::Invalid(PlacementError::OutOfBounds);
+        }
+        if num == EMPTY || num as usize > self.size() {
+            return Verdict::Invalid(PlacementError::InvalidDigit(num));
+        }
+        if place {
+            if self.board[row][col] != EMPTY {
+                return Verdict::Invalid(PlacementError::Occupied);
+            }
+            if !self.can_place(row, col, num) {
+                return Verdict::Invalid(PlacementError::Conflict);
+            }
+        } else if self.board[row][col] != EMPTY || !self.candidates[row][col].contains(&num) {
+            return Verdict::Redundant;
+        }
+        let strategy = if place {
+            self.is_forced(row, col, num)
+        } else {
+            let candidate = Candidate { row, col, num };
+            self.analyze()
+                .strategies
+                .into_iter()
+                .flat_map(|(_, steps)| steps)
+                .find(|step| step.eliminates(&candidate))
+                .map(|step| step.strategy)
+        };
+        match strategy {
+            Some(strategy) => Verdict::Deduced(strategy),
+            None => Verdict::Guess {
+                correct: solution.is_some_and(|solution| (solution[row][col] == num) == place),
+            },
+        }
+    }
+
+    /// Number of steps the human-like solver takes from the current position, plus the
+    /// number of cells it leaves empty.
+    fn solver_steps(&self) -> usize {
+        let mut sudoku = self.clone();
+        let mut steps = 0;
+        while sudoku.unsolved() {
+            let result = sudoku.next_step();
+            if result.strategy == Strategy::None {
+                return steps + sudoku.empty_cells().count();
+            }
+            sudoku
+                .apply(&result)
+                .expect("steps found on the board fit it");
+            steps += 1;
+        }
+        steps
+    }
+}
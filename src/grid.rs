@@ -0,0 +1,122 @@
+//! Board geometry: the size of the grid and the shape of its boxes.
+
+use crate::EMPTY;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Shape of a Sudoku grid. The grid has `box_rows * box_cols` rows and columns,
+/// and as many boxes of `box_rows` × `box_cols` cells; the digits range from 1 to
+/// the grid size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GridSpec {
+    pub box_rows: usize,
+    pub box_cols: usize,
+}
+
+impl GridSpec {
+    /// 6×6 grid with boxes of 2 rows and 3 columns
+    pub const MINI: GridSpec = GridSpec {
+        box_rows: 2,
+        box_cols: 3,
+    };
+    /// The classic 9×9 grid
+    pub const CLASSIC: GridSpec = GridSpec {
+        box_rows: 3,
+        box_cols: 3,
+    };
+    /// 16×16 grid with 4×4 boxes, written in hexadecimal notation
+    pub const HEXA: GridSpec = GridSpec {
+        box_rows: 4,
+        box_cols: 4,
+    };
+
+    /// Number of rows, columns, boxes and digits.
+    pub fn size(&self) -> usize {
+        self.box_rows * self.box_cols
+    }
+
+    pub fn cell_count(&self) -> usize {
+        self.size() * self.size()
+    }
+
+    /// Sum of the digits in a complete row, column, or box.
+    pub fn house_sum(&self) -> u32 {
+        let size = self.size() as u32;
+        size * (size + 1) / 2
+    }
+
+    /// Guess the grid from the number of cells of a serialized board.
+    pub fn from_cell_count(count: usize) -> Option<GridSpec> {
+        [GridSpec::MINI, GridSpec::CLASSIC, GridSpec::HEXA]
+            .into_iter()
+            .find(|spec| spec.cell_count() == count)
+    }
+
+    /// Index of the box the cell belongs to, counting boxes in row-major order.
+    pub fn box_index(&self, row: usize, col: usize) -> usize {
+        let boxes_per_row = self.size() / self.box_cols;
+        (row / self.box_rows) * boxes_per_row + col / self.box_cols
+    }
+
+    /// Box index of each cell.
+    pub fn standard_regions(&self) -> Vec<Vec<usize>> {
+        (0..self.size())
+            .map(|row| {
+                (0..self.size())
+                    .map(|col| self.box_index(row, col))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Cells of each box, in row-major order.
+    pub fn standard_region_cells(&self) -> Vec<Vec<(usize, usize)>> {
+        let boxes_per_row = self.size() / self.box_cols;
+        (0..self.size())
+            .map(|box_index| {
+                let start_row = (box_index / boxes_per_row) * self.box_rows;
+                let start_col = (box_index % boxes_per_row) * self.box_cols;
+                (0..self.size())
+                    .map(|i| (start_row + i / self.box_cols, start_col + i % self.box_cols))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Read a cell of a serialized board. Grids up to 9×9 use the digits themselves
+    /// with `0` or `.` for empty cells; 16×16 grids use `0`–`9` and `A`–`F` for the
+    /// digits 1 to 16 and `.` for empty cells. Returns `None` for an invalid character.
+    pub fn parse_cell(&self, c: char) -> Option<u8> {
+        if c == '.' {
+            return Some(EMPTY);
+        }
+        if self.size() > 9 {
+            let value = c.to_digit(16)? as usize + 1;
+            return (value <= self.size()).then_some(value as u8);
+        }
+        let value = c.to_digit(10)? as usize;
+        (value <= self.size()).then_some(value as u8)
+    }
+
+    /// Character representing the digit in a serialized board, the inverse of [`GridSpec::parse_cell`].
+    pub fn digit_char(&self, num: u8) -> char {
+        if self.size() > 9 {
+            if num == EMPTY {
+                '.'
+            } else {
+                char::from_digit(num as u32 - 1, 16)
+                    .unwrap()
+                    .to_ascii_uppercase()
+            }
+        } else {
+            (num + b'0') as char
+        }
+    }
+}
+
+impl Default for GridSpec {
+    fn default() -> Self {
+        GridSpec::CLASSIC
+    }
+}
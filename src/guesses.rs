@@ -0,0 +1,62 @@
+//! Measuring how far a position is beyond the strategies: how many guesses a solver
+//! needs that applies the strategies wherever it can and guesses only when stuck.
+
+use crate::{SolveOutcome, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Guesses needed to solve a position, see [`Sudoku::guess_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GuessStats {
+    /// Guesses made in total, including the wrong ones
+    pub guesses: usize,
+    /// Guesses on the way to the solution, i.e. the nesting of the right guesses. 0 if
+    /// the strategies solve the position.
+    pub depth: usize,
+    /// Deepest nesting of guesses tried
+    pub max_depth: usize,
+    /// Most digits to choose from at a guess
+    pub max_branching: usize,
+    /// Whether a solution was found
+    pub solved: bool,
+}
+
+impl Sudoku {
+    /// Solve a copy of the Sudoku with the human-like solver, and whenever it gets
+    /// stuck, guess a digit of the empty cell with the fewest options and go on from
+    /// there, trying the digits in ascending order. A secondary measure of hardness
+    /// for positions the strategies can't solve.
+    pub fn guess_stats(&self) -> GuessStats {
+        let mut stats = GuessStats::default();
+        if let Some(depth) = self.clone().solve_guessing(0, &mut stats) {
+            stats.depth = depth;
+            stats.solved = true;
+        }
+        stats
+    }
+
+    /// Returns the number of guesses nested on the way to the solution, or `None` if
+    /// the position has no solution.
+    fn solve_guessing(&mut self, depth: usize, stats: &mut GuessStats) -> Option<usize> {
+        match self.solve_human_like() {
+            SolveOutcome::Solved => return Some(depth),
+            SolveOutcome::StuckAt(_) => {}
+            _ => return None,
+        }
+        let (row, col, nums) = self.most_constrained_cell()?;
+        stats.max_branching = stats.max_branching.max(nums.len());
+        for num in nums {
+            stats.guesses += 1;
+            stats.max_depth = stats.max_depth.max(depth + 1);
+            let mut guess = self.clone();
+            guess.undo_stack.clear();
+            guess.board[row][col] = num;
+            guess.candidates[row][col].clear();
+            if let Some(found) = guess.solve_guessing(depth + 1, stats) {
+                return Some(found);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,88 @@
+//! How hard each cell of a puzzle was to fill, for frontends to render as a heatmap
+//! over the grid.
+
+use crate::{Budget, Cell, SolveObserver, Strategy, StrategyResult, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// How a cell got its digit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Heat {
+    /// Strategy of the step placing the digit
+    pub strategy: Strategy,
+    /// Highest effort among the steps since the previous placement, including the
+    /// placement itself, i.e. of the hardest step needed to get to this digit
+    pub effort: i32,
+    /// Number of the placing step, counted from 1
+    pub step: usize,
+}
+
+/// Difficulty of each cell. Givens and cells the solver didn't fill have none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Heatmap {
+    pub cells: Vec<Vec<Option<Heat>>>,
+    /// Whether the solver filled every cell
+    pub solved: bool,
+}
+
+impl Heatmap {
+    pub fn get(&self, row: usize, col: usize) -> Option<&Heat> {
+        self.cells[row][col].as_ref()
+    }
+
+    /// Highest effort of any cell, to scale the colors by.
+    pub fn max_effort(&self) -> i32 {
+        self.cells
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|heat| heat.effort)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// Observer filling the heatmap while solving.
+struct HeatRecorder {
+    cells: Vec<Vec<Option<Heat>>>,
+    steps: usize,
+    hardest: i32,
+}
+
+impl SolveObserver for HeatRecorder {
+    fn on_step(&mut self, _sudoku: &Sudoku, step: &StrategyResult) {
+        self.steps += 1;
+        self.hardest = self.hardest.max(step.strategy.difficulty());
+        if let Some(Cell { row, col, .. }) = step.removals.sets_cell {
+            self.cells[row][col] = Some(Heat {
+                strategy: step.strategy.clone(),
+                effort: self.hardest,
+                step: self.steps,
+            });
+            self.hardest = 0;
+        }
+    }
+}
+
+impl Sudoku {
+    /// Solve a copy of the Sudoku with the human-like solver and record for each cell
+    /// how hard it was to fill.
+    pub fn heatmap(&self) -> Heatmap {
+        let mut sudoku = self.clone();
+        let size = self.size();
+        let mut recorder = HeatRecorder {
+            cells: vec![vec![None; size]; size],
+            steps: 0,
+            hardest: 0,
+        };
+        let solved = sudoku
+            .solve_human_like_observed(&Budget::unlimited(), &mut recorder)
+            .is_solved();
+        Heatmap {
+            cells: recorder.cells,
+            solved,
+        }
+    }
+}
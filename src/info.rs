@@ -0,0 +1,182 @@
+//! Documentation of the strategies, e.g. for "learn more" panels next to a hint.
+
+use crate::Strategy;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+/// Family of techniques a strategy belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum StrategyCategory {
+    /// Placing a digit that is the only option for a cell or a unit
+    Single,
+    /// Digits of a box confined to a row or column, or the other way round
+    Intersection,
+    /// Cells or digits locked to each other within a unit
+    Pair,
+    /// Digits confined to the same rows in several columns, or vice versa
+    Fish,
+    /// Chains of implications between candidates
+    Chain,
+    /// Patterns that would allow more than one solution
+    Uniqueness,
+    /// Reasoning about the clues of a variant, e.g. cages or thermometers
+    Variant,
+}
+
+impl fmt::Display for StrategyCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StrategyCategory::Single => write!(f, "single"),
+            StrategyCategory::Intersection => write!(f, "intersection"),
+            StrategyCategory::Pair => write!(f, "pair"),
+            StrategyCategory::Fish => write!(f, "fish"),
+            StrategyCategory::Chain => write!(f, "chain"),
+            StrategyCategory::Uniqueness => write!(f, "uniqueness"),
+            StrategyCategory::Variant => write!(f, "variant"),
+        }
+    }
+}
+
+/// What a strategy does and where to read more about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct StrategyInfo {
+    pub category: StrategyCategory,
+    /// One or two sentences in English
+    pub description: &'static str,
+    /// A page explaining the technique, if there's a well-known one
+    pub url: Option<&'static str>,
+}
+
+const HODOKU_SINGLES: &str = "https://hodoku.sourceforge.net/en/tech_singles.php";
+const HODOKU_INTERSECTIONS: &str = "https://hodoku.sourceforge.net/en/tech_intersections.php";
+const HODOKU_NAKED: &str = "https://hodoku.sourceforge.net/en/tech_naked.php";
+const HODOKU_HIDDEN: &str = "https://hodoku.sourceforge.net/en/tech_hidden.php";
+
+impl Strategy {
+    /// Documentation of the strategy. [`Strategy::None`] is described as a single, as
+    /// it stands for no step at all.
+    pub fn info(&self) -> StrategyInfo {
+        let (category, description, url) = match self {
+            Strategy::None => (StrategyCategory::Single, "No strategy applies.", None),
+            Strategy::LastDigit => (
+                StrategyCategory::Single,
+                "A unit has a single empty cell left, which takes the one missing digit.",
+                Some(HODOKU_SINGLES),
+            ),
+            Strategy::ObviousSingle => (
+                StrategyCategory::Single,
+                "A cell has a single candidate left, so that digit goes there.",
+                Some(HODOKU_SINGLES),
+            ),
+            Strategy::HiddenSingle => (
+                StrategyCategory::Single,
+                "A digit fits into only one cell of a unit, so it goes there.",
+                Some(HODOKU_SINGLES),
+            ),
+            Strategy::PointingPair => (
+                StrategyCategory::Intersection,
+                "The candidates for a digit in a box all lie in one row or column, so the \
+                 digit can be removed from the rest of that row or column.",
+                Some(HODOKU_INTERSECTIONS),
+            ),
+            Strategy::ClaimingPair => (
+                StrategyCategory::Intersection,
+                "The candidates for a digit in a row or column all lie in one box, so the \
+                 digit can be removed from the rest of that box.",
+                Some(HODOKU_INTERSECTIONS),
+            ),
+            Strategy::ObviousPair => (
+                StrategyCategory::Pair,
+                "Two cells of a unit have the same two candidates, so these digits can be \
+                 removed from the other cells of the unit.",
+                Some(HODOKU_NAKED),
+            ),
+            Strategy::HiddenPair => (
+                StrategyCategory::Pair,
+                "Two digits fit into the same two cells of a unit only, so the other \
+                 candidates can be removed from these cells.",
+                Some(HODOKU_HIDDEN),
+            ),
+            Strategy::ObviousTriple => (
+                StrategyCategory::Pair,
+                "Three cells of a unit have only three digits between them as candidates, \
+                 so these digits can be removed from the other cells of the unit.",
+                Some(HODOKU_NAKED),
+            ),
+            Strategy::HiddenTriple => (
+                StrategyCategory::Pair,
+                "Three digits fit into the same three cells of a unit only, so the other \
+                 candidates can be removed from these cells.",
+                Some(HODOKU_HIDDEN),
+            ),
+            Strategy::ObviousQuad => (
+                StrategyCategory::Pair,
+                "Four cells of a unit have only four digits between them as candidates, \
+                 so these digits can be removed from the other cells of the unit.",
+                Some(HODOKU_NAKED),
+            ),
+            Strategy::HiddenQuad => (
+                StrategyCategory::Pair,
+                "Four digits fit into the same four cells of a unit only, so the other \
+                 candidates can be removed from these cells.",
+                Some(HODOKU_HIDDEN),
+            ),
+            Strategy::XWing => (
+                StrategyCategory::Fish,
+                "A digit fits into the same two columns in two rows, or the same two rows \
+                 in two columns, so it can be removed from the rest of these lines.",
+                Some("https://hodoku.sourceforge.net/en/tech_fishb.php"),
+            ),
+            Strategy::CageCombination => (
+                StrategyCategory::Variant,
+                "Only some combinations of different digits add up to the sum of a cage, \
+                 so digits outside of all of them can be removed from the cage.",
+                Some("https://en.wikipedia.org/wiki/Killer_sudoku"),
+            ),
+            Strategy::InniesOuties => (
+                StrategyCategory::Variant,
+                "The cages covering a set of units add up to more or less than its sum, \
+                 which fixes the sum of the cells sticking in or out.",
+                Some("https://en.wikipedia.org/wiki/Killer_sudoku"),
+            ),
+            Strategy::LawOfLeftovers => (
+                StrategyCategory::Variant,
+                "The cells of a band of a Jigsaw Sudoku outside the regions overlapping it \
+                 the most hold the same digits as the cells of these regions outside the band.",
+                None,
+            ),
+            Strategy::XV => (
+                StrategyCategory::Variant,
+                "Two cells joined by an X add up to 10, by a V to 5, which limits the \
+                 digits they can hold.",
+                None,
+            ),
+            Strategy::Kropki => (
+                StrategyCategory::Variant,
+                "Two cells joined by a white dot hold consecutive digits, by a black dot \
+                 digits of which one is twice the other.",
+                None,
+            ),
+            Strategy::Thermometer => (
+                StrategyCategory::Variant,
+                "The digits along a thermometer increase from its bulb, which bounds the \
+                 digits of each cell by its position.",
+                None,
+            ),
+            Strategy::Arrow => (
+                StrategyCategory::Variant,
+                "The digits on an arrow add up to the digit in its circle, which bounds \
+                 the digits on both.",
+                None,
+            ),
+        };
+        StrategyInfo {
+            category,
+            description,
+            url,
+        }
+    }
+}
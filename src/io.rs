@@ -0,0 +1,422 @@
+//! Reading boards from strings and writing them out, and reading puzzle collections
+//! from files.
+
+use crate::{Board, EMPTY, GridSpec, Sudoku, SudokuError};
+use std::fmt;
+
+/// Write the digits row by row, separated by spaces.
+fn write_grid(f: &mut fmt::Formatter, spec: GridSpec, values: &[Vec<u8>]) -> fmt::Result {
+    for row in values {
+        for &num in row {
+            write!(f, "{} ", spec.digit_char(num))?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+/// A horizontal line of a framed grid with cells `width` characters wide, e.g.
+/// `╔═══╤═══╦…╗`, with `thin` between cells and `thick` between boxes.
+pub(crate) fn frame_line(
+    spec: GridSpec,
+    width: usize,
+    (left, fill, thin, thick, right): (char, char, char, char, char),
+) -> String {
+    let size = spec.size();
+    let mut line = left.to_string();
+    for col in 0..size {
+        line.extend(std::iter::repeat_n(fill, width));
+        line.push(if col + 1 == size {
+            right
+        } else if (col + 1) % spec.box_cols == 0 {
+            thick
+        } else {
+            thin
+        });
+    }
+    line
+}
+
+/// The horizontal line of a framed grid below the row.
+pub(crate) fn frame_line_below(spec: GridSpec, width: usize, row: usize) -> String {
+    let chars = if row + 1 == spec.size() {
+        ('╚', '═', '╧', '╩', '╝')
+    } else if (row + 1).is_multiple_of(spec.box_rows) {
+        ('╠', '═', '╪', '╬', '╣')
+    } else {
+        ('╟', '─', '┼', '╫', '╢')
+    };
+    frame_line(spec, width, chars)
+}
+
+/// Serialize the digits in row-major order, as read by [`Sudoku::set_board_string`].
+fn serialize(spec: GridSpec, values: &[Vec<u8>]) -> String {
+    values
+        .iter()
+        .flatten()
+        .map(|&digit| spec.digit_char(digit))
+        .collect()
+}
+
+impl fmt::Display for Sudoku {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_grid(f, self.spec, &self.board)
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_grid(f, self.spec, &self.values)
+    }
+}
+
+impl Board {
+    pub fn serialized(&self) -> String {
+        serialize(self.spec, &self.values)
+    }
+
+    /// The givens alone, serialized like [`Board::serialized`].
+    pub fn original_board(&self) -> String {
+        serialize(self.spec, &self.given_values())
+    }
+}
+
+impl std::str::FromStr for Board {
+    type Err = String;
+    /// Parse a serialized board, see [`Sudoku::set_board_string`]. All digits are givens.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cells: Vec<char> = s
+            .chars()
+            .filter(|c| c.is_ascii_hexdigit() || *c == '.')
+            .collect();
+        let spec = GridSpec::from_cell_count(cells.len()).ok_or_else(|| {
+            format!(
+                "must contain exactly 36, 81 or 256 cells, found {}",
+                cells.len()
+            )
+        })?;
+        let mut values = vec![vec![EMPTY; spec.size()]; spec.size()];
+        for (idx, c) in cells.into_iter().enumerate() {
+            values[idx / spec.size()][idx % spec.size()] = spec
+                .parse_cell(c)
+                .ok_or_else(|| format!("invalid digit '{}'", c))?;
+        }
+        Ok(Board::from_givens(spec, values))
+    }
+}
+
+/// Longest input [`parse_any`] reads, in bytes. A framed 16×16 pencilmark grid with
+/// all candidates takes a few KiB.
+const MAX_INPUT_LEN: usize = 1 << 16;
+
+/// Invalid characters [`parse_any`] lists in its error, before it only counts the rest.
+const MAX_REPORTED_CHARS: usize = 10;
+
+/// Characters drawing lines around the cells of a board, which [`parse_any`] skips.
+fn is_frame(c: char) -> bool {
+    matches!(c, '|' | '-' | '+') || ('─'..='╿').contains(&c)
+}
+
+/// The first characters with their positions, and how many more there are.
+fn describe(chars: &[(usize, usize, char)]) -> String {
+    let mut listed: Vec<String> = chars
+        .iter()
+        .take(MAX_REPORTED_CHARS)
+        .map(|(line, column, c)| format!("{:?} at line {}, column {}", c, line, column))
+        .collect();
+    if chars.len() > MAX_REPORTED_CHARS {
+        listed.push(format!("and {} more", chars.len() - MAX_REPORTED_CHARS));
+    }
+    listed.join(", ")
+}
+
+/// Read a puzzle from arbitrary text, e.g. user input or a fuzzer's, and report any
+/// problem instead of guessing. Unlike [`Sudoku::set_board_string`], which skips
+/// unknown characters and logs boards with the wrong number of cells, this never
+/// panics or drops input silently:
+///
+/// - Text with brackets, or with as many whitespace separated tokens as cells of which
+///   some hold several digits, is read as a pencilmark grid, see
+///   [`Sudoku::from_pencilmarks`].
+/// - Otherwise it's a board with `0` or `.` for empty cells, optionally split into
+///   lines and framed by `|`, `-`, `+` or box-drawing characters. It must have 36, 81 or
+///   256 cells. Other characters are reported with their line and column, counted
+///   from 1, and so are digits too large for the grid.
+///
+/// Input longer than 64 KiB is rejected without being read. Givens aren't checked for
+/// conflicts, see [`validate`](crate::validate) for that.
+pub fn parse_any(input: &str) -> Result<Sudoku, SudokuError> {
+    if input.len() > MAX_INPUT_LEN {
+        return Err(SudokuError::Parse(format!(
+            "input of {} bytes exceeds the limit of {} bytes",
+            input.len(),
+            MAX_INPUT_LEN
+        )));
+    }
+    let tokens: Vec<&str> = input
+        .split(|c: char| c.is_whitespace() || is_frame(c))
+        .filter(|token| !token.is_empty())
+        .collect();
+    if input.contains('[')
+        || (GridSpec::from_cell_count(tokens.len()).is_some()
+            && tokens.iter().any(|token| token.chars().count() > 1))
+    {
+        return Sudoku::from_pencilmarks(input);
+    }
+    let mut cells = Vec::new();
+    let mut invalid = Vec::new();
+    for (line, text) in input.lines().enumerate() {
+        for (column, c) in text.chars().enumerate() {
+            let position = (line + 1, column + 1, c);
+            if c.is_ascii_hexdigit() || c == '.' {
+                cells.push(position);
+            } else if !c.is_whitespace() && !is_frame(c) {
+                invalid.push(position);
+            }
+        }
+    }
+    let Some(spec) = GridSpec::from_cell_count(cells.len()) else {
+        let mut message = format!(
+            "must contain exactly 36, 81 or 256 cells, found {}",
+            cells.len()
+        );
+        if !invalid.is_empty() {
+            message += &format!("; invalid characters: {}", describe(&invalid));
+        }
+        return Err(SudokuError::Parse(message));
+    };
+    let mut values = vec![vec![EMPTY; spec.size()]; spec.size()];
+    for (idx, &(line, column, c)) in cells.iter().enumerate() {
+        match spec.parse_cell(c) {
+            Some(num) => values[idx / spec.size()][idx % spec.size()] = num,
+            None => invalid.push((line, column, c)),
+        }
+    }
+    if !invalid.is_empty() {
+        invalid.sort_unstable();
+        return Err(SudokuError::Parse(format!(
+            "invalid characters for a {}×{} grid: {}",
+            spec.size(),
+            spec.size(),
+            describe(&invalid)
+        )));
+    }
+    Ok(Sudoku::from_board(&Board::from_givens(spec, values)))
+}
+
+impl Sudoku {
+    #[allow(dead_code)]
+    pub fn from_string(s: &str) -> Sudoku {
+        let mut sudoku = Sudoku::new();
+        sudoku.set_board_string(s);
+        sudoku
+    }
+
+    pub fn original_board(&self) -> String {
+        serialize(self.spec, &self.original_board)
+    }
+
+    pub fn serialized(&self) -> String {
+        serialize(self.spec, &self.board)
+    }
+
+    /// The digits in a grid framed with box-drawing characters, thick lines between the
+    /// boxes. Givens are shown in brackets, e.g. `[5]`, digits placed since as ` 5 `,
+    /// and empty cells are blank.
+    pub fn to_display_grid(&self) -> String {
+        let mut grid = frame_line(self.spec, 3, ('╔', '═', '╤', '╦', '╗'));
+        grid.push('\n');
+        for (row, values) in self.board.iter().enumerate() {
+            grid.push('║');
+            for (col, &num) in values.iter().enumerate() {
+                let digit = self.spec.digit_char(num);
+                grid += &if num == EMPTY {
+                    "   ".to_string()
+                } else if self.is_given(row, col) {
+                    format!("[{}]", digit)
+                } else {
+                    format!(" {} ", digit)
+                };
+                grid.push(if (col + 1) % self.spec.box_cols == 0 {
+                    '║'
+                } else {
+                    '│'
+                });
+            }
+            grid.push('\n');
+            grid += &frame_line_below(self.spec, 3, row);
+            grid.push('\n');
+        }
+        grid
+    }
+
+    /// Print the board as a framed grid, see [`Sudoku::to_display_grid`], and serialized.
+    #[cfg(feature = "dump")]
+    pub fn print(&self) {
+        print!("{}", self.to_display_grid());
+        println!("{}", self.serialized());
+    }
+
+    #[cfg(feature = "dump")]
+    pub fn dump_notes(&self) {
+        let size = self.size();
+        let GridSpec { box_rows, box_cols } = self.spec;
+        // Each cell shows its candidates in `box_rows` lines of `box_cols` digits
+        let width = box_cols + 2;
+        println!();
+        let mut header = " ".repeat(4 + box_cols / 2);
+        for j in 0..size {
+            header.push_str(&format!("{:x}", j));
+            header.push_str(&" ".repeat(box_cols + 2));
+        }
+        println!("{}", header.trim_end());
+        println!(
+            "  {}",
+            frame_line(self.spec, width, ('╔', '═', '╤', '╦', '╗'))
+        );
+        for i in 0..size {
+            for line in 0..box_rows {
+                if line == box_rows / 2 {
+                    print!("{:x} ║ ", i);
+                } else {
+                    print!("  ║ ");
+                }
+                for j in 0..size {
+                    for k in 0..box_cols {
+                        let num = (box_cols * line + k + 1) as u8;
+                        if self.candidates[i][j].contains(&num) {
+                            print!("{}", self.spec.digit_char(num));
+                        } else {
+                            print!(".");
+                        }
+                    }
+                    if (j + 1) % box_cols == 0 {
+                        print!(" ║ ");
+                    } else {
+                        print!(" │ ");
+                    }
+                }
+                println!();
+            }
+            println!("  {}", frame_line_below(self.spec, width, i));
+        }
+    }
+
+    pub fn restore(&mut self) {
+        self.set_board_string(&self.original_board());
+    }
+
+    /// Set the board from a serialized string. The grid size is derived from the number
+    /// of cells: 36 for 6×6, 81 for 9×9 and 256 for 16×16 (see [`GridSpec::parse_cell`]).
+    /// Characters other than hexadecimal digits and `.` are ignored. If the size changes,
+    /// regions, cages and constraints are reset.
+    ///
+    /// An invalid board is logged and leaves the Sudoku unchanged; use
+    /// [`Sudoku::try_set_board_string`] to handle the error instead.
+    pub fn set_board_string(&mut self, board_string: &str) {
+        if let Err(e) = self.try_set_board_string(board_string) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Set the board like [`Sudoku::set_board_string`], but report an invalid board.
+    pub fn try_set_board_string(&mut self, board_string: &str) -> Result<(), SudokuError> {
+        let board = board_string.parse::<Board>().map_err(SudokuError::Parse)?;
+        if board.spec != self.spec {
+            *self = Sudoku::with_spec(board.spec);
+        }
+        self.clear();
+        self.board = board.values;
+        self.original_board = self.board.clone();
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Sudoku {
+    type Err = String;
+    /// Parse a serialized board of any supported size, see [`Sudoku::set_board_string`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Sudoku::from_board(&s.parse::<Board>()?))
+    }
+}
+
+/// A puzzle collection mapped into memory, in the SDM format (one puzzle per line) or
+/// as CSV with the puzzle in the first column. Records borrow from the mapping, so even
+/// files of several gigabytes are read without copying them or allocating per line.
+///
+/// The file must not be changed while the corpus is open.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Corpus {
+    map: memmap2::Mmap,
+}
+
+/// A line of a [`Corpus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    /// Number of the line in the file, counted from 1
+    pub line: usize,
+    /// The puzzle, not yet parsed
+    pub puzzle: &'a str,
+    /// The other columns of a CSV line, e.g. a rating, or an empty string
+    pub fields: &'a str,
+}
+
+/// Iterator over the records of a [`Corpus`], skipping empty lines and comments
+/// starting with `#`.
+pub struct Records<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+}
+
+impl<'a> Records<'a> {
+    /// Records of a collection already in memory.
+    pub fn new(text: &'a str) -> Records<'a> {
+        Records {
+            lines: text.lines().enumerate(),
+        }
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Record<'a>> {
+        for (i, line) in self.lines.by_ref() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (puzzle, fields) = line.split_once(',').unwrap_or((line, ""));
+            return Some(Record {
+                line: i + 1,
+                puzzle: puzzle.trim(),
+                fields,
+            });
+        }
+        None
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Corpus {
+    /// Map the file into memory. Fails if it can't be read or isn't valid UTF-8.
+    pub fn open(path: impl AsRef<std::path::Path>) -> std::io::Result<Corpus> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: the mapping is only read, and the caller keeps the file unchanged
+        // while the corpus is open.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        std::str::from_utf8(&map)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(Corpus { map })
+    }
+
+    /// The whole file.
+    pub fn text(&self) -> &str {
+        // SAFETY: `open` checked that the mapping is valid UTF-8, and the caller keeps
+        // the file unchanged while the corpus is open.
+        unsafe { std::str::from_utf8_unchecked(&self.map) }
+    }
+
+    pub fn records(&self) -> Records<'_> {
+        Records::new(self.text())
+    }
+}
@@ -0,0 +1,143 @@
+//! Jigsaw Sudoku support: the boxes are replaced by irregular regions of the same size.
+
+use crate::analysis::Hits;
+use crate::{Candidate, DigitSet, EMPTY, Strategy, StrategyResult, Sudoku, SudokuError, Unit};
+use std::collections::BTreeMap;
+use std::ops::ControlFlow;
+
+impl Sudoku {
+    /// Replace the boxes by irregular regions given as a layout of region labels, one
+    /// per cell, e.g. `111222333...` for a 9×9 grid. Any non-whitespace character can be
+    /// used as a label, but there must be as many labels as the grid has rows, each
+    /// covering as many cells.
+    pub fn set_regions_string(&mut self, s: &str) -> Result<(), SudokuError> {
+        let size = self.size();
+        let layout: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if layout.len() != size * size {
+            return Err(SudokuError::Parse(format!(
+                "Region layout must contain exactly {} labels",
+                size * size
+            )));
+        }
+        // Number the regions in the order of their first appearance
+        let mut indices: BTreeMap<char, usize> = BTreeMap::new();
+        let mut regions = vec![vec![0; size]; size];
+        let mut region_cells: Vec<Vec<(usize, usize)>> = Vec::new();
+        for (idx, label) in layout.into_iter().enumerate() {
+            let next_index = indices.len();
+            let region = *indices.entry(label).or_insert(next_index);
+            if region >= size {
+                return Err(SudokuError::Parse(format!(
+                    "Region layout must contain exactly {} different labels",
+                    size
+                )));
+            }
+            if region == region_cells.len() {
+                region_cells.push(Vec::new());
+            }
+            regions[idx / size][idx % size] = region;
+            region_cells[region].push((idx / size, idx % size));
+        }
+        if region_cells.len() != size || region_cells.iter().any(|cells| cells.len() != size) {
+            return Err(SudokuError::Parse(format!(
+                "Each region must consist of exactly {} cells",
+                size
+            )));
+        }
+        self.regions = regions;
+        self.region_cells = region_cells;
+        Ok(())
+    }
+
+    pub fn is_jigsaw(&self) -> bool {
+        self.regions != self.spec.standard_regions()
+    }
+
+    /// Digits a set of cells may contain: their digits if filled, otherwise their candidates.
+    fn possible_digits(&self, cells: &[(usize, usize)]) -> DigitSet {
+        let mut digits = DigitSet::new();
+        for &(row, col) in cells {
+            if self.board[row][col] != EMPTY {
+                digits.insert(self.board[row][col]);
+            } else {
+                digits = digits.union(&self.candidates[row][col]);
+            }
+        }
+        digits
+    }
+
+    /// Apply the Law of Leftovers: a band of `n` consecutive rows or columns contains
+    /// every digit `n` times, as do the `n` regions overlapping the band the most. So the
+    /// cells of the band not covered by these regions contain the same digits as the
+    /// cells of these regions sticking out of the band. A candidate in one of these sets
+    /// that can't be placed anywhere in the other set can be removed.
+    pub fn find_law_of_leftovers(&self) -> StrategyResult {
+        self.first_hit(Strategy::LawOfLeftovers, Sudoku::search_law_of_leftovers)
+    }
+
+    pub(crate) fn search_law_of_leftovers(&self, hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::LawOfLeftovers);
+        if !self.is_jigsaw() {
+            return ControlFlow::Continue(());
+        }
+        for unit in [Unit::Row, Unit::Column] {
+            for size in 1..self.size() {
+                for start in 0..=self.size() - size {
+                    let lines = start..start + size;
+                    let in_band = |&(row, col): &(usize, usize)| {
+                        lines.contains(&self.house_at(&unit, row, col).index())
+                    };
+                    let mut regions: Vec<usize> = (0..self.size()).collect();
+                    regions.sort_by_key(|&region| {
+                        std::cmp::Reverse(
+                            self.region_cells[region]
+                                .iter()
+                                .filter(|cell| in_band(cell))
+                                .count(),
+                        )
+                    });
+                    let covering = &regions[..size];
+                    let inside: Vec<(usize, usize)> = (0..self.spec.cell_count())
+                        .map(|idx| (idx / self.size(), idx % self.size()))
+                        .filter(|cell| in_band(cell))
+                        .filter(|&(row, col)| !covering.contains(&self.regions[row][col]))
+                        .collect();
+                    let outside: Vec<(usize, usize)> = covering
+                        .iter()
+                        .flat_map(|&region| self.region_cells[region].iter().cloned())
+                        .filter(|cell| !in_band(cell))
+                        .collect();
+                    if inside.is_empty() || inside.len() != outside.len() {
+                        continue;
+                    }
+                    let digits_inside = self.possible_digits(&inside);
+                    let digits_outside = self.possible_digits(&outside);
+                    for (cells, other_digits) in
+                        [(&inside, &digits_outside), (&outside, &digits_inside)]
+                    {
+                        for &(row, col) in cells {
+                            for num in self.candidates[row][col] {
+                                if !other_digits.contains(&num) {
+                                    result
+                                        .removals
+                                        .candidates_about_to_be_removed
+                                        .insert(Candidate { row, col, num });
+                                }
+                            }
+                        }
+                    }
+                    if result.removals.will_remove_candidates() {
+                        self.add_pattern_cells(
+                            &mut result.removals,
+                            inside.iter().chain(outside.iter()).cloned(),
+                        );
+                        result.removals.unit = Some(unit.clone());
+                        result.removals.unit_index = Some(lines.collect());
+                        hits.report(&mut result)?;
+                    }
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,348 @@
+//! Killer Sudoku support: cages of cells whose digits must not repeat and must add up to a given sum.
+
+use crate::analysis::Hits;
+use crate::{Candidate, EMPTY, RemovalResult, Strategy, StrategyResult, Sudoku, SudokuError, Unit};
+use smallvec::smallvec;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::ControlFlow;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cage {
+    pub cells: Vec<(usize, usize)>,
+    pub sum: u32,
+}
+
+impl Cage {
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        self.cells.contains(&(row, col))
+    }
+
+    /// Parse cages from the common killer text format: a layout of 81 cage labels
+    /// (any non-whitespace character, `.` for cells without a cage), followed by the
+    /// sum of each cage as `label=sum`, e.g.
+    ///
+    /// ```text
+    /// aabbccdde
+    /// ...
+    /// a=3 b=15 c=22 ...
+    /// ```
+    pub fn parse_cages(s: &str) -> Result<Vec<Cage>, SudokuError> {
+        Cage::parse_cages_sized(s, 9)
+    }
+
+    /// Parse cages like [`Cage::parse_cages`] for a grid of `size` × `size` cells.
+    pub fn parse_cages_sized(s: &str, size: usize) -> Result<Vec<Cage>, SudokuError> {
+        let mut chars = s.chars().filter(|c| !c.is_whitespace());
+        let layout: Vec<char> = chars.by_ref().take(size * size).collect();
+        if layout.len() != size * size {
+            return Err(SudokuError::Parse(format!(
+                "Cage layout must contain exactly {} labels",
+                size * size
+            )));
+        }
+        let mut cells_by_label: BTreeMap<char, Vec<(usize, usize)>> = BTreeMap::new();
+        for (idx, &label) in layout.iter().enumerate() {
+            if label != '.' {
+                cells_by_label
+                    .entry(label)
+                    .or_default()
+                    .push((idx / size, idx % size));
+            }
+        }
+        let mut sums: BTreeMap<char, u32> = BTreeMap::new();
+        for definition in s
+            .split_whitespace()
+            .flat_map(|part| part.split(','))
+            .filter(|part| part.contains('='))
+        {
+            let (label, sum) = definition.split_once('=').unwrap();
+            let mut label_chars = label.chars();
+            let (Some(label), None) = (label_chars.next(), label_chars.next()) else {
+                return Err(SudokuError::Parse(format!(
+                    "Invalid cage label in '{}'",
+                    definition
+                )));
+            };
+            let sum = sum
+                .parse::<u32>()
+                .map_err(|_| SudokuError::Parse(format!("Invalid sum in '{}'", definition)))?;
+            sums.insert(label, sum);
+        }
+        cells_by_label
+            .into_iter()
+            .map(|(label, cells)| {
+                let sum = *sums.get(&label).ok_or_else(|| {
+                    SudokuError::Parse(format!("Missing sum for cage '{}'", label))
+                })?;
+                if cells.len() > size {
+                    return Err(SudokuError::Parse(format!(
+                        "Cage '{}' has more than {} cells",
+                        label, size
+                    )));
+                }
+                Ok(Cage { cells, sum })
+            })
+            .collect()
+    }
+}
+
+/// The digits whose bits are set in the mask.
+fn digits_of(mask: u32) -> impl Iterator<Item = u32> {
+    (1..u32::BITS).filter(move |&d| mask & (1 << d) != 0)
+}
+
+fn digit_sum(mask: u32) -> u32 {
+    digits_of(mask).sum()
+}
+
+/// Number of the digits 1 to `size` not in the mask.
+fn unused_count(mask: u32, size: u32) -> usize {
+    (1..=size).filter(|&d| mask & (1 << d) == 0).count()
+}
+
+impl Sudoku {
+    pub fn set_cages(&mut self, cages: Vec<Cage>) {
+        self.cages = cages;
+    }
+
+    /// Parse cages with [`Cage::parse_cages`] and attach them to the Sudoku.
+    pub fn set_cages_string(&mut self, s: &str) -> Result<(), SudokuError> {
+        self.cages = Cage::parse_cages_sized(s, self.size())?;
+        Ok(())
+    }
+
+    /// Check if placing `num` in the cell keeps all cages containing it satisfiable,
+    /// i.e. no digit repeats and the remaining sum can still be reached with the
+    /// remaining empty cells.
+    pub(crate) fn cage_allows(&self, row: usize, col: usize, num: u8) -> bool {
+        for cage in self.cages.iter().filter(|cage| cage.contains(row, col)) {
+            let mut used = 1u32 << num;
+            let mut sum = num as u32;
+            let mut empty_cells = 0;
+            for &(r, c) in &cage.cells {
+                if (r, c) == (row, col) {
+                    continue;
+                }
+                let digit = self.board[r][c];
+                if digit == EMPTY {
+                    empty_cells += 1;
+                } else if used & (1 << digit) != 0 {
+                    return false;
+                } else {
+                    used |= 1 << digit;
+                    sum += digit as u32;
+                }
+            }
+            if sum > cage.sum {
+                return false;
+            }
+            let remaining = cage.sum - sum;
+            // The smallest and largest sums of `empty_cells` distinct unused digits
+            let unused: Vec<u32> = (1..=self.size() as u32)
+                .filter(|&d| used & (1 << d) == 0)
+                .collect();
+            if unused.len() < empty_cells {
+                return false;
+            }
+            let min: u32 = unused.iter().take(empty_cells).sum();
+            let max: u32 = unused.iter().rev().take(empty_cells).sum();
+            if remaining < min || remaining > max {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Digits the given cell may contain in the current state: its digit if filled, otherwise its candidates.
+    fn cage_cell_mask(&self, row: usize, col: usize) -> u32 {
+        let digit = self.board[row][col];
+        if digit != EMPTY {
+            return 1 << digit;
+        }
+        self.candidates[row][col].bits()
+    }
+
+    /// Find candidates in cages which aren't part of any combination of distinct
+    /// digits adding up to the cage sum that fits the candidates of the other cells.
+    pub fn find_cage_combination(&self) -> StrategyResult {
+        self.first_hit(Strategy::CageCombination, Sudoku::search_cage_combination)
+    }
+
+    pub(crate) fn search_cage_combination(&self, hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::CageCombination);
+        for (cage_idx, cage) in self.cages.iter().enumerate() {
+            let n = cage.cells.len();
+            let size = self.size() as u32;
+            let masks: Vec<u32> = cage
+                .cells
+                .iter()
+                .map(|&(row, col)| self.cage_cell_mask(row, col))
+                .collect();
+            // Whether `k` more distinct digits not in `m` can bring the sum of `m` to the
+            // cage sum: between the sums of the `k` smallest and largest unused digits
+            let in_reach = |m: u32, k: usize| {
+                let unused = (1..=size).filter(|&d| m & (1 << d) == 0);
+                let min: u32 = unused.clone().take(k).sum();
+                let max: u32 = unused.rev().take(k).sum();
+                let sum = digit_sum(m);
+                unused_count(m, size) >= k && sum + min <= cage.sum && sum + max >= cage.sum
+            };
+            // reachable[i]: sets of digits that can be placed in the first `i` cells, as
+            // bitmasks with bit `d` for digit `d`, and from which the sum can be reached.
+            // Only the sets the candidates allow are visited, not every set of digits.
+            let mut reachable: Vec<BTreeSet<u32>> = vec![BTreeSet::new(); n + 1];
+            if in_reach(0, n) {
+                reachable[0].insert(0);
+            }
+            for i in 0..n {
+                let next: BTreeSet<u32> = reachable[i]
+                    .iter()
+                    .flat_map(|&m| digits_of(masks[i] & !m).map(move |d| m | 1 << d))
+                    .filter(|&m| in_reach(m, n - i - 1))
+                    .collect();
+                reachable[i + 1] = next;
+            }
+            // completable[i]: the sets of `reachable[i]` from which the rest of the cage
+            // can be filled so that the sum is reached
+            let mut completable: Vec<BTreeSet<u32>> = vec![BTreeSet::new(); n + 1];
+            completable[n] = reachable[n]
+                .iter()
+                .copied()
+                .filter(|&m| digit_sum(m) == cage.sum)
+                .collect();
+            for i in (0..n).rev() {
+                let done: BTreeSet<u32> = reachable[i]
+                    .iter()
+                    .copied()
+                    .filter(|&m| {
+                        digits_of(masks[i] & !m).any(|d| completable[i + 1].contains(&(m | 1 << d)))
+                    })
+                    .collect();
+                completable[i] = done;
+            }
+            for (i, &(row, col)) in cage.cells.iter().enumerate() {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                // Digits of the cell in some combination filling the whole cage
+                let rest = &completable[i + 1];
+                let possible: u32 = completable[i]
+                    .iter()
+                    .flat_map(|&m| {
+                        digits_of(masks[i] & !m).filter(move |&d| rest.contains(&(m | 1 << d)))
+                    })
+                    .fold(0, |possible, d| possible | 1 << d);
+                for num in self.candidates[row][col] {
+                    if possible & (1 << num) == 0 {
+                        result
+                            .removals
+                            .candidates_about_to_be_removed
+                            .insert(Candidate { row, col, num });
+                    }
+                }
+            }
+            if result.removals.will_remove_candidates() {
+                self.add_pattern_cells(&mut result.removals, cage.cells.iter().cloned());
+                result.removals.unit = Some(Unit::Cage);
+                result.removals.unit_index = Some(smallvec![cage_idx]);
+                hits.report(&mut result)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Set a cell determined by the "rule of 45": the digits of a house add up to 45
+    /// (in a 9×9 grid),
+    /// so a single cell not covered by the cages inside the house (innie), or a single
+    /// cell sticking out of the cages covering the house (outie), can be calculated.
+    pub fn find_innies_outies(&self) -> StrategyResult {
+        self.first_hit(Strategy::InniesOuties, Sudoku::search_innies_outies)
+    }
+
+    pub(crate) fn search_innies_outies(&self, hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::InniesOuties);
+        if self.cages.is_empty() {
+            return ControlFlow::Continue(());
+        }
+        let house_sum = self.spec.house_sum();
+        for house in self.houses() {
+            let cells: Vec<(usize, usize)> = self.cells_of(house).collect();
+            // Innies: cells of the house not covered by cages lying completely inside it
+            let inside: Vec<&Cage> = self
+                .cages
+                .iter()
+                .filter(|cage| cage.cells.iter().all(|cell| cells.contains(cell)))
+                .collect();
+            let uncovered: Vec<(usize, usize)> = cells
+                .iter()
+                .filter(|&&(row, col)| !inside.iter().any(|cage| cage.contains(row, col)))
+                .cloned()
+                .collect();
+            let known: u32 = inside.iter().map(|cage| cage.sum).sum::<u32>();
+            if let Some(removals) = self.collect_innie_outie(&uncovered, |sum_of_filled| {
+                house_sum.checked_sub(known + sum_of_filled)
+            }) {
+                result.removals = removals;
+                result.removals.unit = Some(house.unit());
+                result.removals.unit_index = Some(smallvec![house.index()]);
+                hits.report(&mut result)?;
+            }
+            // Outies: cells outside the house belonging to cages overlapping it
+            let overlapping: Vec<&Cage> = self
+                .cages
+                .iter()
+                .filter(|cage| cage.cells.iter().any(|cell| cells.contains(cell)))
+                .collect();
+            let covers_house = cells
+                .iter()
+                .all(|&(row, col)| overlapping.iter().any(|cage| cage.contains(row, col)));
+            if !covers_house {
+                continue;
+            }
+            let outside: Vec<(usize, usize)> = overlapping
+                .iter()
+                .flat_map(|cage| cage.cells.iter())
+                .filter(|cell| !cells.contains(cell))
+                .cloned()
+                .collect();
+            let total: u32 = overlapping.iter().map(|cage| cage.sum).sum::<u32>();
+            if let Some(removals) = self.collect_innie_outie(&outside, |sum_of_filled| {
+                total.checked_sub(house_sum + sum_of_filled)
+            }) {
+                result.removals = removals;
+                result.removals.unit = Some(house.unit());
+                result.removals.unit_index = Some(smallvec![house.index()]);
+                hits.report(&mut result)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// If exactly one of `cells` is empty, calculate its digit from the sum of the
+    /// filled ones and collect the removals for setting it.
+    fn collect_innie_outie(
+        &self,
+        cells: &[(usize, usize)],
+        digit_for: impl Fn(u32) -> Option<u32>,
+    ) -> Option<RemovalResult> {
+        let empty: Vec<&(usize, usize)> = cells
+            .iter()
+            .filter(|&&(row, col)| self.board[row][col] == EMPTY)
+            .collect();
+        if empty.len() != 1 {
+            return None;
+        }
+        let &(row, col) = empty[0];
+        let sum_of_filled: u32 = cells
+            .iter()
+            .map(|&(row, col)| self.board[row][col] as u32)
+            .sum();
+        let num = digit_for(sum_of_filled)?;
+        if !(1..=self.size() as u32).contains(&num)
+            || !self.candidates[row][col].contains(&(num as u8))
+        {
+            return None;
+        }
+        Some(self.collect_set_num(num as u8, row, col))
+    }
+}
@@ -0,0 +1,58 @@
+//! Links between candidates, the inferences chain-based steps are made of, so that
+//! renderers can draw them as arrows between the candidates.
+
+use crate::{Candidate, ConjugatePair};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// How the truth of one candidate of a link bears on the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum LinkKind {
+    /// At least one of the candidates is true: if `from` is false, `to` is true.
+    /// Usually drawn as a solid line.
+    Strong,
+    /// At most one of the candidates is true: if `from` is true, `to` is false.
+    /// Usually drawn as a dashed line.
+    Weak,
+}
+
+/// An inference from one candidate to another, e.g. between the only two places of a
+/// digit in a unit, or between two candidates of the same cell.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Link {
+    pub from: Candidate,
+    pub to: Candidate,
+    pub kind: LinkKind,
+}
+
+impl Link {
+    pub fn strong(from: Candidate, to: Candidate) -> Link {
+        Link {
+            from,
+            to,
+            kind: LinkKind::Strong,
+        }
+    }
+
+    pub fn weak(from: Candidate, to: Candidate) -> Link {
+        Link {
+            from,
+            to,
+            kind: LinkKind::Weak,
+        }
+    }
+}
+
+impl ConjugatePair {
+    /// The strong link from the first cell of the pair to the second.
+    pub fn link(&self) -> Link {
+        let [from, to] = self.cells.map(|cell| Candidate {
+            row: cell.row,
+            col: cell.col,
+            num: self.num,
+        });
+        Link::strong(from, to)
+    }
+}
@@ -0,0 +1,236 @@
+//! Strategy names and hint explanations in several languages and naming schemes.
+
+use crate::{Strategy, StrategyResult, Unit};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+    French,
+}
+
+impl std::str::FromStr for Locale {
+    type Err = String;
+    /// Parse a language code like `de` or `fr-CA`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or_default();
+        match language.to_lowercase().as_str() {
+            "en" => Ok(Locale::English),
+            "de" => Ok(Locale::German),
+            "fr" => Ok(Locale::French),
+            _ => Err(format!("Unsupported locale: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Locale::English => write!(f, "en"),
+            Locale::German => write!(f, "de"),
+            Locale::French => write!(f, "fr"),
+        }
+    }
+}
+
+/// Names of the basic strategies, see [`Strategy::name_in`]. The strategies themselves,
+/// and how they're serialized, are the same in all schemes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum NamingScheme {
+    /// "Last Digit", "Obvious Single", "Claiming Pair", … as on sudoku.coach
+    #[default]
+    Obvious,
+    /// "Full House", "Naked Single", "Box/Line Reduction", … as on sudokuwiki.org and in
+    /// most of the literature
+    Naked,
+}
+
+impl std::str::FromStr for NamingScheme {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "obvious" | "coach" => Ok(NamingScheme::Obvious),
+            "naked" | "wiki" => Ok(NamingScheme::Naked),
+            _ => Err(format!("Unknown naming scheme: {}", s)),
+        }
+    }
+}
+
+impl Strategy {
+    /// Name of the strategy in the given language and the default naming scheme.
+    pub fn name(&self, locale: Locale) -> &'static str {
+        self.name_in(locale, NamingScheme::default())
+    }
+
+    /// Name of the strategy in the given language and naming scheme.
+    pub fn name_in(&self, locale: Locale, scheme: NamingScheme) -> &'static str {
+        if scheme == NamingScheme::Naked
+            && let Some(name) = self.naked_name(locale)
+        {
+            return name;
+        }
+        match locale {
+            Locale::English => self.to_string(),
+            Locale::German => match self {
+                Strategy::None => "Keine",
+                Strategy::LastDigit => "Letzte Ziffer",
+                Strategy::ObviousSingle => "Offensichtlicher Single",
+                Strategy::HiddenSingle => "Versteckter Single",
+                Strategy::PointingPair => "Zeigendes Paar",
+                Strategy::ClaimingPair => "Beanspruchendes Paar",
+                Strategy::ObviousPair => "Offensichtliches Paar",
+                Strategy::HiddenPair => "Verstecktes Paar",
+                Strategy::ObviousTriple => "Offensichtliches Tripel",
+                Strategy::HiddenTriple => "Verstecktes Tripel",
+                Strategy::ObviousQuad => "Offensichtliches Quartett",
+                Strategy::HiddenQuad => "Verstecktes Quartett",
+                Strategy::XWing => "X-Wing",
+                Strategy::CageCombination => "Käfigkombination",
+                Strategy::InniesOuties => "Innies & Outies",
+                Strategy::LawOfLeftovers => "Gesetz der Reste",
+                Strategy::XV => "XV",
+                Strategy::Kropki => "Kropki-Punkte",
+                Strategy::Thermometer => "Thermometer",
+                Strategy::Arrow => "Pfeil",
+            },
+            Locale::French => match self {
+                Strategy::None => "Aucune",
+                Strategy::LastDigit => "Dernier chiffre",
+                Strategy::ObviousSingle => "Singleton évident",
+                Strategy::HiddenSingle => "Singleton caché",
+                Strategy::PointingPair => "Paire pointante",
+                Strategy::ClaimingPair => "Paire revendiquante",
+                Strategy::ObviousPair => "Paire évidente",
+                Strategy::HiddenPair => "Paire cachée",
+                Strategy::ObviousTriple => "Triplet évident",
+                Strategy::HiddenTriple => "Triplet caché",
+                Strategy::ObviousQuad => "Quadruplet évident",
+                Strategy::HiddenQuad => "Quadruplet caché",
+                Strategy::XWing => "X-Wing",
+                Strategy::CageCombination => "Combinaison de cage",
+                Strategy::InniesOuties => "Innies & Outies",
+                Strategy::LawOfLeftovers => "Loi des restes",
+                Strategy::XV => "XV",
+                Strategy::Kropki => "Points Kropki",
+                Strategy::Thermometer => "Thermomètre",
+                Strategy::Arrow => "Flèche",
+            },
+        }
+    }
+
+    /// Names differing in the [`NamingScheme::Naked`] scheme.
+    fn naked_name(&self, locale: Locale) -> Option<&'static str> {
+        match (locale, self) {
+            (Locale::English, Strategy::LastDigit) => Some("Full House"),
+            (Locale::English, Strategy::ObviousSingle) => Some("Naked Single"),
+            (Locale::English, Strategy::ObviousPair) => Some("Naked Pair"),
+            (Locale::English, Strategy::ObviousTriple) => Some("Naked Triple"),
+            (Locale::English, Strategy::ObviousQuad) => Some("Naked Quad"),
+            (Locale::English, Strategy::ClaimingPair) => Some("Box/Line Reduction"),
+            (Locale::German, Strategy::ObviousSingle) => Some("Nackter Single"),
+            (Locale::German, Strategy::ObviousPair) => Some("Nacktes Paar"),
+            (Locale::German, Strategy::ObviousTriple) => Some("Nacktes Tripel"),
+            (Locale::German, Strategy::ObviousQuad) => Some("Nacktes Quartett"),
+            (Locale::French, Strategy::ObviousSingle) => Some("Singleton nu"),
+            (Locale::French, Strategy::ObviousPair) => Some("Paire nue"),
+            (Locale::French, Strategy::ObviousTriple) => Some("Triplet nu"),
+            (Locale::French, Strategy::ObviousQuad) => Some("Quadruplet nu"),
+            _ => None,
+        }
+    }
+}
+
+impl Unit {
+    /// Name of the unit in the given language.
+    pub fn name(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::English, Unit::Row) => "Row",
+            (Locale::English, Unit::Column) => "Column",
+            (Locale::English, Unit::Box) => "Box",
+            (Locale::English, Unit::Cage) => "Cage",
+            (Locale::English, Unit::Diagonal) => "Diagonal",
+            (Locale::English, Unit::Window) => "Window",
+            (Locale::English, Unit::Custom) => "Unit",
+            (Locale::German, Unit::Row) => "Zeile",
+            (Locale::German, Unit::Column) => "Spalte",
+            (Locale::German, Unit::Box) => "Block",
+            (Locale::German, Unit::Cage) => "Käfig",
+            (Locale::German, Unit::Diagonal) => "Diagonale",
+            (Locale::German, Unit::Window) => "Fenster",
+            (Locale::German, Unit::Custom) => "Einheit",
+            (Locale::French, Unit::Row) => "ligne",
+            (Locale::French, Unit::Column) => "colonne",
+            (Locale::French, Unit::Box) => "bloc",
+            (Locale::French, Unit::Cage) => "cage",
+            (Locale::French, Unit::Diagonal) => "diagonale",
+            (Locale::French, Unit::Window) => "fenêtre",
+            (Locale::French, Unit::Custom) => "unité",
+        }
+    }
+
+    /// "in Row", "in Zeile", "dans la ligne", …
+    fn in_phrase(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English | Locale::German => format!("in {}", self.name(locale)),
+            Locale::French => match self {
+                Unit::Box => format!("dans le {}", self.name(locale)),
+                Unit::Custom => format!("dans l'{}", self.name(locale)),
+                _ => format!("dans la {}", self.name(locale)),
+            },
+        }
+    }
+}
+
+impl StrategyResult {
+    /// Describe the step for a hint, e.g. "Hidden Single in Box 3: place 7 in r2c5" or
+    /// "Pointing Pair in Box 1: remove r1c4<>5, r1c6<>5".
+    pub fn explain(&self, locale: Locale) -> String {
+        self.explain_in(locale, NamingScheme::default())
+    }
+
+    /// Describe the step like [`StrategyResult::explain`], naming the strategy in the
+    /// given naming scheme.
+    pub fn explain_in(&self, locale: Locale, scheme: NamingScheme) -> String {
+        let mut text = self.strategy.name_in(locale, scheme).to_string();
+        if let (Some(unit), Some(indices)) = (&self.removals.unit, &self.removals.unit_index) {
+            let indices: Vec<String> = indices
+                .iter()
+                .map(|index| (index + 1).to_string())
+                .collect();
+            text += &format!(" {} {}", unit.in_phrase(locale), indices.join(", "));
+        }
+        let action = if let Some(cell) = &self.removals.sets_cell {
+            match locale {
+                Locale::English => format!("place {} in {}", cell.num, cell.coord()),
+                Locale::German => format!("{} in {} eintragen", cell.num, cell.coord()),
+                Locale::French => format!("placer {} en {}", cell.num, cell.coord()),
+            }
+        } else {
+            let mut candidates: Vec<_> = self
+                .removals
+                .candidates_about_to_be_removed
+                .iter()
+                .collect();
+            candidates.sort_by_key(|candidate| (candidate.row, candidate.col, candidate.num));
+            let candidates: Vec<String> = candidates
+                .iter()
+                .map(|candidate| candidate.to_string())
+                .collect();
+            match locale {
+                Locale::English => format!("remove {}", candidates.join(", ")),
+                Locale::German => format!("{} entfernen", candidates.join(", ")),
+                Locale::French => format!("éliminer {}", candidates.join(", ")),
+            }
+        };
+        match locale {
+            Locale::French => format!("{} : {}", text, action),
+            _ => format!("{}: {}", text, action),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Removing givens a puzzle doesn't need, e.g. to tidy up a hand-made puzzle.
+
+use crate::oneshot::check_unique;
+use crate::{EMPTY, Sudoku, SudokuError, Symmetry};
+
+impl Sudoku {
+    /// The puzzle with as many givens removed as possible while the solution stays
+    /// unique. The givens are tried in row-major order, each together with its image
+    /// under the symmetry, so that a symmetric pattern of givens stays symmetric. The
+    /// result is minimal: removing any further given, or pair of givens under the
+    /// symmetry, allows more than one solution. Digits placed since and notes are
+    /// dropped. Fails if the puzzle doesn't have exactly one solution.
+    pub fn minimized(&self, symmetry: Symmetry) -> Result<Sudoku, SudokuError> {
+        let mut sudoku = self.clone();
+        sudoku.clear();
+        sudoku.undo_stack.clear();
+        sudoku.board = self.original_board.clone();
+        check_unique(&sudoku)?;
+        let size = sudoku.size();
+        for row in 0..size {
+            for col in 0..size {
+                let orbit: Vec<(usize, usize)> = symmetry
+                    .orbit(size, row, col)
+                    .into_iter()
+                    .filter(|&(row, col)| sudoku.board[row][col] != EMPTY)
+                    .collect();
+                if orbit.is_empty() {
+                    continue;
+                }
+                let digits: Vec<u8> = orbit
+                    .iter()
+                    .map(|&(row, col)| sudoku.board[row][col])
+                    .collect();
+                for &(row, col) in &orbit {
+                    sudoku.board[row][col] = EMPTY;
+                }
+                if sudoku.count_solutions(2) != 1 {
+                    for (&(row, col), &num) in orbit.iter().zip(&digits) {
+                        sudoku.board[row][col] = num;
+                    }
+                }
+            }
+        }
+        sudoku.original_board = sudoku.board.clone();
+        Ok(sudoku)
+    }
+}
@@ -0,0 +1,202 @@
+//! Rating and solving a puzzle in a single call, for consumers that don't need to
+//! drive the solver step by step.
+//!
+//! A [`Solver`] only reads the [`Board`] it's given and works on a private [`Sudoku`]
+//! created from it, so one solver and one board can be shared between threads, e.g.
+//! behind an `Arc` in the handlers of a web server, and used concurrently without
+//! cloning them first. The types of the solver are `Send` and `Sync`, and there is no
+//! global state.
+
+use crate::{
+    Backtracking, Board, BruteForceBackend, Budget, DifficultyLevel, Metadata, RATING_ALGORITHM,
+    RatingMode, Strategy, Sudoku,
+};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Reason why a puzzle can't be rated or solved, returned by the one-shot API: [`rate`],
+/// [`solve`], [`Solver`], [`Rerater`](crate::Rerater),
+/// [`RatedPuzzle::rate`](crate::RatedPuzzle::rate) and `verify_unique`. The other
+/// fallible APIs return a [`SudokuError`](crate::SudokuError), which this converts into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Error {
+    /// The input isn't a valid Sudoku board
+    Parse(String),
+    /// Some givens break the rules
+    Conflict,
+    /// The Sudoku has no solution at all
+    NoSolution,
+    /// The Sudoku has more than one solution
+    MultipleSolutions,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "Invalid Sudoku board: {}", e),
+            Error::Conflict => write!(f, "The Sudoku contains conflicting digits"),
+            Error::NoSolution => write!(f, "The Sudoku has no solution"),
+            Error::MultipleSolutions => write!(f, "The Sudoku has more than one solution"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Rating of a puzzle as computed by the human-like solver.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Rating {
+    pub puzzle: String,
+    /// Whether the human-like solver solved the puzzle. The difficulty and the
+    /// strategies of unsolved puzzles cover the steps up to where the solver got stuck.
+    pub solved: bool,
+    /// Score of the puzzle in the mode of the solver
+    pub difficulty: f64,
+    pub mode: RatingMode,
+    /// Level of the average difficulty, whatever the mode
+    pub level: DifficultyLevel,
+    /// Version of the rating algorithm, see [`RATING_ALGORITHM`]
+    pub algorithm: &'static str,
+    /// Number of candidates removed or cells set per strategy
+    pub strategies: BTreeMap<Strategy, usize>,
+    /// Metadata of the board, passed through
+    pub metadata: Metadata,
+}
+
+/// Solution of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Solution {
+    pub puzzle: String,
+    pub solution: String,
+    /// Whether the human-like solver found the solution, or backtracking was needed
+    pub solved_by_logic: bool,
+    /// Metadata of the board, passed through
+    pub metadata: Metadata,
+}
+
+/// Make sure the Sudoku has exactly one solution.
+pub(crate) fn check_unique(sudoku: &Sudoku) -> Result<(), Error> {
+    check_unique_with(sudoku, &Backtracking)
+}
+
+/// Make sure the Sudoku has exactly one solution, counting them with the backend.
+fn check_unique_with(sudoku: &Sudoku, backend: &dyn BruteForceBackend) -> Result<(), Error> {
+    if sudoku.has_conflicts() {
+        return Err(Error::Conflict);
+    }
+    match backend.count_solutions(sudoku, 2) {
+        0 => Err(Error::NoSolution),
+        1 => Ok(()),
+        _ => Err(Error::MultipleSolutions),
+    }
+}
+
+/// Rates and solves boards within a [`Budget`]. It holds no state besides its
+/// settings, so a single solver can serve any number of threads at once.
+#[derive(Debug, Clone)]
+pub struct Solver {
+    pub(crate) budget: Budget,
+    pub(crate) mode: RatingMode,
+    /// Checks uniqueness and completes the solutions the strategies don't find
+    pub(crate) backend: Arc<dyn BruteForceBackend>,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Solver {
+            budget: Budget::default(),
+            mode: RatingMode::default(),
+            backend: Arc::new(Backtracking),
+        }
+    }
+}
+
+impl Solver {
+    /// A solver without limits.
+    pub fn new() -> Solver {
+        Solver::default()
+    }
+
+    /// A solver giving up on the human-like strategies once the budget runs out. The
+    /// budget applies to each call separately.
+    pub fn with_budget(budget: Budget) -> Solver {
+        Solver {
+            budget,
+            ..Solver::default()
+        }
+    }
+
+    /// Score puzzles in the given mode instead of by their average difficulty.
+    pub fn with_rating_mode(self, mode: RatingMode) -> Solver {
+        Solver { mode, ..self }
+    }
+
+    /// Check uniqueness and complete solutions with the backend instead of the built-in
+    /// backtracker.
+    pub fn with_backend(self, backend: impl BruteForceBackend + 'static) -> Solver {
+        Solver {
+            backend: Arc::new(backend),
+            ..self
+        }
+    }
+
+    /// Rate the board, which must have exactly one solution.
+    pub fn rate(&self, board: &Board) -> Result<Rating, Error> {
+        let mut sudoku = Sudoku::from_board(board);
+        check_unique_with(&sudoku, self.backend.as_ref())?;
+        let solved = sudoku.solve_human_like_within(&self.budget).is_solved();
+        Ok(Rating {
+            puzzle: board.original_board(),
+            solved,
+            difficulty: sudoku.difficulty_in(self.mode),
+            mode: self.mode,
+            level: DifficultyLevel::of(sudoku.difficulty()),
+            algorithm: RATING_ALGORITHM,
+            strategies: sudoku.rating(),
+            metadata: board.metadata.clone(),
+        })
+    }
+
+    /// Solve the board, which must have exactly one solution. Where the strategies
+    /// aren't sufficient, the solution is completed by the backend.
+    pub fn solve(&self, board: &Board) -> Result<Solution, Error> {
+        let mut sudoku = Sudoku::from_board(board);
+        check_unique_with(&sudoku, self.backend.as_ref())?;
+        let solved_by_logic = sudoku.solve_human_like_within(&self.budget).is_solved();
+        if !solved_by_logic {
+            sudoku.board = self
+                .backend
+                .solutions(&sudoku, 1)
+                .pop()
+                .ok_or(Error::NoSolution)?;
+        }
+        Ok(Solution {
+            puzzle: board.original_board(),
+            solution: sudoku.serialized(),
+            solved_by_logic,
+            metadata: board.metadata.clone(),
+        })
+    }
+}
+
+/// Parse the puzzle, given in the format of [`Sudoku::set_board_string`].
+pub(crate) fn parse_board(puzzle: &str) -> Result<Board, Error> {
+    puzzle.trim().parse::<Board>().map_err(Error::Parse)
+}
+
+/// Rate the puzzle, given in the format of [`Sudoku::set_board_string`].
+pub fn rate(puzzle: &str) -> Result<Rating, Error> {
+    Solver::new().rate(&parse_board(puzzle)?)
+}
+
+/// Solve the puzzle, given in the format of [`Sudoku::set_board_string`]. Where the
+/// strategies aren't sufficient, the solution is completed by backtracking.
+pub fn solve(puzzle: &str) -> Result<Solution, Error> {
+    Solver::new().solve(&parse_board(puzzle)?)
+}
@@ -0,0 +1,288 @@
+//! The order in which the solver tries its detectors, and tuning it to a corpus. The
+//! solver takes the step of the first detector that finds one, so trying cheap
+//! detectors that often find a step first makes batch solving faster.
+
+use crate::analysis::{SEARCHES, Search};
+use crate::{Strategy, StrategyResult, Sudoku, SudokuError};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// The order in which [`Sudoku::next_step`] tries the strategies. The default is
+/// easiest first, which the ratings are made for: with another order the solver may
+/// take other steps, rating the same puzzle differently. Load an order tuned with
+/// [`tune_order`] where throughput matters more, e.g. when checking which puzzles of a
+/// large corpus the strategies can solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchOrder {
+    /// Indexes into `SEARCHES`
+    order: [usize; SEARCHES.len()],
+}
+
+impl Default for SearchOrder {
+    fn default() -> Self {
+        SearchOrder {
+            order: std::array::from_fn(|index| index),
+        }
+    }
+}
+
+impl SearchOrder {
+    /// The strategies in the given order first, then the others in the default order.
+    /// Fails if a strategy is listed twice.
+    pub fn from_strategies(strategies: &[Strategy]) -> Result<SearchOrder, SudokuError> {
+        let mut order = Vec::with_capacity(SEARCHES.len());
+        for strategy in strategies {
+            let index = SEARCHES
+                .iter()
+                .position(|(s, _)| s == strategy)
+                .ok_or_else(|| SudokuError::Parse(format!("No detector for {:?}", strategy)))?;
+            if order.contains(&index) {
+                return Err(SudokuError::Parse(format!(
+                    "{:?} is listed twice",
+                    strategy
+                )));
+            }
+            order.push(index);
+        }
+        for index in 0..SEARCHES.len() {
+            if !order.contains(&index) {
+                order.push(index);
+            }
+        }
+        Ok(SearchOrder {
+            order: order
+                .try_into()
+                .expect("every detector is in the order once"),
+        })
+    }
+
+    /// The strategies in the order they're tried.
+    pub fn strategies(&self) -> Vec<Strategy> {
+        self.searches().map(|(strategy, _)| strategy).collect()
+    }
+
+    pub(crate) fn searches(&self) -> impl Iterator<Item = (Strategy, Search)> {
+        self.order.into_iter().map(|index| SEARCHES[index].clone())
+    }
+}
+
+impl fmt::Display for SearchOrder {
+    /// The order profile: the identifier of each strategy on a line of its own.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for strategy in self.strategies() {
+            writeln!(f, "{:?}", strategy)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for SearchOrder {
+    type Err = SudokuError;
+    /// Parse an order profile as written by [`SearchOrder`]'s `Display`. Strategies not
+    /// mentioned are tried after the others, in the default order. Empty lines and
+    /// lines starting with `#` are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let strategies = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|name| {
+                Strategy::ALL
+                    .iter()
+                    .find(|strategy| format!("{:?}", strategy) == name)
+                    .cloned()
+                    .ok_or_else(|| SudokuError::Parse(format!("Unknown strategy: {}", name)))
+            })
+            .collect::<Result<Vec<Strategy>, SudokuError>>()?;
+        SearchOrder::from_strategies(&strategies)
+    }
+}
+
+/// How a detector did over the positions of a [`tune_order`] run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectorStats {
+    pub strategy: Strategy,
+    /// Number of positions in which the detector found a step
+    pub hits: usize,
+    /// Time the detector took over all positions
+    pub time: Duration,
+}
+
+/// Detector statistics of a corpus, and the order proposed from them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderTuning {
+    pub puzzles: usize,
+    /// Number of positions every detector ran in
+    pub positions: usize,
+    /// The detectors in the default order
+    pub detectors: Vec<DetectorStats>,
+    /// The detectors by their time per step found, fastest first. Those that never
+    /// found a step come last, in the default order.
+    pub order: SearchOrder,
+    /// Average time the solver would spend finding the next step in the positions, with
+    /// the order the puzzles were solved with and with the proposed one
+    pub time_per_step_before: Duration,
+    pub time_per_step_after: Duration,
+}
+
+impl OrderTuning {
+    /// Share of the positions in which the detector found a step, from 0 to 1.
+    pub fn hit_rate(&self, detector: &DetectorStats) -> f64 {
+        if self.positions == 0 {
+            return 0.0;
+        }
+        detector.hits as f64 / self.positions as f64
+    }
+
+    /// Average time the detector takes in a position.
+    pub fn cost(&self, detector: &DetectorStats) -> Duration {
+        if self.positions == 0 {
+            return Duration::ZERO;
+        }
+        detector.time / self.positions as u32
+    }
+}
+
+/// A table with a row per detector in the proposed order, and the estimated speedup.
+impl fmt::Display for OrderTuning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<20} {:>9} {:>10}",
+            "Strategy", "Hit rate", "Cost (µs)"
+        )?;
+        for strategy in self.order.strategies() {
+            let detector = self
+                .detectors
+                .iter()
+                .find(|detector| detector.strategy == strategy)
+                .expect("every detector has statistics");
+            writeln!(
+                f,
+                "{:<20} {:>8.1}% {:>10.2}",
+                strategy.to_string(),
+                100.0 * self.hit_rate(detector),
+                1e6 * self.cost(detector).as_secs_f64()
+            )?;
+        }
+        writeln!(
+            f,
+            "Time per step: {:.2} µs before, {:.2} µs after",
+            1e6 * self.time_per_step_before.as_secs_f64(),
+            1e6 * self.time_per_step_after.as_secs_f64()
+        )
+    }
+}
+
+/// Solve the puzzles, each with its own [`SearchOrder`], running every detector in
+/// each position to measure how often it finds a step and how long it takes, and
+/// propose the order that finds the next step fastest on average. That's the order
+/// by time per step found, the average time divided by the hit rate.
+///
+/// The positions are those reached with the order the puzzles are solved with; with
+/// the proposed order the solver may go through others, so the times after are an
+/// estimate.
+pub fn tune_order(puzzles: &[Sudoku]) -> OrderTuning {
+    let mut detectors: Vec<DetectorStats> = SEARCHES
+        .into_iter()
+        .map(|(strategy, _)| DetectorStats {
+            strategy,
+            hits: 0,
+            time: Duration::ZERO,
+        })
+        .collect();
+    // The detectors that found a step in each position, as a bit per detector, and
+    // the order the position was solved with
+    let mut positions: Vec<(u32, SearchOrder)> = Vec::new();
+    for puzzle in puzzles {
+        let mut sudoku = puzzle.clone();
+        sudoku.undo_stack.clear();
+        sudoku.calc_all_notes();
+        while sudoku.unsolved() && sudoku.find_contradiction().is_none() {
+            let results: Vec<StrategyResult> = SEARCHES
+                .into_iter()
+                .zip(&mut detectors)
+                .map(|((strategy, search), detector)| {
+                    let start = Instant::now();
+                    let result = sudoku.first_hit(strategy, search);
+                    detector.time += start.elapsed();
+                    result
+                })
+                .collect();
+            let mut hits = 0;
+            for ((index, result), detector) in results.iter().enumerate().zip(&mut detectors) {
+                if result.removals.will_remove_candidates() {
+                    hits |= 1 << index;
+                    detector.hits += 1;
+                }
+            }
+            positions.push((hits, sudoku.search_order));
+            let step = sudoku
+                .search_order
+                .order
+                .into_iter()
+                .find(|&index| hits & 1 << index != 0);
+            let Some(index) = step else {
+                break;
+            };
+            sudoku
+                .apply(&results[index])
+                .expect("steps found on the board fit it");
+            sudoku.undo_stack.clear();
+        }
+    }
+
+    let count = positions.len().max(1) as f64;
+    let cost: Vec<f64> = detectors
+        .iter()
+        .map(|detector| detector.time.as_secs_f64() / count)
+        .collect();
+    let mut order: Vec<usize> = (0..SEARCHES.len()).collect();
+    let time_per_hit = |index: usize| match detectors[index].hits {
+        0 => f64::INFINITY,
+        hits => cost[index] * count / hits as f64,
+    };
+    order.sort_by(|&a, &b| time_per_hit(a).total_cmp(&time_per_hit(b)));
+    let order = SearchOrder {
+        order: order
+            .try_into()
+            .expect("every detector is in the order once"),
+    };
+    // Time of the detectors tried in a position until the first one finding a step
+    let time_per_step = |hits: u32, order: &SearchOrder| -> f64 {
+        let mut time = 0.0;
+        for index in order.order {
+            time += cost[index];
+            if hits & 1 << index != 0 {
+                break;
+            }
+        }
+        time
+    };
+    let before: f64 = positions
+        .iter()
+        .map(|(hits, solved_with)| time_per_step(*hits, solved_with))
+        .sum();
+    let after: f64 = positions
+        .iter()
+        .map(|(hits, _)| time_per_step(*hits, &order))
+        .sum();
+    OrderTuning {
+        puzzles: puzzles.len(),
+        positions: positions.len(),
+        detectors,
+        order,
+        time_per_step_before: Duration::from_secs_f64(before / count),
+        time_per_step_after: Duration::from_secs_f64(after / count),
+    }
+}
+
+impl Sudoku {
+    /// Try the strategies in the given order when looking for the next step.
+    pub fn with_search_order(self, search_order: SearchOrder) -> Sudoku {
+        Sudoku {
+            search_order,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Searching independent parts of the grid, e.g. the units for pairs or the digits for
+//! fish, on all cores if the `parallel` feature is enabled.
+
+/// Apply `find` to the items and return the first hit in the order of the items, so that
+/// the result doesn't depend on whether the search runs in parallel.
+#[cfg(feature = "parallel")]
+pub(crate) fn find_first<T, R, F>(items: Vec<T>, find: F) -> Option<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> Option<R> + Sync + Send,
+{
+    use rayon::prelude::*;
+    items.into_par_iter().find_map_first(find)
+}
+
+/// Apply `find` to the items and return the first hit in the order of the items.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn find_first<T, R, F>(items: Vec<T>, find: F) -> Option<R>
+where
+    F: Fn(T) -> Option<R>,
+{
+    items.into_iter().find_map(find)
+}
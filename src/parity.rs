@@ -0,0 +1,55 @@
+//! Odd/even Sudoku: cells can be marked so that they only take odd or only even digits.
+
+use crate::{Sudoku, SudokuError};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Parity {
+    #[default]
+    Any,
+    Odd,
+    Even,
+}
+
+impl Parity {
+    pub fn allows(self, num: u8) -> bool {
+        match self {
+            Parity::Any => true,
+            Parity::Odd => !num.is_multiple_of(2),
+            Parity::Even => num.is_multiple_of(2),
+        }
+    }
+}
+
+impl Sudoku {
+    /// Mark cells as odd or even with a mask of one character per cell: `o` for odd,
+    /// `e` for even, and `.` or `0` for unrestricted cells, e.g. `o..e.....`.
+    /// Whitespace is ignored.
+    pub fn set_parity_string(&mut self, s: &str) -> Result<(), SudokuError> {
+        let size = self.size();
+        let mask: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if mask.len() != size * size {
+            return Err(SudokuError::Parse(format!(
+                "Parity mask must contain exactly {} characters",
+                size * size
+            )));
+        }
+        let mut parity = vec![vec![Parity::Any; size]; size];
+        for (idx, c) in mask.into_iter().enumerate() {
+            parity[idx / size][idx % size] = match c.to_ascii_lowercase() {
+                'o' => Parity::Odd,
+                'e' => Parity::Even,
+                '.' | '0' => Parity::Any,
+                _ => return Err(SudokuError::Parse(format!("Invalid parity mark '{}'", c))),
+            };
+        }
+        self.parity = parity;
+        Ok(())
+    }
+
+    pub fn parity(&self, row: usize, col: usize) -> Parity {
+        self.parity[row][col]
+    }
+}
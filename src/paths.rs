@@ -0,0 +1,102 @@
+//! Rating a puzzle along several solve paths. The solver always takes a step of the
+//! easiest strategy that applies, but a human may spot a harder step first, so the
+//! difficulty along other paths gives a range rather than a single number.
+
+use crate::{FirstFound, HardestFirst, SeededRandom, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// How to choose among the steps available in a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum PathOrder {
+    /// The step the solver takes, see [`FirstFound`]
+    FirstFound,
+    /// A step of the hardest strategy that applies, see [`HardestFirst`]
+    HardestFirst,
+    /// Any step, chosen at random with the given seed, see [`SeededRandom`]
+    Random(u64),
+}
+
+/// Rating along one solve path.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PathRating {
+    pub order: PathOrder,
+    /// Whether the path reaches the solution. Otherwise the difficulty covers the steps
+    /// up to where it got stuck.
+    pub solved: bool,
+    pub difficulty: f64,
+    pub steps: usize,
+}
+
+/// Ratings along several solve paths and their spread.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PathSpread {
+    pub paths: Vec<PathRating>,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// Standard deviation of the difficulties
+    pub std_dev: f64,
+}
+
+impl PathOrder {
+    /// `count` distinct orders: the solver's, hardest first, and random orders with the
+    /// seeds 1, 2, …
+    pub fn first(count: usize) -> Vec<PathOrder> {
+        [PathOrder::FirstFound, PathOrder::HardestFirst]
+            .into_iter()
+            .chain((1..).map(PathOrder::Random))
+            .take(count)
+            .collect()
+    }
+}
+
+impl Sudoku {
+    /// Solve a copy of the Sudoku from its current digits, choosing the steps in the
+    /// given order.
+    pub fn rate_path(&self, order: PathOrder) -> PathRating {
+        let mut sudoku = self.clone();
+        sudoku.calc_all_notes();
+        sudoku.rating.clear();
+        let (_, steps) = match order {
+            PathOrder::FirstFound => sudoku.follow_policy(&mut FirstFound),
+            PathOrder::HardestFirst => sudoku.follow_policy(&mut HardestFirst),
+            PathOrder::Random(seed) => sudoku.follow_policy(&mut SeededRandom::new(seed)),
+        };
+        PathRating {
+            order,
+            solved: sudoku.is_solved(),
+            difficulty: if steps == 0 { 0.0 } else { sudoku.difficulty() },
+            steps,
+        }
+    }
+
+    /// Rate the Sudoku along each of the orders, see [`PathOrder::first`], and compute
+    /// the spread of the difficulties. Returns `None` if no order is given.
+    pub fn rate_paths(&self, orders: &[PathOrder]) -> Option<PathSpread> {
+        if orders.is_empty() {
+            return None;
+        }
+        let paths: Vec<PathRating> = orders.iter().map(|&order| self.rate_path(order)).collect();
+        let difficulties: Vec<f64> = paths.iter().map(|path| path.difficulty).collect();
+        let mean = difficulties.iter().sum::<f64>() / difficulties.len() as f64;
+        let variance = difficulties
+            .iter()
+            .map(|difficulty| (difficulty - mean).powi(2))
+            .sum::<f64>()
+            / difficulties.len() as f64;
+        Some(PathSpread {
+            min: difficulties.iter().cloned().fold(f64::INFINITY, f64::min),
+            max: difficulties
+                .iter()
+                .cloned()
+                .fold(f64::NEG_INFINITY, f64::max),
+            mean,
+            std_dev: variance.sqrt(),
+            paths,
+        })
+    }
+}
@@ -0,0 +1,128 @@
+//! The pattern formed by the givens, which puzzle editors show alongside the rating.
+
+use crate::{EMPTY, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Symmetry of the pattern of givens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Symmetry {
+    #[default]
+    None,
+    /// Unchanged by turning the grid by 180°
+    Rotational,
+    /// Unchanged by mirroring the grid top to bottom
+    Horizontal,
+    /// Unchanged by mirroring the grid left to right
+    Vertical,
+    /// Unchanged by mirroring the grid along the main diagonal
+    Diagonal,
+    /// Unchanged by mirroring the grid along the anti-diagonal
+    AntiDiagonal,
+}
+
+impl Symmetry {
+    /// All symmetries besides `None`.
+    pub const ALL: [Symmetry; 5] = [
+        Symmetry::Rotational,
+        Symmetry::Horizontal,
+        Symmetry::Vertical,
+        Symmetry::Diagonal,
+        Symmetry::AntiDiagonal,
+    ];
+
+    /// The cell the symmetry maps the cell to.
+    pub fn image(self, size: usize, row: usize, col: usize) -> (usize, usize) {
+        match self {
+            Symmetry::None => (row, col),
+            Symmetry::Rotational => (size - 1 - row, size - 1 - col),
+            Symmetry::Horizontal => (size - 1 - row, col),
+            Symmetry::Vertical => (row, size - 1 - col),
+            Symmetry::Diagonal => (col, row),
+            Symmetry::AntiDiagonal => (size - 1 - col, size - 1 - row),
+        }
+    }
+
+    /// The cell and its image under the symmetry, without duplicates.
+    pub fn orbit(self, size: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let image = self.image(size, row, col);
+        if image == (row, col) {
+            vec![(row, col)]
+        } else {
+            vec![(row, col), image]
+        }
+    }
+}
+
+impl std::str::FromStr for Symmetry {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Symmetry::None),
+            "rotational" => Ok(Symmetry::Rotational),
+            "horizontal" => Ok(Symmetry::Horizontal),
+            "vertical" => Ok(Symmetry::Vertical),
+            "diagonal" => Ok(Symmetry::Diagonal),
+            "anti-diagonal" | "antidiagonal" => Ok(Symmetry::AntiDiagonal),
+            _ => Err(format!("Unknown symmetry: {}", s)),
+        }
+    }
+}
+
+/// Where the givens of a puzzle are, regardless of their digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Pattern {
+    pub givens: usize,
+    /// Symmetries of the pattern, in the order of [`Symmetry::ALL`]. Empty if the
+    /// pattern has none.
+    pub symmetries: Vec<Symmetry>,
+    /// Number of givens in each band of rows, from top to bottom
+    pub bands: Vec<usize>,
+    /// Number of givens in each stack of columns, from left to right
+    pub stacks: Vec<usize>,
+    /// One line per row with `X` for a given and `.` for an empty cell
+    pub mask: String,
+}
+
+impl Sudoku {
+    /// Analyze the pattern of the givens: their symmetries, how they are spread over
+    /// the bands and stacks, and a mask to display it.
+    pub fn analyze_pattern(&self) -> Pattern {
+        let size = self.size();
+        let given = |(row, col): (usize, usize)| self.original_board[row][col] != EMPTY;
+        let cells: Vec<(usize, usize)> = (0..size)
+            .flat_map(|row| (0..size).map(move |col| (row, col)))
+            .collect();
+        let symmetries = Symmetry::ALL
+            .into_iter()
+            .filter(|symmetry| {
+                cells
+                    .iter()
+                    .all(|&(row, col)| given((row, col)) == given(symmetry.image(size, row, col)))
+            })
+            .collect();
+        let mut bands = vec![0; size / self.spec.box_rows];
+        let mut stacks = vec![0; size / self.spec.box_cols];
+        for &(row, col) in cells.iter().filter(|&&cell| given(cell)) {
+            bands[row / self.spec.box_rows] += 1;
+            stacks[col / self.spec.box_cols] += 1;
+        }
+        let mask = (0..size)
+            .map(|row| {
+                (0..size)
+                    .map(|col| if given((row, col)) { 'X' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        Pattern {
+            givens: bands.iter().sum(),
+            symmetries,
+            bands,
+            stacks,
+            mask,
+        }
+    }
+}
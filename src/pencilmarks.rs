@@ -0,0 +1,137 @@
+//! Positions given as pencilmark grids, i.e. digits and candidates without the givens
+//! they came from, e.g. positions set up by a teacher or exported from other tools.
+
+use crate::{
+    Coord, DifficultyLevel, DigitSet, EMPTY, GridSpec, RATING_ALGORITHM, Rating, RatingMode,
+    Sudoku, SudokuError,
+};
+
+/// Characters drawing the grid around the cells, e.g. in the pencilmark grids of HoDoKu.
+fn is_decoration(c: char) -> bool {
+    matches!(c, '|' | '-' | '+' | ':' | '.' | '\'' | '*' | '=') || ('─'..='╿').contains(&c)
+}
+
+/// A cell of a pencilmark grid as written: a digit, or candidates.
+enum Token<'a> {
+    Digit(char),
+    Notes(&'a str),
+}
+
+impl Sudoku {
+    /// The digits and notes as text, one row per line with the cells separated by
+    /// spaces. A filled cell shows its digit, an empty cell its notes in brackets, e.g.
+    /// `[258]`, so that cells with a single note can't be mistaken for filled ones.
+    pub fn pencilmarks(&self) -> String {
+        let mut text = String::new();
+        for (row, notes) in self.board.iter().zip(&self.candidates) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(notes)
+                .map(|(&num, notes)| {
+                    if num != EMPTY {
+                        self.spec.digit_char(num).to_string()
+                    } else {
+                        let notes: String =
+                            notes.iter().map(|num| self.spec.digit_char(num)).collect();
+                        format!("[{}]", notes)
+                    }
+                })
+                .collect();
+            text += &cells.join(" ");
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Read a position from a pencilmark grid, as written by [`Sudoku::pencilmarks`] or
+    /// by other tools: the cells in row-major order, separated by whitespace. A single
+    /// digit is a filled cell, several digits or digits in brackets are the candidates of
+    /// an empty cell. Lines and corners drawn around the cells are skipped.
+    ///
+    /// The digits become the givens. Since the position needn't derive from a puzzle,
+    /// it's checked for consistency instead: no digit may repeat in a unit, no candidate
+    /// may be placed in a cell it sees, and every empty cell and every missing digit of
+    /// a unit needs a candidate.
+    pub fn from_pencilmarks(grid: &str) -> Result<Sudoku, SudokuError> {
+        let tokens: Vec<Token> = grid
+            .split(|c: char| c.is_whitespace() || c == '|')
+            .filter(|token| !token.is_empty() && !token.chars().all(is_decoration))
+            .map(|token| {
+                if let Some(notes) = token.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+                    Ok(Token::Notes(notes))
+                } else if token.chars().count() == 1 {
+                    Ok(Token::Digit(token.chars().next().unwrap()))
+                } else if token.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    Ok(Token::Notes(token))
+                } else {
+                    Err(SudokuError::Parse(format!("Invalid cell '{}'", token)))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        let spec = GridSpec::from_cell_count(tokens.len()).ok_or_else(|| {
+            SudokuError::Parse(format!(
+                "A pencilmark grid must contain exactly 36, 81 or 256 cells, found {}",
+                tokens.len()
+            ))
+        })?;
+        let size = spec.size();
+        let digit = |c: char| match spec.parse_cell(c) {
+            Some(num) if num != EMPTY => Ok(num),
+            _ => Err(SudokuError::Parse(format!("Invalid digit '{}'", c))),
+        };
+        let mut sudoku = Sudoku::with_spec(spec);
+        for (idx, token) in tokens.into_iter().enumerate() {
+            let (row, col) = (idx / size, idx % size);
+            match token {
+                Token::Digit(c) => sudoku.board[row][col] = digit(c)?,
+                Token::Notes(notes) => {
+                    sudoku.candidates[row][col] =
+                        notes.chars().map(digit).collect::<Result<DigitSet, _>>()?;
+                }
+            }
+        }
+        sudoku.original_board = sudoku.board.clone();
+        if sudoku.has_conflicts() {
+            return Err(SudokuError::InvalidBoard(
+                "The pencilmarks contain conflicting digits".to_string(),
+            ));
+        }
+        for Coord { row, col } in sudoku.empty_cells() {
+            if let Some(num) = sudoku.candidates[row][col]
+                .iter()
+                .find(|&num| !sudoku.can_place(row, col, num))
+            {
+                return Err(SudokuError::InvalidBoard(format!(
+                    "r{}c{} has the candidate {}, which is placed in a cell it sees",
+                    row + 1,
+                    col + 1,
+                    spec.digit_char(num)
+                )));
+            }
+        }
+        if let Some(contradiction) = sudoku.find_contradiction() {
+            return Err(SudokuError::Contradiction(contradiction));
+        }
+        Ok(sudoku)
+    }
+}
+
+/// Rate the position given as a pencilmark grid, see [`Sudoku::from_pencilmarks`], by
+/// solving it from its candidates. Unlike [`rate`](crate::rate), the solution needn't be
+/// unique.
+pub fn rate_pencilmarks(grid: &str) -> Result<Rating, SudokuError> {
+    let mut sudoku = Sudoku::from_pencilmarks(grid)?;
+    let puzzle = sudoku.serialized();
+    let solved = sudoku.solve_from_notes().is_solved();
+    let difficulty = sudoku.difficulty();
+    Ok(Rating {
+        puzzle,
+        solved,
+        difficulty,
+        mode: RatingMode::Average,
+        level: DifficultyLevel::of(difficulty),
+        algorithm: RATING_ALGORITHM,
+        strategies: sudoku.rating(),
+        metadata: sudoku.metadata,
+    })
+}
@@ -0,0 +1,155 @@
+//! Placing and removing digits by hand, as needed by interactive frontends.
+
+use crate::{Cell, Coord, EMPTY, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Reason why a digit can't be placed in or removed from a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum PlacementError {
+    /// The cell lies outside the grid
+    OutOfBounds,
+    /// The digit isn't in the range of the grid
+    InvalidDigit(u8),
+    /// The cell holds a given, which can't be changed
+    Given,
+    /// The cell already holds a digit
+    Occupied,
+    /// The digit breaks one of the rules
+    Conflict,
+}
+
+impl fmt::Display for PlacementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlacementError::OutOfBounds => write!(f, "Cell lies outside the grid"),
+            PlacementError::InvalidDigit(num) => write!(f, "Invalid digit {}", num),
+            PlacementError::Given => write!(f, "Cell holds a given"),
+            PlacementError::Occupied => write!(f, "Cell already holds a digit"),
+            PlacementError::Conflict => write!(f, "Digit conflicts with the rules"),
+        }
+    }
+}
+
+impl std::error::Error for PlacementError {}
+
+impl Sudoku {
+    fn check_cell(&self, row: usize, col: usize) -> Result<(), PlacementError> {
+        if row >= self.size() || col >= self.size() {
+            return Err(PlacementError::OutOfBounds);
+        }
+        if self.original_board[row][col] != EMPTY {
+            return Err(PlacementError::Given);
+        }
+        Ok(())
+    }
+
+    /// Place `num` in the cell if the rules allow it, and remove it from the notes
+    /// of the cell's peers. The step can be undone with [`Sudoku::undo`].
+    pub fn set_cell(&mut self, row: usize, col: usize, num: u8) -> Result<(), PlacementError> {
+        self.check_cell(row, col)?;
+        if num == EMPTY || num as usize > self.size() {
+            return Err(PlacementError::InvalidDigit(num));
+        }
+        if self.board[row][col] != EMPTY {
+            return Err(PlacementError::Occupied);
+        }
+        if !self.can_place(row, col, num) {
+            return Err(PlacementError::Conflict);
+        }
+        self.push_undo();
+        let removals = self.collect_set_num(num, row, col);
+        for candidate in &removals.candidates_about_to_be_removed {
+            self.candidates[candidate.row][candidate.col].remove(&candidate.num);
+        }
+        self.board[row][col] = num;
+        Ok(())
+    }
+
+    /// Remove the digit from the cell. The cell gets back all candidates the rules
+    /// allow, and the digit becomes a candidate again in the peers where it fits.
+    /// The step can be undone with [`Sudoku::undo`].
+    pub fn clear_cell(&mut self, row: usize, col: usize) -> Result<(), PlacementError> {
+        self.check_cell(row, col)?;
+        let num = self.board[row][col];
+        if num == EMPTY {
+            return Ok(());
+        }
+        self.push_undo();
+        self.board[row][col] = EMPTY;
+        self.candidates[row][col] = (1..=self.size() as u8)
+            .filter(|&n| self.can_place(row, col, n))
+            .collect();
+        for peer in Coord::new(row, col).peers(self) {
+            if self.board[peer.row][peer.col] == EMPTY && self.can_place(peer.row, peer.col, num) {
+                self.candidates[peer.row][peer.col].insert(num);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add `num` to the notes of an empty cell, or remove it if it's there already.
+    /// Returns whether `num` is a note afterwards. Unlike the solver, players may note
+    /// digits that break the rules. The step can be undone with [`Sudoku::undo`].
+    pub fn toggle_note(&mut self, row: usize, col: usize, num: u8) -> Result<bool, PlacementError> {
+        self.check_cell(row, col)?;
+        if num == EMPTY || num as usize > self.size() {
+            return Err(PlacementError::InvalidDigit(num));
+        }
+        if self.board[row][col] != EMPTY {
+            return Err(PlacementError::Occupied);
+        }
+        self.push_undo();
+        let notes = &mut self.candidates[row][col];
+        if notes.remove(&num) {
+            Ok(false)
+        } else {
+            notes.insert(num);
+            Ok(true)
+        }
+    }
+
+    /// Pairs of cells holding the same digit while sharing a unit, or while one of
+    /// the constraints forbids it, e.g. a knight's move apart in Anti-Knight Sudoku.
+    /// The first cell of each pair comes first in row-major order, and the pairs are
+    /// sorted in the same way. Frontends can check this after each move to highlight
+    /// mistakes.
+    pub fn conflicts(&self) -> Vec<(Cell, Cell)> {
+        let filled = |&(row, col): &(usize, usize)| self.board[row][col] != EMPTY;
+        let mut pairs = BTreeSet::new();
+        let mut add = |a: (usize, usize), b: (usize, usize)| {
+            if a != b && self.board[a.0][a.1] == self.board[b.0][b.1] {
+                let (a, b) = (Coord::new(a.0, a.1), Coord::new(b.0, b.1));
+                pairs.insert((a.min(b), a.max(b)));
+            }
+        };
+        for group in self.groups() {
+            let cells: Vec<(usize, usize)> = group.cells.into_iter().filter(filled).collect();
+            for (i, &a) in cells.iter().enumerate() {
+                for &b in &cells[i + 1..] {
+                    add(a, b);
+                }
+            }
+        }
+        if !self.constraints.is_empty() {
+            for row in 0..self.size() {
+                for col in 0..self.size() {
+                    if filled(&(row, col)) {
+                        for peer in self.constraint_peers(row, col) {
+                            add((row, col), peer);
+                        }
+                    }
+                }
+            }
+        }
+        let cell = |coord: Coord| Cell {
+            row: coord.row,
+            col: coord.col,
+            num: self.board[coord.row][coord.col],
+        };
+        pairs.into_iter().map(|(a, b)| (cell(a), cell(b))).collect()
+    }
+}
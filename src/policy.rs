@@ -0,0 +1,121 @@
+//! Choosing among several steps available in the same position. The solver takes the
+//! first step of the easiest strategy it finds; a [`StepPolicy`] makes that choice
+//! pluggable, e.g. to see how much the rating depends on which step is taken.
+
+use crate::{Analysis, SolveOutcome, StrategyResult, Sudoku};
+
+/// Picks the step to take among all steps available in a position.
+pub trait StepPolicy {
+    /// Choose one of the steps of the analysis, or `None` to stop solving. The
+    /// strategies of the analysis are in the order the solver tries them, and their
+    /// steps in the order they're found.
+    fn choose<'a>(&mut self, analysis: &'a Analysis) -> Option<&'a StrategyResult>;
+}
+
+/// The step the solver takes: the first one found of the first strategy that applies.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FirstFound;
+
+/// A step of the easiest strategy that applies. The solver tries some harder
+/// strategies early, e.g. those of the variants, which this policy puts last. Among
+/// strategies of the same difficulty, the one the solver tries first wins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EasiestFirst;
+
+/// A step of the hardest strategy that applies. Among strategies of the same
+/// difficulty, the one the solver tries last wins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HardestFirst;
+
+/// Any step, chosen uniformly at random. The same seed always leads to the same
+/// choices, and no `rand` dependency is needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeededRandom {
+    state: u64,
+}
+
+impl SeededRandom {
+    pub fn new(seed: u64) -> SeededRandom {
+        SeededRandom { state: seed }
+    }
+
+    /// Next number of the SplitMix64 sequence.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl StepPolicy for FirstFound {
+    fn choose<'a>(&mut self, analysis: &'a Analysis) -> Option<&'a StrategyResult> {
+        analysis.strategies.first().map(|(_, steps)| &steps[0])
+    }
+}
+
+impl StepPolicy for EasiestFirst {
+    fn choose<'a>(&mut self, analysis: &'a Analysis) -> Option<&'a StrategyResult> {
+        analysis
+            .strategies
+            .iter()
+            .min_by_key(|(strategy, _)| strategy.difficulty())
+            .map(|(_, steps)| &steps[0])
+    }
+}
+
+impl StepPolicy for HardestFirst {
+    fn choose<'a>(&mut self, analysis: &'a Analysis) -> Option<&'a StrategyResult> {
+        analysis
+            .strategies
+            .iter()
+            .max_by_key(|(strategy, _)| strategy.difficulty())
+            .map(|(_, steps)| &steps[0])
+    }
+}
+
+impl StepPolicy for SeededRandom {
+    fn choose<'a>(&mut self, analysis: &'a Analysis) -> Option<&'a StrategyResult> {
+        let count = analysis.step_count();
+        if count == 0 {
+            return None;
+        }
+        let index = (self.next_u64() % count as u64) as usize;
+        analysis
+            .strategies
+            .iter()
+            .flat_map(|(_, steps)| steps)
+            .nth(index)
+    }
+}
+
+impl Sudoku {
+    /// Solve with human-like strategies like [`Sudoku::solve_human_like`], but let the
+    /// policy choose which of the available steps to take. The rating counts the
+    /// candidates each step removes, like the solver does.
+    pub fn solve_with_policy(&mut self, policy: &mut dyn StepPolicy) -> SolveOutcome {
+        self.calc_all_notes();
+        self.rating.clear();
+        self.follow_policy(policy).0
+    }
+
+    /// Take the steps chosen by the policy from the current notes, until the Sudoku is
+    /// solved or no step is left. Returns the outcome and the number of steps taken.
+    pub(crate) fn follow_policy(&mut self, policy: &mut dyn StepPolicy) -> (SolveOutcome, usize) {
+        let mut steps = 0;
+        while self.unsolved() {
+            if let Some(contradiction) = self.find_contradiction() {
+                return (SolveOutcome::Contradiction(contradiction), steps);
+            }
+            let analysis = self.analyze();
+            let Some(step) = policy.choose(&analysis) else {
+                return (SolveOutcome::StuckAt(self.empty_cells().count()), steps);
+            };
+            self.rate_step(step);
+            self.apply(step).expect("steps found on the board fit it");
+            steps += 1;
+        }
+        (SolveOutcome::Solved, steps)
+    }
+}
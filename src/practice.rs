@@ -0,0 +1,64 @@
+//! Drills for a single technique: positions from solving a puzzle where the solver
+//! needs the technique for its next step, as standalone puzzles with pencilmarks.
+
+use crate::{Strategy, StrategyResult, Sudoku};
+
+/// A position in which a technique is the easiest way forward.
+#[derive(Debug)]
+pub struct PracticePosition {
+    /// The position as a puzzle of its own: the digits placed so far are its givens,
+    /// and its notes are the pencilmarks the solver had
+    pub sudoku: Sudoku,
+    /// The step the solver took, i.e. the solution of the drill
+    pub step: StrategyResult,
+    /// Number of steps the solver took before reaching the position
+    pub steps_before: usize,
+}
+
+impl PracticePosition {
+    /// The digits and pencilmarks as text, see [`Sudoku::pencilmarks`].
+    pub fn pencilmarks(&self) -> String {
+        self.sudoku.pencilmarks()
+    }
+}
+
+impl Sudoku {
+    /// Solve a copy of the Sudoku like the human-like solver and collect the positions in
+    /// which its next step uses the strategy, i.e. no easier strategy applies.
+    pub fn practice_positions(&self, strategy: &Strategy) -> Vec<PracticePosition> {
+        let mut sudoku = self.clone();
+        sudoku.calc_all_notes();
+        sudoku.rating.clear();
+        let mut positions = Vec::new();
+        let mut steps = 0;
+        while sudoku.unsolved() && sudoku.find_contradiction().is_none() {
+            let step = sudoku.next_step();
+            if step.strategy == Strategy::None {
+                break;
+            }
+            let position = (step.strategy == *strategy).then(|| sudoku.as_puzzle());
+            sudoku
+                .apply(&step)
+                .expect("steps found on the board fit it");
+            if let Some(position) = position {
+                positions.push(PracticePosition {
+                    sudoku: position,
+                    step,
+                    steps_before: steps,
+                });
+            }
+            steps += 1;
+        }
+        positions
+    }
+
+    /// The current position as a new puzzle with the same variant clues and notes, whose
+    /// givens are the digits placed so far.
+    fn as_puzzle(&self) -> Sudoku {
+        let mut puzzle = self.clone();
+        puzzle.original_board = puzzle.board.clone();
+        puzzle.rating.clear();
+        puzzle.undo_stack.clear();
+        puzzle
+    }
+}
@@ -0,0 +1,8 @@
+//! The types most programs using the crate need, to be imported with
+//! `use rate_my_sudoku::prelude::*;`.
+
+pub use crate::{
+    ApplyError, Board, Budget, Candidate, Cell, Coord, DifficultyLevel, EMPTY, Error, GridSpec,
+    House, Rating, Solution, SolveOutcome, Solver, Strategy, StrategyResult, Sudoku, Unit, rate,
+    solve,
+};
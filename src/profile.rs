@@ -0,0 +1,120 @@
+//! Where the human-like solver spends its time: how often each detector runs, how often
+//! it finds a step, and how long it takes, e.g. to see which strategy to speed up first.
+
+use crate::analysis::SEARCHES;
+use crate::{Budget, SolveOutcome, Strategy, Sudoku};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Time spent in the detector of a strategy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrategyProfile {
+    pub strategy: Strategy,
+    /// Number of times the detector ran
+    pub calls: usize,
+    /// Number of times it found the step the solver took
+    pub steps: usize,
+    pub time: Duration,
+}
+
+/// Timings of solving one or more puzzles with [`Sudoku::solve_profiled`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub puzzles: usize,
+    pub solved: usize,
+    /// Time spent solving, including the notes and applying the steps
+    pub time: Duration,
+    /// Detectors in the default order
+    pub strategies: Vec<StrategyProfile>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile {
+            puzzles: 0,
+            solved: 0,
+            time: Duration::ZERO,
+            strategies: SEARCHES
+                .into_iter()
+                .map(|(strategy, _)| StrategyProfile {
+                    strategy,
+                    calls: 0,
+                    steps: 0,
+                    time: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Profile {
+    pub fn new() -> Profile {
+        Profile::default()
+    }
+
+    /// Puzzles solved or given up on per second.
+    pub fn puzzles_per_second(&self) -> f64 {
+        self.puzzles as f64 / self.time.as_secs_f64()
+    }
+
+    /// Share of the solving time the detector took, from 0 to 1.
+    pub fn time_share(&self, strategy: &StrategyProfile) -> f64 {
+        strategy.time.as_secs_f64() / self.time.as_secs_f64()
+    }
+}
+
+/// A table with a row per detector that ran.
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<20} {:>10} {:>8} {:>10} {:>6}",
+            "Strategy", "Calls", "Steps", "Time (ms)", "Share"
+        )?;
+        for strategy in self.strategies.iter().filter(|strategy| strategy.calls > 0) {
+            writeln!(
+                f,
+                "{:<20} {:>10} {:>8} {:>10.1} {:>5.1}%",
+                strategy.strategy.to_string(),
+                strategy.calls,
+                strategy.steps,
+                1e3 * strategy.time.as_secs_f64(),
+                100.0 * self.time_share(strategy)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Sudoku {
+    /// Solve like [`Sudoku::solve_human_like`], timing each detector, and add the
+    /// timings to the profile.
+    pub fn solve_profiled(&mut self, profile: &mut Profile) -> SolveOutcome {
+        let start = Instant::now();
+        self.calc_all_notes();
+        self.rating.clear();
+        let timings = &mut profile.strategies;
+        let outcome = self.solve_from_here(&Budget::unlimited(), None, &mut (), &mut |sudoku| {
+            sudoku.next_step_with(|sudoku, strategy, search| {
+                let timing = timings
+                    .iter_mut()
+                    .find(|timing| timing.strategy == strategy)
+                    .expect("every detector has a timing");
+                let detector_start = Instant::now();
+                let result = sudoku.first_hit(strategy, search);
+                timing.time += detector_start.elapsed();
+                timing.calls += 1;
+                if result.removals.will_remove_candidates() {
+                    timing.steps += 1;
+                }
+                result
+            })
+        });
+        profile.time += start.elapsed();
+        profile.puzzles += 1;
+        if outcome.is_solved() {
+            profile.solved += 1;
+        }
+        outcome
+    }
+}
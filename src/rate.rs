@@ -1,17 +1,276 @@
-use rate_my_sudoku::Sudoku;
+use rate_my_sudoku::{Checkpoint, Corpus, CorpusStats, Error, RatedPuzzle, Record, Sudoku};
+use std::io::{self, Seek, Write};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
+/// The system allocator, counting allocations for `bench`. Only with the
+/// `count-allocations` feature, so that the other subcommands don't pay for it.
+#[cfg(feature = "count-allocations")]
+mod allocations {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingAllocator;
+
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Number of allocations since the start.
+    pub fn count() -> Option<usize> {
+        Some(ALLOCATIONS.load(Ordering::Relaxed))
+    }
+}
+
+#[cfg(not(feature = "count-allocations"))]
+mod allocations {
+    /// Allocations aren't counted without the `count-allocations` feature.
+    pub fn count() -> Option<usize> {
+        None
+    }
+}
+
+/// Exit codes of the `rate` binary, so that shell scripts can branch on the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exit {
+    /// The Sudoku has a unique solution and was solved by the human-like solver
+    Solved = 0,
+    /// The Sudoku has a unique solution, but the strategies aren't sufficient to solve it
+    UnsolvableByLogic = 1,
+    /// The input isn't a valid Sudoku board
+    InvalidInput = 2,
+    /// The Sudoku has more than one solution
+    MultipleSolutions = 3,
+    /// The Sudoku has no solution at all
+    NoSolution = 4,
+}
+
+impl From<Exit> for ExitCode {
+    fn from(exit: Exit) -> Self {
+        ExitCode::from(exit as u8)
+    }
+}
+
+impl From<&Error> for Exit {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::Parse(_) | Error::Conflict => Exit::InvalidInput,
+            Error::NoSolution => Exit::NoSolution,
+            Error::MultipleSolutions => Exit::MultipleSolutions,
+        }
+    }
+}
+
+/// Check that the puzzle has exactly one solution, with the bit-parallel check of the
+/// `fast-verify` feature for 9×9 grids.
+fn verify_unique(puzzle: &str) -> Result<(), Error> {
+    let sudoku = puzzle.parse::<Sudoku>().map_err(Error::Parse)?;
+    #[cfg(feature = "fast-verify")]
+    if sudoku.size() == 9 {
+        return rate_my_sudoku::verify_unique(puzzle);
+    }
+    if sudoku.has_conflicts() {
+        return Err(Error::Conflict);
+    }
+    match sudoku.count_solutions(2) {
+        0 => Err(Error::NoSolution),
+        1 => Ok(()),
+        _ => Err(Error::MultipleSolutions),
+    }
+}
+
+/// The subcommands that skip the uniqueness check unless `--verify` is given. Rating a
+/// single puzzle, or each puzzle given as an argument, always checks it.
+const VERIFY_SUBCOMMANDS: [&str; 5] = [
+    "--summary",
+    "coverage",
+    "batch",
+    "--fast-estimate",
+    "walkthrough",
+];
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().collect();
+    // Rating puzzles without a unique solution is pointless, so check them first
+    let verify = args.iter().any(|arg| arg == "--verify");
+    args.retain(|arg| arg != "--verify");
     if args.len() < 2 {
         println!("Please provide a serialized Sudoku board");
-        return;
+        return Exit::InvalidInput.into();
+    }
+    if verify && !VERIFY_SUBCOMMANDS.contains(&args[1].as_str()) {
+        println!("--verify only applies to {}", VERIFY_SUBCOMMANDS.join(", "));
+        return Exit::InvalidInput.into();
+    }
+    if args[1] == "serve" {
+        return serve(args.get(2).map_or("127.0.0.1:8080", String::as_str));
+    }
+    if args[1] == "tui" {
+        return match args.get(2) {
+            Some(puzzle) => tui(puzzle),
+            None => {
+                println!("Usage: rate tui <serialized Sudoku board>");
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "step" {
+        return match args.get(2) {
+            Some(puzzle) => step(puzzle),
+            None => {
+                println!("Usage: rate step <serialized Sudoku board>");
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "walkthrough" {
+        return match args.get(2) {
+            Some(puzzle) => walkthrough(puzzle, verify),
+            None => {
+                println!("Usage: rate walkthrough <serialized Sudoku board>");
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "repl" {
+        return match args.get(2) {
+            Some(puzzle) => repl(puzzle),
+            None => {
+                println!("Usage: rate repl <serialized Sudoku board>");
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "transform" {
+        return transform(&args[2..]);
+    }
+    if args[1] == "minimize" {
+        return minimize(&args[2..]);
+    }
+    if args[1] == "canonicalize" {
+        return canonicalize(&args[2..]);
+    }
+    if args[1] == "generate" {
+        return generate(&args[2..]);
+    }
+    if args[1] == "render" {
+        return render(&args[2..]);
+    }
+    if args[1] == "bench" {
+        return bench(&args[2..]);
+    }
+    if args[1] == "validate" {
+        return validate(&args[2..]);
+    }
+    if args[1] == "--oneline" {
+        return oneline(&args[2..]);
+    }
+    if args[1] == "--fast-estimate" {
+        return fast_estimate(&args[2..], verify);
+    }
+    if args[1] == "--summary" {
+        return match args.get(2) {
+            Some(corpus) => summary(corpus, verify),
+            None => {
+                println!("Usage: rate --summary <file with one puzzle per line>");
+                Exit::InvalidInput.into()
+            }
+        };
     }
-    if args[1].len() != 81 {
-        println!("Please provide a string of length 81");
-        return;
+    if args[1] == "coverage" {
+        return match args.get(2) {
+            Some(corpus) => coverage(corpus, verify),
+            None => {
+                println!("Usage: rate coverage <file with one puzzle per line>");
+                Exit::InvalidInput.into()
+            }
+        };
     }
-    let mut s0 = Sudoku::new();
-    s0.set_board_string(&args[1]);
+    if args[1] == "--log" {
+        return match args.get(2) {
+            Some(puzzle) => log(puzzle),
+            None => {
+                println!("Usage: rate --log <serialized Sudoku board>");
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "batch" {
+        return match (args.get(2), args.get(3)) {
+            (Some(corpus), Some(output)) => batch(
+                corpus,
+                output,
+                args.get(4).is_some_and(|arg| arg == "--resume"),
+                verify,
+            ),
+            _ => {
+                println!(
+                    "Usage: rate batch <file with one puzzle per line> <output file> [--resume]"
+                );
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "duplicates" {
+        return match args.get(2) {
+            Some(corpus) => duplicates(corpus),
+            None => {
+                println!("Usage: rate duplicates <file with one puzzle per line>");
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "calibrate" {
+        return match args.get(2) {
+            Some(references) => calibrate(references, args.get(3)),
+            None => {
+                println!("Usage: rate calibrate <reference file> [<weights profile>]");
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    if args[1] == "tune-order" {
+        return match args.get(2) {
+            Some(corpus) => tune_order(corpus, args.get(3)),
+            None => {
+                println!(
+                    "Usage: rate tune-order <file with one puzzle per line> [<order profile>]"
+                );
+                Exit::InvalidInput.into()
+            }
+        };
+    }
+    // An option that isn't known would otherwise be taken for a puzzle
+    if let Some(option) = args[1..].iter().find(|arg| arg.starts_with("--")) {
+        println!("Unknown option: {}", option);
+        return Exit::InvalidInput.into();
+    }
+    // Several puzzles get a line each, like with `--oneline`
+    if args.len() > 2 {
+        return oneline(&args[1..]);
+    }
+    if let Err(e) = verify_unique(&args[1]) {
+        println!("{}", e);
+        return Exit::from(&e).into();
+    }
+    let mut s0 = Sudoku::from_string(&args[1]);
     let start = std::time::Instant::now();
     s0.solve_puzzle();
     let duration = start.elapsed();
@@ -21,8 +280,7 @@ fn main() {
     );
 
     let start = std::time::Instant::now();
-    let mut s1 = Sudoku::new();
-    s1.set_board_string(&args[1]);
+    let mut s1 = Sudoku::from_string(&args[1]);
     s1.solve_by_backtracking();
     let duration = start.elapsed();
     println!(
@@ -37,4 +295,1052 @@ fn main() {
         println!("Backtracking solver:");
         s1.print();
     }
+
+    if s0.is_solved() {
+        Exit::Solved.into()
+    } else {
+        Exit::UnsolvableByLogic.into()
+    }
+}
+
+/// Solve the puzzle with the human-like solver and print each step as a JSON line.
+#[cfg(feature = "serde")]
+fn log(puzzle: &str) -> ExitCode {
+    use rate_my_sudoku::{Budget, StepLog};
+
+    let mut sudoku = match puzzle.parse::<Sudoku>() {
+        Ok(sudoku) => sudoku,
+        Err(e) => {
+            println!("Invalid Sudoku board: {}", e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    if sudoku.has_conflicts() {
+        println!("The Sudoku contains conflicting digits");
+        return Exit::InvalidInput.into();
+    }
+    let mut steps = StepLog::new(std::io::stdout().lock());
+    let outcome = sudoku.solve_human_like_observed(&Budget::default(), &mut steps);
+    if let Err(e) = steps.finish() {
+        eprintln!("Can't write the step log: {}", e);
+    }
+    if outcome.is_solved() {
+        Exit::Solved.into()
+    } else {
+        Exit::UnsolvableByLogic.into()
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn log(_puzzle: &str) -> ExitCode {
+    println!("Step log not available; rebuild with `--features serde`");
+    ExitCode::FAILURE
+}
+
+/// Print a Markdown write-up of the solve, to paste into a blog post or forum thread.
+fn walkthrough(puzzle: &str, verify: bool) -> ExitCode {
+    let sudoku = match puzzle.parse::<Sudoku>() {
+        Ok(sudoku) => sudoku,
+        Err(e) => {
+            println!("Invalid Sudoku board: {}", e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    if sudoku.has_conflicts() {
+        println!("The Sudoku contains conflicting digits");
+        return Exit::InvalidInput.into();
+    }
+    if let Some(e) = verify.then(|| verify_unique(puzzle).err()).flatten() {
+        println!("{}", e);
+        return Exit::from(&e).into();
+    }
+    print!("{}", sudoku.walkthrough_markdown());
+    if sudoku.clone().solve_human_like().is_solved() {
+        Exit::Solved.into()
+    } else {
+        Exit::UnsolvableByLogic.into()
+    }
+}
+
+/// Solve the puzzle one step at a time, showing what each step changed and waiting for
+/// Enter before the next one. `q` or the end of the input stops.
+fn step(puzzle: &str) -> ExitCode {
+    use io::{BufRead, IsTerminal};
+    use rate_my_sudoku::{Strategy, render_diff, render_diff_ansi};
+
+    let mut sudoku = match puzzle.parse::<Sudoku>() {
+        Ok(sudoku) => sudoku,
+        Err(e) => {
+            println!("Invalid Sudoku board: {}", e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    if sudoku.has_conflicts() {
+        println!("The Sudoku contains conflicting digits");
+        return Exit::InvalidInput.into();
+    }
+    let render = if io::stdout().is_terminal() {
+        render_diff_ansi
+    } else {
+        render_diff
+    };
+    print!("{}", sudoku.to_display_grid());
+    sudoku.calc_all_notes();
+    let mut input = io::stdin().lock().lines();
+    let mut steps = 0;
+    while sudoku.unsolved() && sudoku.find_contradiction().is_none() {
+        let result = sudoku.next_step();
+        if result.strategy == Strategy::None {
+            break;
+        }
+        let before = sudoku.snapshot();
+        sudoku
+            .apply(&result)
+            .expect("steps found on the board fit it");
+        steps += 1;
+        println!("\nStep {}: {}", steps, result.strategy);
+        print!("{}", render(&before, &sudoku.snapshot()));
+        if !sudoku.unsolved() {
+            break;
+        }
+        print!("Enter: next step, q: quit ");
+        let _ = io::stdout().flush();
+        match input.next() {
+            Some(Ok(line)) if line.trim() != "q" => {}
+            _ => return ExitCode::SUCCESS,
+        }
+    }
+    if sudoku.is_solved() {
+        Exit::Solved.into()
+    } else {
+        println!("No strategy applies");
+        Exit::UnsolvableByLogic.into()
+    }
+}
+
+/// Keep the puzzle loaded and read commands from stdin, one per line, to take steps,
+/// undo them, place digits and look at the notes and the rating. The end of the input
+/// or `quit` stops.
+fn repl(puzzle: &str) -> ExitCode {
+    use io::{BufRead, IsTerminal};
+    use rate_my_sudoku::{Cell, DifficultyLevel, Locale, Strategy, render_diff, render_diff_ansi};
+
+    const HELP: &str = "Commands:
+  step          take the next step of the solver
+  back          undo the last step or placement
+  hint          show the next step without taking it
+  place r3c5=7  place a digit
+  notes         show the digits and notes
+  board         show the grid
+  rate          rate the position from its notes
+  quit          stop";
+    let mut sudoku = match puzzle.parse::<Sudoku>() {
+        Ok(sudoku) => sudoku,
+        Err(e) => {
+            println!("Invalid Sudoku board: {}", e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    if sudoku.has_conflicts() {
+        println!("The Sudoku contains conflicting digits");
+        return Exit::InvalidInput.into();
+    }
+    let render = if io::stdout().is_terminal() {
+        render_diff_ansi
+    } else {
+        render_diff
+    };
+    sudoku.calc_all_notes();
+    print!("{}", sudoku.to_display_grid());
+    println!("Type `help` for the commands");
+    let mut input = io::stdin().lock().lines();
+    loop {
+        print!("> ");
+        let _ = io::stdout().flush();
+        let Some(Ok(line)) = input.next() else {
+            println!();
+            break;
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => {}
+            ["help"] => println!("{}", HELP),
+            ["quit"] | ["q"] => break,
+            ["step"] => {
+                let result = sudoku.next_step();
+                if result.strategy == Strategy::None {
+                    println!("No strategy applies");
+                    continue;
+                }
+                let before = sudoku.snapshot();
+                sudoku
+                    .apply(&result)
+                    .expect("steps found on the board fit it");
+                println!("{}", result.explain(Locale::English));
+                print!("{}", render(&before, &sudoku.snapshot()));
+            }
+            ["back"] => {
+                sudoku.undo();
+                print!("{}", sudoku.to_display_grid());
+            }
+            ["hint"] => {
+                let result = sudoku.clone().next_step();
+                if result.strategy == Strategy::None {
+                    println!("No strategy applies");
+                } else {
+                    println!("{}", result.explain(Locale::English));
+                }
+            }
+            ["place", placement] => match placement.parse::<Cell>() {
+                Ok(cell) => match sudoku.set_cell(cell.row, cell.col, cell.num) {
+                    Ok(()) => print!("{}", sudoku.to_display_grid()),
+                    Err(e) => println!("Can't place {}: {}", cell, e),
+                },
+                Err(e) => println!("{}", e),
+            },
+            ["notes"] => print!("{}", sudoku.pencilmarks()),
+            ["board"] => print!("{}", sudoku.to_display_grid()),
+            ["rate"] => {
+                let mut rest = sudoku.clone();
+                let solved = rest.solve_from_notes().is_solved();
+                let difficulty = rest.difficulty();
+                println!(
+                    "Difficulty from here: {:.2} ({}), hardest: {}, solved: {}",
+                    difficulty,
+                    DifficultyLevel::of(difficulty),
+                    rest.hardest_technique()
+                        .map_or("none".to_string(), |strategy| strategy.to_string()),
+                    if solved { "yes" } else { "no" }
+                );
+            }
+            _ => println!(
+                "Unknown command '{}', type `help` for the commands",
+                line.trim()
+            ),
+        }
+    }
+    if sudoku.is_solved() {
+        Exit::Solved.into()
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Print the puzzles given as arguments, or else read from stdin one per line,
+/// transformed into isomorphic ones as requested by the options.
+fn transform(args: &[String]) -> ExitCode {
+    use rate_my_sudoku::Transform;
+
+    const USAGE: &str = "Usage: rate transform [--rotate 90|180|270] [--transpose] \
+                         [--relabel-seed <number>] [<serialized Sudoku board>...]";
+    let mut transform = Transform::new();
+    let mut puzzles = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--transpose" => transform = transform.with_transpose(),
+            "--rotate" => {
+                match args
+                    .next()
+                    .and_then(|degrees| degrees.parse::<u32>().ok())
+                    .filter(|degrees| degrees.is_multiple_of(90))
+                {
+                    Some(degrees) => transform = transform.with_rotation(degrees / 90),
+                    None => {
+                        println!("{}", USAGE);
+                        return Exit::InvalidInput.into();
+                    }
+                }
+            }
+            "--relabel-seed" => match args.next().and_then(|seed| seed.parse::<u64>().ok()) {
+                Some(seed) => transform = transform.with_relabel_seed(seed),
+                None => {
+                    println!("{}", USAGE);
+                    return Exit::InvalidInput.into();
+                }
+            },
+            _ => puzzles.push(arg.clone()),
+        }
+    }
+    let mut exit = ExitCode::SUCCESS;
+    for puzzle in puzzles_or_stdin(puzzles) {
+        let transformed = puzzle
+            .parse::<Sudoku>()
+            .map_err(|e| e.to_string())
+            .and_then(|sudoku| sudoku.transformed(&transform).map_err(|e| e.to_string()));
+        match transformed {
+            Ok(sudoku) => println!("{}", sudoku.serialized()),
+            Err(e) => {
+                eprintln!("Invalid Sudoku board {}: {}", puzzle, e);
+                exit = Exit::InvalidInput.into();
+            }
+        }
+    }
+    exit
+}
+
+/// The puzzles given as arguments, or else the non-empty lines of stdin.
+fn puzzles_or_stdin(puzzles: Vec<String>) -> Vec<String> {
+    use io::BufRead;
+
+    if !puzzles.is_empty() {
+        return puzzles;
+    }
+    io::stdin()
+        .lock()
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Print the canonical form of each puzzle given as an argument, or else read from
+/// stdin one per line.
+fn canonicalize(args: &[String]) -> ExitCode {
+    let mut exit = ExitCode::SUCCESS;
+    for puzzle in puzzles_or_stdin(args.to_vec()) {
+        let canonical = puzzle
+            .parse::<Sudoku>()
+            .map_err(|e| e.to_string())
+            .and_then(|sudoku| sudoku.canonical_form().map_err(|e| e.to_string()));
+        match canonical {
+            Ok(canonical) => println!("{}", canonical),
+            Err(e) => {
+                eprintln!("Invalid Sudoku board {}: {}", puzzle, e);
+                exit = Exit::InvalidInput.into();
+            }
+        }
+    }
+    exit
+}
+
+/// Draw the puzzle in the requested format, into the output file or else to stdout.
+/// The format defaults to the extension of the output file, or to ANSI text.
+fn render(args: &[String]) -> ExitCode {
+    use rate_my_sudoku::{RenderFormat, RenderOptions};
+
+    const USAGE: &str = "Usage: rate render <serialized Sudoku board> \
+                         [--format svg|png|ansi|html] [--with-candidates] \
+                         [--highlight-next-step] [<output file>]";
+    let mut format: Option<RenderFormat> = None;
+    let mut options = RenderOptions::new();
+    let mut highlight = false;
+    let mut paths = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().map(|format| format.parse()) {
+                Some(Ok(parsed)) => format = Some(parsed),
+                _ => {
+                    println!("{}", USAGE);
+                    return Exit::InvalidInput.into();
+                }
+            },
+            "--with-candidates" => options = options.with_candidates(),
+            "--highlight-next-step" => highlight = true,
+            _ => paths.push(arg),
+        }
+    }
+    let (puzzle, output) = match paths.as_slice() {
+        [puzzle] => (puzzle, None),
+        [puzzle, output] => (puzzle, Some(output)),
+        _ => {
+            println!("{}", USAGE);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let format = format
+        .or_else(|| {
+            let extension = std::path::Path::new(output?).extension()?;
+            extension.to_str()?.parse().ok()
+        })
+        .unwrap_or(RenderFormat::Ansi);
+    let mut sudoku = match puzzle.parse::<Sudoku>() {
+        Ok(sudoku) => sudoku,
+        Err(e) => {
+            println!("Invalid Sudoku board: {}", e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    sudoku.calc_all_notes();
+    if highlight {
+        options = options.with_highlight(sudoku.next_step());
+    }
+    let picture = match format {
+        RenderFormat::Svg => sudoku.to_svg(&options).into_bytes(),
+        RenderFormat::Html => sudoku.to_html(&options).into_bytes(),
+        RenderFormat::Ansi => sudoku.to_ansi(&options).into_bytes(),
+        #[cfg(feature = "png")]
+        RenderFormat::Png => sudoku.to_png(&options),
+        #[cfg(not(feature = "png"))]
+        RenderFormat::Png => {
+            println!("PNG output not available; rebuild with `--features png`");
+            return ExitCode::FAILURE;
+        }
+    };
+    let written = match output {
+        Some(path) => std::fs::write(path, picture),
+        None => io::stdout().write_all(&picture),
+    };
+    match written {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Can't write the picture: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The value of the `error` field of a puzzle that can't be rated on a single line.
+fn error_field(e: &Error) -> &'static str {
+    match e {
+        Error::Parse(_) => "invalid",
+        Error::Conflict => "conflicts",
+        Error::NoSolution => "no-solution",
+        Error::MultipleSolutions => "multiple-solutions",
+    }
+}
+
+/// Rate each puzzle given as an argument, or else read from stdin one per line, and
+/// print the rating on a single line, see [`RatedPuzzle::oneline`]. Puzzles that can't be
+/// rated get an `error` field instead. The exit code tells the first problem found.
+fn oneline(args: &[String]) -> ExitCode {
+    let mut exit = Exit::Solved;
+    for puzzle in puzzles_or_stdin(args.to_vec()) {
+        let problem = match verify_unique(&puzzle).and_then(|()| RatedPuzzle::rate(&puzzle)) {
+            Ok(rated) => {
+                println!("{}", rated.oneline());
+                (!rated.solved).then_some(Exit::UnsolvableByLogic)
+            }
+            Err(e) => {
+                println!("{} error={}", puzzle, error_field(&e));
+                Some(Exit::from(&e))
+            }
+        };
+        if let (Exit::Solved, Some(problem)) = (exit, problem) {
+            exit = problem;
+        }
+    }
+    exit.into()
+}
+
+/// Predict the difficulty of each puzzle given as an argument, or else read from stdin
+/// one per line, without solving it, and print it on a single line, see
+/// [`Sudoku::estimate`]. With `verify`, puzzles without a unique solution get an `error`
+/// field instead. The exit code tells the first problem found.
+fn fast_estimate(args: &[String], verify: bool) -> ExitCode {
+    let mut exit = Exit::Solved;
+    for puzzle in puzzles_or_stdin(args.to_vec()) {
+        let checked = if verify {
+            verify_unique(&puzzle)
+        } else {
+            Ok(())
+        };
+        match checked.and_then(|()| puzzle.parse::<Sudoku>().map_err(Error::Parse)) {
+            Ok(sudoku) => {
+                let estimate = sudoku.estimate();
+                println!(
+                    "{} level={} estimate={:.2} clues={}",
+                    puzzle,
+                    estimate.level(),
+                    estimate.difficulty,
+                    estimate.givens
+                );
+            }
+            Err(e) => {
+                println!("{} error={}", puzzle, error_field(&e));
+                if exit == Exit::Solved {
+                    exit = Exit::from(&e);
+                }
+            }
+        }
+    }
+    exit.into()
+}
+
+/// Check each puzzle given as an argument, or else read from stdin one per line, and
+/// print the findings below it. The exit code tells the first problem found.
+fn validate(args: &[String]) -> ExitCode {
+    let mut exit = Exit::Solved;
+    for puzzle in puzzles_or_stdin(args.to_vec()) {
+        println!("{}", puzzle);
+        let problem = match rate_my_sudoku::validate(&puzzle) {
+            Ok(validation) => {
+                for line in validation.to_string().lines() {
+                    println!("  {}", line);
+                }
+                match validation.solutions {
+                    _ if !validation.duplicates.is_empty() => Some(Exit::InvalidInput),
+                    Some(0) => Some(Exit::NoSolution),
+                    Some(1) => None,
+                    _ => Some(Exit::MultipleSolutions),
+                }
+            }
+            Err(e) => {
+                println!("  {}", e);
+                Some(Exit::InvalidInput)
+            }
+        };
+        if let (Exit::Solved, Some(problem)) = (exit, problem) {
+            exit = problem;
+        }
+    }
+    exit.into()
+}
+
+/// Remove the givens the puzzle doesn't need and print the result with its rating.
+fn minimize(args: &[String]) -> ExitCode {
+    use rate_my_sudoku::Symmetry;
+
+    let (symmetry, puzzle) = match args {
+        [puzzle] => (Symmetry::None, puzzle),
+        [option, symmetry, puzzle] if option == "--symmetry" => match symmetry.parse() {
+            Ok(symmetry) => (symmetry, puzzle),
+            Err(e) => {
+                println!("{}", e);
+                return Exit::InvalidInput.into();
+            }
+        },
+        _ => {
+            println!("Usage: rate minimize [--symmetry <symmetry>] <serialized Sudoku board>");
+            return Exit::InvalidInput.into();
+        }
+    };
+    let before = match rate_my_sudoku::rate(puzzle) {
+        Ok(rating) => rating,
+        Err(e) => {
+            println!("{}", e);
+            return Exit::from(&e).into();
+        }
+    };
+    let sudoku = Sudoku::from_string(puzzle);
+    let minimized = match sudoku.minimized(symmetry) {
+        Ok(minimized) => minimized,
+        Err(e) => {
+            println!("{}", e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let after = rate_my_sudoku::rate(&minimized.serialized())
+        .expect("minimizing keeps the solution unique");
+    println!("{}", after.puzzle);
+    println!(
+        "Clues: {} (before: {})",
+        minimized.givens_count(),
+        sudoku.givens_count()
+    );
+    let describe = |rating: &rate_my_sudoku::Rating| {
+        if rating.solved {
+            format!("{:.2} ({})", rating.difficulty, rating.level)
+        } else {
+            "not solvable with the strategies".to_string()
+        }
+    };
+    println!(
+        "Difficulty: {} (before: {})",
+        describe(&after),
+        describe(&before)
+    );
+    if after.solved {
+        Exit::Solved.into()
+    } else {
+        Exit::UnsolvableByLogic.into()
+    }
+}
+
+/// Time, and count the allocations of, running `solve` on each puzzle. Returns the
+/// time and the number of allocations, if counted.
+fn measure(puzzles: &[Sudoku], mut solve: impl FnMut(Sudoku)) -> (Duration, Option<usize>) {
+    let before = allocations::count();
+    let start = Instant::now();
+    for sudoku in puzzles {
+        solve(sudoku.clone());
+    }
+    let time = start.elapsed();
+    let allocations = allocations::count()
+        .zip(before)
+        .map(|(after, before)| after - before);
+    (time, allocations)
+}
+
+/// Solve every puzzle of the file with each solver and print their throughput and
+/// allocations, then how the time of the human-like solver splits up by strategy. The
+/// human-like solver tries the strategies in the order of the order profile, if given.
+fn bench(args: &[String]) -> ExitCode {
+    use rate_my_sudoku::{Profile, SearchOrder};
+
+    const USAGE: &str =
+        "Usage: rate bench --file <file with one puzzle per line> [--order <order profile>]";
+    let (file, order) = match args {
+        [option, file] if option == "--file" => (file, SearchOrder::default()),
+        [option, file, order_option, profile]
+            if option == "--file" && order_option == "--order" =>
+        {
+            let order = std::fs::read_to_string(profile)
+                .map_err(|e| format!("Can't read {}: {}", profile, e))
+                .and_then(|text| text.parse::<SearchOrder>().map_err(|e| e.to_string()));
+            match order {
+                Ok(order) => (file, order),
+                Err(e) => {
+                    println!("{}", e);
+                    return Exit::InvalidInput.into();
+                }
+            }
+        }
+        _ => {
+            println!("{}", USAGE);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let corpus = match Corpus::open(file) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            println!("Can't read {}: {}", file, e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let puzzles: Vec<Sudoku> = corpus
+        .records()
+        .filter_map(|record| record.puzzle.parse::<Sudoku>().ok())
+        .map(|sudoku| sudoku.with_search_order(order))
+        .collect();
+    if puzzles.is_empty() {
+        println!("No puzzles in {}", file);
+        return Exit::InvalidInput.into();
+    }
+    let mut profile = Profile::new();
+    #[cfg_attr(not(feature = "fast-verify"), allow(unused_mut))]
+    let mut solvers = vec![
+        (
+            "human-like",
+            measure(&puzzles, |mut sudoku| {
+                sudoku.solve_profiled(&mut profile);
+            }),
+        ),
+        (
+            "backtracking",
+            measure(&puzzles, |mut sudoku| {
+                sudoku.solve_by_backtracking();
+            }),
+        ),
+    ];
+    #[cfg(feature = "fast-verify")]
+    {
+        let puzzles: Vec<Sudoku> = puzzles.iter().filter(|s| s.size() == 9).cloned().collect();
+        if !puzzles.is_empty() {
+            solvers.push((
+                "fast-verify",
+                measure(&puzzles, |sudoku| {
+                    let _ = rate_my_sudoku::verify_unique(&sudoku.original_board());
+                }),
+            ));
+        }
+    }
+    println!(
+        "Puzzles: {}, solved by the human-like solver: {}\n",
+        profile.puzzles, profile.solved
+    );
+    println!(
+        "{:<14} {:>10} {:>12} {:>14}",
+        "Solver", "Puzzles/s", "ms/puzzle", "Allocs/puzzle"
+    );
+    for (solver, (time, allocations)) in solvers {
+        let count = puzzles.len() as f64;
+        let allocations = allocations.map_or("-".to_string(), |allocations| {
+            format!("{:.1}", allocations as f64 / count)
+        });
+        println!(
+            "{:<14} {:>10.1} {:>12.3} {:>14}",
+            solver,
+            count / time.as_secs_f64(),
+            1e3 * time.as_secs_f64() / count,
+            allocations
+        );
+    }
+    println!();
+    print!("{}", profile);
+    ExitCode::SUCCESS
+}
+
+/// Rate every puzzle in the file and print statistics over all of them. With `verify`,
+/// puzzles without a unique solution are skipped.
+fn summary(corpus: &str, verify: bool) -> ExitCode {
+    let corpus = match Corpus::open(corpus) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            println!("Can't read {}: {}", corpus, e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let mut rated = Vec::new();
+    for record in corpus.records() {
+        if let Some(e) = verify.then(|| verify_unique(record.puzzle).err()).flatten() {
+            println!("Skipping line {}: {}", record.line, e);
+            continue;
+        }
+        match RatedPuzzle::rate(record.puzzle) {
+            Ok(puzzle) => rated.push(puzzle),
+            Err(e) => println!("Skipping line {}: {}", record.line, e),
+        }
+    }
+    print!("{}", CorpusStats::new(&rated));
+    ExitCode::SUCCESS
+}
+
+/// Rate every puzzle in the file and print which strategies the puzzles need, and
+/// which puzzles the strategies don't solve. With `verify`, puzzles without a unique
+/// solution are skipped.
+fn coverage(corpus: &str, verify: bool) -> ExitCode {
+    use rate_my_sudoku::Coverage;
+
+    let corpus = match Corpus::open(corpus) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            println!("Can't read {}: {}", corpus, e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let mut rated = Vec::new();
+    for record in corpus.records() {
+        if let Some(e) = verify.then(|| verify_unique(record.puzzle).err()).flatten() {
+            println!("Skipping line {}: {}", record.line, e);
+            continue;
+        }
+        match RatedPuzzle::rate(record.puzzle) {
+            Ok(puzzle) => rated.push(puzzle),
+            Err(e) => println!("Skipping line {}: {}", record.line, e),
+        }
+    }
+    print!("{}", Coverage::new(&rated));
+    ExitCode::SUCCESS
+}
+
+/// Number of puzzles rated between two checkpoints of a batch run.
+const CHECKPOINT_INTERVAL: usize = 100;
+
+/// Rate every puzzle in the file and write a line with its difficulty and the puzzle to
+/// `output`, or `FAILED` instead of the difficulty if the strategies don't solve it.
+/// The other columns of CSV input follow the puzzle unchanged.
+/// The progress is saved to `<output>.checkpoint` regularly, so that an interrupted
+/// run can be continued with `resume`. With `verify`, puzzles without a unique solution
+/// are skipped.
+fn batch(corpus: &str, output: &str, resume: bool, verify: bool) -> ExitCode {
+    let corpus = match Corpus::open(corpus) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            println!("Can't read {}: {}", corpus, e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let checkpoint_path = format!("{}.checkpoint", output);
+    let start = if resume {
+        match Checkpoint::load(&checkpoint_path) {
+            Ok(checkpoint) => checkpoint.unwrap_or_default(),
+            Err(e) => {
+                println!("Can't read {}: {}", checkpoint_path, e);
+                return Exit::InvalidInput.into();
+            }
+        }
+    } else {
+        Checkpoint::default()
+    };
+    let records = corpus
+        .records()
+        .skip_while(|record| record.line <= start.line);
+    match rate_batch(records, output, &checkpoint_path, start, verify) {
+        Ok(rated) => {
+            println!("Rated {} puzzles", rated);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            println!("Can't write {}: {}", output, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Rate the records, appending to the output as it was at the checkpoint. Returns the
+/// number of puzzles rated.
+fn rate_batch<'a>(
+    records: impl Iterator<Item = Record<'a>>,
+    output: &str,
+    checkpoint_path: &str,
+    start: Checkpoint,
+    verify: bool,
+) -> io::Result<usize> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(output)?;
+    // Drop what was written after the checkpoint
+    file.set_len(start.output_len)?;
+    file.seek(io::SeekFrom::End(0))?;
+    let mut writer = io::BufWriter::new(file);
+    let mut checkpoint = start;
+    let mut rated = 0;
+    for record in records {
+        checkpoint.line = record.line;
+        // The other columns, e.g. an ID, so that the output can be joined with the input
+        let fields = if record.fields.is_empty() {
+            String::new()
+        } else {
+            format!(",{}", record.fields)
+        };
+        if let Some(e) = verify.then(|| verify_unique(record.puzzle).err()).flatten() {
+            eprintln!("Skipping line {}: {}", record.line, e);
+            continue;
+        }
+        let line = match RatedPuzzle::rate(record.puzzle) {
+            Ok(puzzle) if puzzle.solved => {
+                format!("{:6.2} {}{}\n", puzzle.difficulty, puzzle.puzzle, fields)
+            }
+            Ok(puzzle) => format!("FAILED {}{}\n", puzzle.puzzle, fields),
+            Err(e) => {
+                eprintln!("Skipping line {}: {}", record.line, e);
+                continue;
+            }
+        };
+        writer.write_all(line.as_bytes())?;
+        checkpoint.output_len += line.len() as u64;
+        rated += 1;
+        if rated % CHECKPOINT_INTERVAL == 0 {
+            writer.flush()?;
+            checkpoint.save(checkpoint_path)?;
+        }
+    }
+    writer.flush()?;
+    // The run is complete, so there's nothing to resume
+    match std::fs::remove_file(checkpoint_path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(rated),
+    }
+}
+
+/// Print the line numbers of isomorphic puzzles in the file, one group per line.
+fn duplicates(corpus: &str) -> ExitCode {
+    let corpus = match Corpus::open(corpus) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            println!("Can't read {}: {}", corpus, e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let (line_numbers, puzzles): (Vec<usize>, Vec<&str>) = corpus
+        .records()
+        .map(|record| (record.line, record.puzzle))
+        .unzip();
+    match rate_my_sudoku::find_duplicates(&puzzles) {
+        Ok(groups) => {
+            for group in groups {
+                let lines: Vec<String> =
+                    group.iter().map(|&i| line_numbers[i].to_string()).collect();
+                println!("{}", lines.join(" "));
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            println!("Invalid puzzle: {}", e);
+            Exit::InvalidInput.into()
+        }
+    }
+}
+
+/// Fit the strategy efforts to the ratings in the reference file, and write the
+/// weights profile to `profile`, or print it.
+fn calibrate(references: &str, profile: Option<&String>) -> ExitCode {
+    let text = match std::fs::read_to_string(references) {
+        Ok(text) => text,
+        Err(e) => {
+            println!("Can't read {}: {}", references, e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let calibration = rate_my_sudoku::parse_references(&text)
+        .and_then(|references| rate_my_sudoku::calibrate(&references));
+    let calibration = match calibration {
+        Ok(calibration) => calibration,
+        Err(e) => {
+            println!("Calibration failed: {}", e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    println!("Puzzles used: {}", calibration.puzzles);
+    println!(
+        "Correlation with reference: {:.3} before, {:.3} after",
+        calibration.correlation_before, calibration.correlation_after
+    );
+    match profile {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, calibration.weights.to_string()) {
+                println!("Can't write {}: {}", path, e);
+                return ExitCode::FAILURE;
+            }
+            println!("Weights profile written to {}", path);
+        }
+        None => print!("{}", calibration.weights),
+    }
+    ExitCode::SUCCESS
+}
+
+/// Measure the detectors on every puzzle of the file and propose the order that finds
+/// steps fastest, and write the order profile to `profile`, or print it.
+fn tune_order(corpus: &str, profile: Option<&String>) -> ExitCode {
+    let corpus = match Corpus::open(corpus) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            println!("Can't read {}: {}", corpus, e);
+            return Exit::InvalidInput.into();
+        }
+    };
+    let puzzles: Vec<Sudoku> = corpus
+        .records()
+        .filter_map(|record| record.puzzle.parse::<Sudoku>().ok())
+        .collect();
+    let tuning = rate_my_sudoku::tune_order(&puzzles);
+    println!(
+        "Puzzles: {}, positions: {}\n",
+        tuning.puzzles, tuning.positions
+    );
+    print!("{}", tuning);
+    match profile {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, tuning.order.to_string()) {
+                println!("Can't write {}: {}", path, e);
+                return ExitCode::FAILURE;
+            }
+            println!("Order profile written to {}", path);
+        }
+        None => print!("\n{}", tuning.order),
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(feature = "serve")]
+fn serve(addr: &str) -> ExitCode {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
+        .format_timestamp(None)
+        .format_target(false)
+        .init();
+    match rate_my_sudoku::serve::run(addr) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            println!("Server error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(not(feature = "serve"))]
+fn serve(_addr: &str) -> ExitCode {
+    println!("Server mode not available; rebuild with `--features serve`");
+    ExitCode::FAILURE
+}
+
+/// Generate puzzles as requested by the options and print them, rated.
+#[cfg(feature = "generate")]
+fn generate(args: &[String]) -> ExitCode {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rate_my_sudoku::{Board, DifficultyRange, DigOptions, GridSpec, RatedPuzzle};
+
+    /// Number of failed attempts in a row after which no fitting puzzle is expected
+    const MAX_ATTEMPTS: usize = 1000;
+    const USAGE: &str = "Usage: rate generate [--count <number>] [--difficulty <min>..<max>] \
+                         [--symmetry <symmetry>] [--min-clues <number>] [--seed <number>] \
+                         [--format sdm|csv]";
+
+    let mut count = 1;
+    let mut range: Option<DifficultyRange> = None;
+    let mut options = DigOptions::new();
+    let mut seed: Option<u64> = None;
+    let mut csv = false;
+    let mut args = args.iter();
+    while let Some(option) = args.next() {
+        let Some(value) = args.next() else {
+            println!("{}", USAGE);
+            return Exit::InvalidInput.into();
+        };
+        let valid = match option.as_str() {
+            "--count" => value.parse().map(|value| count = value).is_ok(),
+            "--difficulty" => value.parse().map(|value| range = Some(value)).is_ok(),
+            "--symmetry" => value
+                .parse()
+                .map(|symmetry| options = options.clone().with_symmetry(symmetry))
+                .is_ok(),
+            "--min-clues" => value
+                .parse()
+                .map(|min_clues| options = options.clone().with_min_givens(min_clues))
+                .is_ok(),
+            "--seed" => value.parse().map(|value| seed = Some(value)).is_ok(),
+            "--format" => {
+                csv = value == "csv";
+                csv || value == "sdm"
+            }
+            _ => false,
+        };
+        if !valid {
+            println!("Invalid option {} {}", option, value);
+            println!("{}", USAGE);
+            return Exit::InvalidInput.into();
+        }
+    }
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    let mut generated = 0;
+    let mut attempts = 0;
+    while generated < count {
+        if attempts == MAX_ATTEMPTS {
+            eprintln!("No fitting puzzle found in {} attempts", MAX_ATTEMPTS);
+            return ExitCode::FAILURE;
+        }
+        attempts += 1;
+        let rated = match &range {
+            Some(range) => Sudoku::generate_in_range(GridSpec::CLASSIC, &options, range, &mut rng),
+            None => Sudoku::new()
+                .random_solution(&mut rng)
+                .and_then(|solution| {
+                    let solution = Board::from_givens(GridSpec::CLASSIC, solution);
+                    let puzzle = Sudoku::dig(&solution, &options, &mut rng).ok()?;
+                    RatedPuzzle::rate(&puzzle.serialized()).ok()
+                }),
+        };
+        let Some(rated) = rated else {
+            continue;
+        };
+        if csv {
+            println!("{},{:.2},{}", rated.puzzle, rated.difficulty, rated.givens);
+        } else {
+            println!("{}", rated.puzzle);
+        }
+        generated += 1;
+        attempts = 0;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(not(feature = "generate"))]
+fn generate(_args: &[String]) -> ExitCode {
+    println!("Generator not available; rebuild with `--features generate`");
+    ExitCode::FAILURE
+}
+
+#[cfg(feature = "tui")]
+fn tui(puzzle: &str) -> ExitCode {
+    match rate_my_sudoku::tui::run(puzzle) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            println!("Error: {}", e);
+            Exit::InvalidInput.into()
+        }
+    }
+}
+
+#[cfg(not(feature = "tui"))]
+fn tui(_puzzle: &str) -> ExitCode {
+    println!("Trainer not available; rebuild with `--features tui`");
+    ExitCode::FAILURE
 }
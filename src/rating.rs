@@ -0,0 +1,237 @@
+//! Rating the difficulty of a Sudoku by the strategies the solver needs.
+
+use crate::{Strategy, StrategyResult, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The hardest strategy with a count in the rating, if any.
+pub(crate) fn hardest_technique(rating: &BTreeMap<Strategy, usize>) -> Option<Strategy> {
+    rating
+        .iter()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(strategy, _)| strategy)
+        .max_by_key(|strategy| strategy.difficulty())
+        .cloned()
+}
+
+impl Strategy {
+    pub(crate) fn difficulty(&self) -> i32 {
+        match self {
+            Strategy::None => 0,
+            Strategy::LastDigit => 4,
+            Strategy::ObviousSingle => 5,
+            Strategy::HiddenSingle => 14,
+            Strategy::PointingPair => 50,
+            Strategy::ClaimingPair => 50,
+            Strategy::ObviousPair => 60,
+            Strategy::HiddenPair => 70,
+            Strategy::ObviousTriple => 80,
+            Strategy::HiddenTriple => 100,
+            Strategy::ObviousQuad => 120,
+            Strategy::HiddenQuad => 150,
+            Strategy::XWing => 140,
+            Strategy::CageCombination => 30,
+            Strategy::InniesOuties => 40,
+            Strategy::LawOfLeftovers => 60,
+            Strategy::XV => 20,
+            Strategy::Kropki => 25,
+            Strategy::Thermometer => 30,
+            Strategy::Arrow => 35,
+        }
+    }
+}
+
+/// Identifies the rating algorithm, i.e. the strategies, the order they're tried in, and
+/// their efforts. Ratings stored with another identifier may differ from the ones
+/// computed now. Bump the number whenever one of these changes.
+pub const RATING_ALGORITHM: &str = "rms-3";
+
+/// Coarse difficulty classes derived from the numerical difficulty of a Sudoku.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum DifficultyLevel {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+impl DifficultyLevel {
+    /// Classify a difficulty as returned by [`Sudoku::difficulty`].
+    pub fn of(difficulty: f64) -> DifficultyLevel {
+        if difficulty < 5.0 {
+            DifficultyLevel::Easy
+        } else if difficulty < 7.0 {
+            DifficultyLevel::Medium
+        } else if difficulty < 10.0 {
+            DifficultyLevel::Hard
+        } else {
+            DifficultyLevel::Expert
+        }
+    }
+
+    /// Number of givens to start generating Sudokus of this level with.
+    pub fn filled_cells(&self) -> usize {
+        match self {
+            DifficultyLevel::Easy => 34,
+            DifficultyLevel::Medium => 30,
+            DifficultyLevel::Hard => 28,
+            DifficultyLevel::Expert => 26,
+        }
+    }
+}
+
+/// How the steps of a solve add up to the score of a puzzle.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum RatingMode {
+    /// The average effort per removed candidate, see [`Sudoku::difficulty`]
+    #[default]
+    Average,
+    /// The effort of the hardest strategy needed, like Sudoku Explainer rates puzzles,
+    /// see [`Sudoku::hardest_difficulty`]
+    Hardest,
+}
+
+impl std::str::FromStr for RatingMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "average" => Ok(RatingMode::Average),
+            "hardest" => Ok(RatingMode::Hardest),
+            _ => Err(format!("Unknown rating mode: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for RatingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RatingMode::Average => write!(f, "average"),
+            RatingMode::Hardest => write!(f, "hardest"),
+        }
+    }
+}
+
+impl std::str::FromStr for DifficultyLevel {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "easy" => Ok(DifficultyLevel::Easy),
+            "medium" => Ok(DifficultyLevel::Medium),
+            "hard" => Ok(DifficultyLevel::Hard),
+            "expert" => Ok(DifficultyLevel::Expert),
+            _ => Err(format!("Unknown difficulty level: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for DifficultyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DifficultyLevel::Easy => write!(f, "easy"),
+            DifficultyLevel::Medium => write!(f, "medium"),
+            DifficultyLevel::Hard => write!(f, "hard"),
+            DifficultyLevel::Expert => write!(f, "expert"),
+        }
+    }
+}
+
+impl StrategyResult {
+    /// What the step adds to the rating of its strategy: the candidates it removes, and
+    /// one more if it places a digit.
+    pub(crate) fn rating_count(&self) -> usize {
+        self.removals.candidates_about_to_be_removed.len()
+            + usize::from(self.removals.sets_cell.is_some())
+    }
+}
+
+impl Sudoku {
+    pub(crate) fn add_to_rating(&mut self, strategy: &Strategy, count: usize) {
+        self.rating
+            .entry(strategy.clone())
+            .and_modify(|total| *total += count)
+            .or_insert(count);
+    }
+
+    /// Count the candidates the step removes towards the rating, as the solver does for
+    /// the step it takes. A digit it places is counted when the step is applied.
+    pub(crate) fn rate_step(&mut self, step: &StrategyResult) {
+        self.add_to_rating(
+            &step.strategy,
+            step.removals.candidates_about_to_be_removed.len(),
+        );
+    }
+
+    pub fn effort(&self) -> f64 {
+        let candidates_removed = self.rating.iter().map(|(_, &count)| count).sum::<usize>();
+        let total_rating: i32 = self
+            .rating
+            .iter()
+            .map(|(strategy, &count)| strategy.difficulty() * count as i32)
+            .sum();
+        (total_rating as f64) / (candidates_removed as f64)
+    }
+
+    #[allow(dead_code)]
+    pub fn rating(&self) -> BTreeMap<Strategy, usize> {
+        self.rating.clone()
+    }
+
+    pub fn difficulty(&self) -> f64 {
+        let candidates_removed = self.rating.iter().map(|(_, &count)| count).sum::<usize>();
+        let total_rating: i32 = self
+            .rating
+            .iter()
+            .map(|(strategy, &count)| strategy.difficulty() * count as i32)
+            .sum();
+        (total_rating as f64) / (candidates_removed as f64)
+    }
+
+    /// The hardest strategy the solver used, if any.
+    pub fn hardest_technique(&self) -> Option<Strategy> {
+        hardest_technique(&self.rating)
+    }
+
+    /// The effort of the hardest strategy the solver used, 0 if it didn't take a step.
+    pub fn hardest_difficulty(&self) -> f64 {
+        self.hardest_technique()
+            .map_or(0.0, |strategy| strategy.difficulty() as f64)
+    }
+
+    /// The score of the puzzle in the given mode.
+    pub fn difficulty_in(&self, mode: RatingMode) -> f64 {
+        match mode {
+            RatingMode::Average => self.difficulty(),
+            RatingMode::Hardest => self.hardest_difficulty(),
+        }
+    }
+
+    #[cfg(feature = "dump")]
+    pub fn dump_rating(&self) {
+        println!("Rating ({}):", RATING_ALGORITHM);
+        let candidates_removed = self.rating.iter().map(|(_, &count)| count).sum::<usize>();
+        let total_rating: i32 = self
+            .rating
+            .iter()
+            .map(|(strategy, &count)| strategy.difficulty() * count as i32)
+            .sum();
+        let difficulty = (total_rating as f64) / (candidates_removed as f64);
+        println!("  Difficulty: {:.2}", difficulty);
+        if let Some(hardest) = self.hardest_technique() {
+            println!(
+                "  Hardest technique: {} ({})",
+                hardest,
+                hardest.difficulty()
+            );
+        }
+        println!("  Total candidates removed: {}; by …", candidates_removed);
+        let mut strategies: Vec<(&Strategy, &usize)> = self.rating.iter().collect();
+        strategies.sort_by_key(|(strategy, _)| strategy.difficulty());
+        for (strategy, count) in strategies {
+            println!("  - {} ({}): {}", strategy, strategy.difficulty(), count);
+        }
+    }
+}
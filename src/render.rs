@@ -0,0 +1,503 @@
+//! Pictures of the board for sharing and printing: SVG, PNG and HTML, and colored text
+//! for terminals. The notes and the pattern of a step can be shown as well.
+
+use crate::io::{frame_line, frame_line_below};
+use crate::{Candidate, EMPTY, GridSpec, StrategyResult, Sudoku};
+
+/// Size of a cell in pixels.
+const CELL: f32 = 48.0;
+/// Space around the grid in pixels.
+const MARGIN: f32 = 8.0;
+
+type Rgb = (u8, u8, u8);
+
+const BACKGROUND: Rgb = (255, 255, 255);
+const THIN_LINE: Rgb = (180, 180, 180);
+const THICK_LINE: Rgb = (0, 0, 0);
+const GIVEN: Rgb = (0, 0, 0);
+const PLACED: Rgb = (30, 80, 200);
+const NOTE: Rgb = (100, 100, 100);
+const PATTERN_CELL: Rgb = (255, 243, 176);
+const TARGET_CELL: Rgb = (200, 240, 200);
+const PATTERN_NOTE: Rgb = (30, 150, 60);
+const REMOVED_NOTE: Rgb = (220, 40, 40);
+
+/// Output format of the `rate render` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderFormat {
+    /// [`Sudoku::to_svg`]
+    #[default]
+    Svg,
+    /// [`Sudoku::to_png`], with the `png` feature
+    Png,
+    /// [`Sudoku::to_ansi`]
+    Ansi,
+    /// [`Sudoku::to_html`]
+    Html,
+}
+
+impl std::str::FromStr for RenderFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "svg" => Ok(RenderFormat::Svg),
+            "png" => Ok(RenderFormat::Png),
+            "ansi" => Ok(RenderFormat::Ansi),
+            "html" => Ok(RenderFormat::Html),
+            _ => Err(format!("Unknown format: {}", s)),
+        }
+    }
+}
+
+/// What to draw besides the digits.
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// Show the notes of the empty cells
+    pub candidates: bool,
+    /// Step to highlight: the cells of its pattern, the cell it places a digit in, the
+    /// candidates of its pattern and those it removes. Implies showing the notes.
+    pub highlight: Option<StrategyResult>,
+}
+
+impl RenderOptions {
+    pub fn new() -> RenderOptions {
+        RenderOptions::default()
+    }
+
+    pub fn with_candidates(self) -> RenderOptions {
+        RenderOptions {
+            candidates: true,
+            ..self
+        }
+    }
+
+    pub fn with_highlight(self, step: StrategyResult) -> RenderOptions {
+        RenderOptions {
+            highlight: Some(step),
+            ..self
+        }
+    }
+
+    fn shows_candidates(&self) -> bool {
+        self.candidates || self.highlight.is_some()
+    }
+
+    /// Whether the cell is part of the pattern, and whether the step places a digit in it.
+    fn cell_role(&self, row: usize, col: usize) -> (bool, bool) {
+        let Some(step) = &self.highlight else {
+            return (false, false);
+        };
+        let removals = &step.removals;
+        let target = removals
+            .sets_cell
+            .as_ref()
+            .is_some_and(|cell| (cell.row, cell.col) == (row, col));
+        let pattern = removals
+            .cells_affected
+            .iter()
+            .any(|cell| (cell.row, cell.col) == (row, col));
+        (pattern, target)
+    }
+
+    /// Color of the candidate: placed by the step or part of its pattern, removed by it,
+    /// or neither.
+    fn note_color(&self, row: usize, col: usize, num: u8) -> Rgb {
+        let Some(step) = &self.highlight else {
+            return NOTE;
+        };
+        let candidate = Candidate { row, col, num };
+        let placed = step
+            .removals
+            .sets_cell
+            .as_ref()
+            .is_some_and(|cell| (cell.row, cell.col, cell.num) == (row, col, num));
+        if placed || step.removals.candidates_affected.contains(&candidate) {
+            PATTERN_NOTE
+        } else if step
+            .removals
+            .candidates_about_to_be_removed
+            .contains(&candidate)
+        {
+            REMOVED_NOTE
+        } else {
+            NOTE
+        }
+    }
+}
+
+/// Something to draw, in pixels.
+enum Shape {
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: Rgb,
+    },
+    /// A character centered at `x`, `y`, `size` pixels high
+    Glyph {
+        x: f32,
+        y: f32,
+        size: f32,
+        c: char,
+        color: Rgb,
+        bold: bool,
+    },
+}
+
+fn hex(color: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2)
+}
+
+impl Sudoku {
+    /// Width and height of the pictures in pixels.
+    fn picture_size(&self) -> f32 {
+        2.0 * MARGIN + self.size() as f32 * CELL
+    }
+
+    /// The shapes of the picture, back to front.
+    fn shapes(&self, options: &RenderOptions) -> Vec<Shape> {
+        let size = self.size();
+        let GridSpec { box_rows, box_cols } = self.spec;
+        let extent = self.picture_size();
+        let mut shapes = vec![Shape::Rect {
+            x: 0.0,
+            y: 0.0,
+            width: extent,
+            height: extent,
+            color: BACKGROUND,
+        }];
+        for row in 0..size {
+            for col in 0..size {
+                let color = match options.cell_role(row, col) {
+                    (_, true) => TARGET_CELL,
+                    (true, false) => PATTERN_CELL,
+                    (false, false) => continue,
+                };
+                shapes.push(Shape::Rect {
+                    x: MARGIN + col as f32 * CELL,
+                    y: MARGIN + row as f32 * CELL,
+                    width: CELL,
+                    height: CELL,
+                    color,
+                });
+            }
+        }
+        // Thin lines first, so that the thick ones cover them where they cross
+        for thick in [false, true] {
+            for i in 0..=size {
+                let offset = MARGIN + i as f32 * CELL;
+                let (width, color) = if thick {
+                    (3.0, THICK_LINE)
+                } else {
+                    (1.0, THIN_LINE)
+                };
+                if i.is_multiple_of(box_cols) == thick {
+                    shapes.push(Shape::Rect {
+                        x: offset - width / 2.0,
+                        y: MARGIN - 1.5,
+                        width,
+                        height: size as f32 * CELL + 3.0,
+                        color,
+                    });
+                }
+                if i.is_multiple_of(box_rows) == thick {
+                    shapes.push(Shape::Rect {
+                        x: MARGIN - 1.5,
+                        y: offset - width / 2.0,
+                        width: size as f32 * CELL + 3.0,
+                        height: width,
+                        color,
+                    });
+                }
+            }
+        }
+        let (note_width, note_height) = (CELL / box_cols as f32, CELL / box_rows as f32);
+        for row in 0..size {
+            for col in 0..size {
+                let (x, y) = (MARGIN + col as f32 * CELL, MARGIN + row as f32 * CELL);
+                let num = self.board[row][col];
+                if num != EMPTY {
+                    let given = self.is_given(row, col);
+                    shapes.push(Shape::Glyph {
+                        x: x + CELL / 2.0,
+                        y: y + CELL / 2.0,
+                        size: CELL * 0.6,
+                        c: self.spec.digit_char(num),
+                        color: if given { GIVEN } else { PLACED },
+                        bold: given,
+                    });
+                } else if options.shows_candidates() {
+                    for num in self.candidates[row][col].iter() {
+                        let k = num as usize - 1;
+                        shapes.push(Shape::Glyph {
+                            x: x + (k % box_cols) as f32 * note_width + note_width / 2.0,
+                            y: y + (k / box_cols) as f32 * note_height + note_height / 2.0,
+                            size: note_width.min(note_height) * 0.65,
+                            c: self.spec.digit_char(num),
+                            color: options.note_color(row, col, num),
+                            bold: false,
+                        });
+                    }
+                }
+            }
+        }
+        shapes
+    }
+
+    /// The board as an SVG image: givens in black, digits placed since in blue, notes
+    /// in gray. A highlighted step shades the cells of its pattern in yellow and the
+    /// cell it places a digit in in green, and colors the candidates of its pattern
+    /// green and those it removes red.
+    pub fn to_svg(&self, options: &RenderOptions) -> String {
+        let extent = self.picture_size();
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" \
+             viewBox=\"0 0 {0} {0}\">\n",
+            extent
+        );
+        for shape in self.shapes(options) {
+            svg += &match shape {
+                Shape::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                    x,
+                    y,
+                    width,
+                    height,
+                    hex(color)
+                ),
+                Shape::Glyph {
+                    x,
+                    y,
+                    size,
+                    c,
+                    color,
+                    bold,
+                } => format!(
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" font-family=\"sans-serif\"{} \
+                     fill=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>\n",
+                    x,
+                    y,
+                    size,
+                    if bold { " font-weight=\"bold\"" } else { "" },
+                    hex(color),
+                    c
+                ),
+            };
+        }
+        svg + "</svg>\n"
+    }
+
+    /// A standalone HTML page showing the board as in [`Sudoku::to_svg`], with the
+    /// strategy and the changes of a highlighted step below.
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Sudoku</title>\n</head>\n<body>\n",
+        );
+        html += &self.to_svg(options);
+        if let Some(step) = &options.highlight {
+            let removals = &step.removals;
+            html += &format!("<p>{}", step.strategy);
+            if let Some(cell) = &removals.sets_cell {
+                html += &format!(": {}", cell);
+            } else if !removals.candidates_about_to_be_removed.is_empty() {
+                let eliminations: Vec<String> = removals
+                    .candidates_about_to_be_removed
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                html += &format!(
+                    ": {}",
+                    eliminations
+                        .join(", ")
+                        .replace('<', "&lt;")
+                        .replace('>', "&gt;")
+                );
+            }
+            html += "</p>\n";
+        }
+        html + "</body>\n</html>\n"
+    }
+
+    /// The board as text with ANSI colors for terminals, framed like
+    /// [`Sudoku::to_display_grid`]: givens in bold, digits placed since in blue. With
+    /// notes, each cell shows its candidates like [`Sudoku::dump_notes`]. A highlighted
+    /// step colors the cells and candidates like [`Sudoku::to_svg`].
+    pub fn to_ansi(&self, options: &RenderOptions) -> String {
+        const RESET: &str = "\x1b[0m";
+        let size = self.size();
+        let GridSpec { box_rows, box_cols } = self.spec;
+        let notes = options.shows_candidates();
+        // Notes take `box_rows` lines of `box_cols` characters per cell
+        let (lines, width) = if notes {
+            (box_rows, box_cols + 2)
+        } else {
+            (1, 3)
+        };
+        let mut text = frame_line(self.spec, width, ('╔', '═', '╤', '╦', '╗'));
+        text.push('\n');
+        for row in 0..size {
+            for line in 0..lines {
+                text.push('║');
+                for col in 0..size {
+                    let background = match options.cell_role(row, col) {
+                        (_, true) => "\x1b[42m",
+                        (true, false) => "\x1b[43m",
+                        (false, false) => "",
+                    };
+                    text += background;
+                    let num = self.board[row][col];
+                    if num != EMPTY {
+                        let style = if self.is_given(row, col) {
+                            "\x1b[1m"
+                        } else {
+                            "\x1b[34m"
+                        };
+                        if line == lines / 2 {
+                            let digit = self.spec.digit_char(num);
+                            text += &format!("{}{:^3$}{}", style, digit, RESET, width);
+                        } else {
+                            text += &" ".repeat(width);
+                        }
+                    } else if notes {
+                        text.push(' ');
+                        for k in 0..box_cols {
+                            let num = (box_cols * line + k + 1) as u8;
+                            if !self.candidates[row][col].contains(&num) {
+                                text.push('.');
+                                continue;
+                            }
+                            let digit = self.spec.digit_char(num);
+                            match options.note_color(row, col, num) {
+                                REMOVED_NOTE => {
+                                    text += &format!("\x1b[31m{}{}{}", digit, RESET, background)
+                                }
+                                PATTERN_NOTE => {
+                                    text += &format!("\x1b[32m{}{}{}", digit, RESET, background)
+                                }
+                                _ => text.push(digit),
+                            }
+                        }
+                        text.push(' ');
+                    } else {
+                        text += &" ".repeat(width);
+                    }
+                    if !background.is_empty() {
+                        text += RESET;
+                    }
+                    text.push(if (col + 1).is_multiple_of(box_cols) {
+                        '║'
+                    } else {
+                        '│'
+                    });
+                }
+                text.push('\n');
+            }
+            text += &frame_line_below(self.spec, width, row);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// The board as a PNG image, drawn like [`Sudoku::to_svg`] with digits in the
+    /// style of seven-segment displays, so that no font is needed.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self, options: &RenderOptions) -> Vec<u8> {
+        let extent = self.picture_size() as usize;
+        let mut canvas = Canvas {
+            width: extent,
+            height: extent,
+            pixels: vec![0; extent * extent * 3],
+        };
+        for shape in self.shapes(options) {
+            match shape {
+                Shape::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => canvas.fill(x, y, width, height, color),
+                Shape::Glyph {
+                    x,
+                    y,
+                    size,
+                    c,
+                    color,
+                    bold,
+                } => canvas.glyph(x, y, size, c, color, bold),
+            }
+        }
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, extent as u32, extent as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&canvas.pixels))
+            .expect("encoding an RGB image in memory can't fail");
+        png
+    }
+}
+
+/// RGB pixels to draw the shapes on.
+#[cfg(feature = "png")]
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+/// Segments lit for each hexadecimal digit: top, top right, bottom right, bottom, bottom
+/// left, top left and middle, from the lowest bit.
+#[cfg(feature = "png")]
+const SEGMENTS: [u8; 16] = [
+    0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F, 0x77, 0x7C, 0x39, 0x5E, 0x79, 0x71,
+];
+
+#[cfg(feature = "png")]
+impl Canvas {
+    fn fill(&mut self, x: f32, y: f32, width: f32, height: f32, color: Rgb) {
+        let clamp = |value: f32, max: usize| (value.round().max(0.0) as usize).min(max);
+        let (left, right) = (clamp(x, self.width), clamp(x + width, self.width));
+        let (top, bottom) = (clamp(y, self.height), clamp(y + height, self.height));
+        for row in top..bottom {
+            for col in left..right {
+                let i = (row * self.width + col) * 3;
+                self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+            }
+        }
+    }
+
+    /// Draw the digit as seven segments, centered at `x`, `y`.
+    fn glyph(&mut self, x: f32, y: f32, size: f32, c: char, color: Rgb, bold: bool) {
+        let Some(digit) = c.to_digit(16) else {
+            return;
+        };
+        let (height, width) = (size, size * 0.55);
+        let thickness = (size / if bold { 6.0 } else { 8.0 }).max(1.0);
+        let (left, top) = (x - width / 2.0, y - height / 2.0);
+        let half = height / 2.0;
+        let segments = [
+            (left, top, width, thickness),
+            (left + width - thickness, top, thickness, half),
+            (left + width - thickness, top + half, thickness, half),
+            (left, top + height - thickness, width, thickness),
+            (left, top + half, thickness, half),
+            (left, top, thickness, half),
+            (left, top + half - thickness / 2.0, width, thickness),
+        ];
+        for (i, &(x, y, width, height)) in segments.iter().enumerate() {
+            if SEGMENTS[digit as usize] & 1 << i != 0 {
+                self.fill(x, y, width, height, color);
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Re-rating a puzzle after each edit of a given, for puzzle editors. Rating from
+//! scratch repeats most of the work every time: the uniqueness check, and the solve,
+//! which after a small edit mostly runs into positions an earlier solve went through.
+
+use crate::{
+    Board, Cell, DifficultyLevel, EMPTY, Error, RATING_ALGORITHM, Rating, Solver, Strategy, Sudoku,
+};
+use std::collections::BTreeMap;
+
+/// Default number of positions a [`Rerater`] remembers, see [`Rerater::with_cache_limit`].
+const MAX_CACHED_POSITIONS: usize = 1 << 16;
+
+/// What the solver does from a position on: the candidates each strategy removes up to
+/// the end of the solve, and whether it ends solved.
+#[derive(Debug, Clone)]
+struct Tail {
+    rating: BTreeMap<Strategy, usize>,
+    solved: bool,
+}
+
+/// A board being edited one cell at a time and rated after each edit, with the same
+/// results as [`Solver::rate`].
+///
+/// The solver's steps depend on the digits and notes only, so every position a solve
+/// went through is remembered with the rating of the steps from there to the end. A
+/// later solve reaching one of these positions, e.g. once the edited cell is filled,
+/// takes the rest of the rating from there instead of solving on. The unique solution
+/// is remembered too, so that adding one of its digits as a given skips the
+/// uniqueness check.
+#[derive(Debug, Clone)]
+pub struct Rerater {
+    solver: Solver,
+    board: Board,
+    /// The solution of the board, if it has exactly one
+    solution: Option<Vec<Vec<u8>>>,
+    tails: BTreeMap<u128, Tail>,
+    cache_limit: usize,
+}
+
+impl Rerater {
+    /// Start editing the board, rating it with a default [`Solver`].
+    pub fn new(board: Board) -> Rerater {
+        Rerater {
+            solver: Solver::new(),
+            board,
+            solution: None,
+            tails: BTreeMap::new(),
+            cache_limit: MAX_CACHED_POSITIONS,
+        }
+    }
+
+    /// Rate with the mode, budget and backend of the solver. The budget applies to the
+    /// steps taken, not to those reused from earlier solves.
+    pub fn with_solver(self, solver: Solver) -> Rerater {
+        Rerater { solver, ..self }
+    }
+
+    /// Remember at most `limit` positions. Once a solve would pass the limit, the
+    /// positions remembered so far are forgotten, so that a long editing session
+    /// doesn't keep every position it ever went through.
+    pub fn with_cache_limit(self, limit: usize) -> Rerater {
+        Rerater {
+            cache_limit: limit,
+            ..self
+        }
+    }
+
+    /// The board with the edits so far.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Number of positions remembered from the solves so far.
+    pub fn cached_positions(&self) -> usize {
+        self.tails.len()
+    }
+
+    /// Rate the board as it is, like [`Solver::rate`].
+    pub fn rate(&mut self) -> Result<Rating, Error> {
+        let mut sudoku = Sudoku::from_board(&self.board);
+        if self.solution.is_none() {
+            self.solution = Some(self.unique_solution(&sudoku)?);
+        }
+        let (rating, solved) = self.solve(&mut sudoku);
+        sudoku.rating = rating;
+        Ok(Rating {
+            puzzle: self.board.original_board(),
+            solved,
+            difficulty: sudoku.difficulty_in(self.solver.mode),
+            mode: self.solver.mode,
+            level: DifficultyLevel::of(sudoku.difficulty()),
+            algorithm: RATING_ALGORITHM,
+            strategies: sudoku.rating(),
+            metadata: self.board.metadata.clone(),
+        })
+    }
+
+    /// Make the cell's digit a given, or clear the cell if the digit is [`EMPTY`], and
+    /// rate the board again. Fails like [`Solver::rate`] if the edited board doesn't
+    /// have exactly one solution, and with [`Error::Parse`] if the cell or digit is
+    /// outside of the grid.
+    pub fn rerate_after_edit(&mut self, cell: Cell) -> Result<Rating, Error> {
+        let size = self.board.size();
+        if cell.row >= size || cell.col >= size || cell.num as usize > size {
+            return Err(Error::Parse(format!("{} is outside of the grid", cell)));
+        }
+        // Adding a digit of the unique solution keeps the solution unique
+        let keeps_solution = self.board.values[cell.row][cell.col] == cell.num
+            || self.solution.as_ref().is_some_and(|solution| {
+                cell.num != EMPTY && solution[cell.row][cell.col] == cell.num
+            });
+        if !keeps_solution {
+            self.solution = None;
+        }
+        self.board.values[cell.row][cell.col] = cell.num;
+        self.board.givens[cell.row][cell.col] = cell.num != EMPTY;
+        self.rate()
+    }
+
+    fn unique_solution(&self, sudoku: &Sudoku) -> Result<Vec<Vec<u8>>, Error> {
+        if sudoku.has_conflicts() {
+            return Err(Error::Conflict);
+        }
+        let mut solutions = self.solver.backend.solutions(sudoku, 2);
+        match solutions.len() {
+            0 => Err(Error::NoSolution),
+            1 => Ok(solutions.remove(0)),
+            _ => Err(Error::MultipleSolutions),
+        }
+    }
+
+    /// Solve like [`Sudoku::solve_human_like_within`] until the solve ends or reaches a
+    /// remembered position, and return the rating and whether the solve ends solved.
+    /// The positions passed are remembered unless the budget ran out.
+    fn solve(&mut self, sudoku: &mut Sudoku) -> (BTreeMap<Strategy, usize>, bool) {
+        let budget = &self.solver.budget;
+        let deadline = budget.deadline();
+        sudoku.calc_all_notes();
+        sudoku.rating.clear();
+        // Each position passed, with the strategy of the step taken in it and what the
+        // step added to the rating
+        let mut path: Vec<(u128, Strategy, usize)> = Vec::new();
+        let tail = loop {
+            let hash = sudoku.position_hash();
+            if let Some(tail) = self.tails.get(&hash) {
+                break tail.clone();
+            }
+            if !sudoku.unsolved() {
+                break Tail {
+                    rating: BTreeMap::new(),
+                    solved: true,
+                };
+            }
+            if budget.exceeded(path.len(), deadline).is_some() {
+                return (sudoku.rating.clone(), false);
+            }
+            let step = sudoku.next_step();
+            if step.strategy == Strategy::None {
+                break Tail {
+                    rating: BTreeMap::new(),
+                    solved: false,
+                };
+            }
+            sudoku
+                .apply(&step)
+                .expect("steps found on the board fit it");
+            sudoku.undo_stack.clear();
+            let count = step.rating_count();
+            path.push((hash, step.strategy, count));
+        };
+        let mut rating = tail.rating.clone();
+        for (strategy, count) in &sudoku.rating {
+            *rating.entry(strategy.clone()).or_insert(0) += count;
+        }
+        // Remember the tail of each position passed, the last one first
+        if self.tails.len() + path.len() > self.cache_limit {
+            self.tails.clear();
+        }
+        let mut tail = tail;
+        for (hash, strategy, count) in path.into_iter().rev() {
+            *tail.rating.entry(strategy).or_insert(0) += count;
+            if self.tails.len() < self.cache_limit {
+                self.tails.insert(hash, tail.clone());
+            }
+        }
+        (rating, tail.solved)
+    }
+}
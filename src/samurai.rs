@@ -0,0 +1,219 @@
+//! Samurai Sudoku: five classic grids, four of them overlapping the corner boxes of the center grid.
+
+use crate::{EMPTY, SolveOutcome, Strategy, StrategyResult, Sudoku, SudokuError};
+use std::collections::BTreeMap;
+
+/// Number of rows and columns of the samurai layout.
+pub const SAMURAI_SIZE: usize = 21;
+
+/// Position of the top-left cell of each grid in the layout: top left, top right,
+/// center, bottom left, bottom right.
+pub const SAMURAI_OFFSETS: [(usize, usize); 5] = [(0, 0), (0, 12), (6, 6), (12, 0), (12, 12)];
+
+#[derive(Debug, Clone, Default)]
+pub struct Samurai {
+    pub grids: [Sudoku; 5],
+}
+
+impl Samurai {
+    pub fn new() -> Samurai {
+        Samurai::default()
+    }
+
+    /// The grids containing the given cell of the layout, as `(grid, row, col)` with
+    /// the row and column inside the grid. Cells in the gaps between the grids
+    /// belong to no grid, cells in the shared boxes belong to two.
+    pub fn grids_at(row: usize, col: usize) -> Vec<(usize, usize, usize)> {
+        SAMURAI_OFFSETS
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(top, left))| {
+                (top..top + 9).contains(&row) && (left..left + 9).contains(&col)
+            })
+            .map(|(grid, &(top, left))| (grid, row - top, col - left))
+            .collect()
+    }
+
+    /// Parse the standard text layout of 21 lines with 21 characters each: digits
+    /// for givens, `0` or `.` for empty cells, and anything (usually blanks) in the
+    /// gaps between the grids. Common indentation is ignored.
+    pub fn from_layout(s: &str) -> Result<Samurai, SudokuError> {
+        let lines: Vec<&str> = s.lines().filter(|line| !line.trim().is_empty()).collect();
+        if lines.len() != SAMURAI_SIZE {
+            return Err(SudokuError::Parse(format!(
+                "Samurai layout must have {} lines, found {}",
+                SAMURAI_SIZE,
+                lines.len()
+            )));
+        }
+        let indent = lines
+            .iter()
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let mut samurai = Samurai::new();
+        for (row, line) in lines.iter().enumerate() {
+            let chars: Vec<char> = line.chars().skip(indent).collect();
+            for col in 0..SAMURAI_SIZE {
+                let grids = Samurai::grids_at(row, col);
+                if grids.is_empty() {
+                    continue;
+                }
+                let c = chars.get(col).copied().unwrap_or(' ');
+                let num = match c {
+                    '.' => EMPTY,
+                    _ => c.to_digit(10).ok_or_else(|| {
+                        SudokuError::Parse(format!(
+                            "Invalid character '{}' at line {}, column {}",
+                            c,
+                            row + 1,
+                            col + 1
+                        ))
+                    })? as u8,
+                };
+                for (grid, r, c) in grids {
+                    samurai.grids[grid].board[r][c] = num;
+                    samurai.grids[grid].original_board[r][c] = num;
+                }
+            }
+        }
+        Ok(samurai)
+    }
+
+    /// The board in the text layout, with blanks in the gaps between the grids.
+    pub fn to_layout(&self) -> String {
+        let mut layout = String::new();
+        for row in 0..SAMURAI_SIZE {
+            let line: String = (0..SAMURAI_SIZE)
+                .map(|col| match Samurai::grids_at(row, col).first() {
+                    Some(&(grid, r, c)) => (self.grids[grid].board[r][c] + b'0') as char,
+                    None => ' ',
+                })
+                .collect();
+            layout.push_str(line.trim_end());
+            layout.push('\n');
+        }
+        layout
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.grids.iter().all(|grid| grid.is_solved())
+    }
+
+    pub fn has_conflicts(&self) -> bool {
+        self.grids.iter().any(|grid| grid.has_conflicts())
+    }
+
+    /// Make the shared boxes agree: digits set in one grid are set in the other one
+    /// as well, and the candidates of empty shared cells are intersected.
+    fn sync(&mut self) {
+        for row in 0..SAMURAI_SIZE {
+            for col in 0..SAMURAI_SIZE {
+                let cells = Samurai::grids_at(row, col);
+                if cells.len() < 2 {
+                    continue;
+                }
+                let num = cells
+                    .iter()
+                    .map(|&(grid, r, c)| self.grids[grid].board[r][c])
+                    .find(|&num| num != EMPTY);
+                if let Some(num) = num {
+                    for &(grid, r, c) in &cells {
+                        let sudoku = &mut self.grids[grid];
+                        if sudoku.board[r][c] != EMPTY {
+                            continue;
+                        }
+                        let removals = sudoku.collect_set_num(num, r, c);
+                        for candidate in &removals.candidates_about_to_be_removed {
+                            sudoku.candidates[candidate.row][candidate.col].remove(&candidate.num);
+                        }
+                        sudoku.board[r][c] = num;
+                    }
+                } else {
+                    let common = cells
+                        .iter()
+                        .map(|&(grid, r, c)| self.grids[grid].candidates[r][c])
+                        .reduce(|a, b| a.intersection(&b))
+                        .unwrap_or_default();
+                    for &(grid, r, c) in &cells {
+                        self.grids[grid].candidates[r][c] = common;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Solve all grids together with the human-like strategies. In each step, the
+    /// easiest step available in any of the grids is taken, and the shared boxes
+    /// are kept in sync. If the grids get stuck, the empty cells of all grids are
+    /// counted, so cells in the shared boxes count twice.
+    pub fn solve_human_like(&mut self) -> SolveOutcome {
+        for grid in &mut self.grids {
+            grid.calc_all_notes();
+            grid.rating.clear();
+        }
+        loop {
+            self.sync();
+            if self.is_solved() {
+                break;
+            }
+            let mut best: Option<(usize, StrategyResult)> = None;
+            for (idx, grid) in self.grids.iter_mut().enumerate() {
+                if grid.is_solved() {
+                    continue;
+                }
+                // Only the step actually taken counts for the rating
+                let rating = grid.rating.clone();
+                let result = grid.next_step();
+                grid.rating = rating;
+                if result.strategy == Strategy::None {
+                    continue;
+                }
+                if best.as_ref().is_none_or(|(_, best_result)| {
+                    result.strategy.difficulty() < best_result.strategy.difficulty()
+                }) {
+                    best = Some((idx, result));
+                }
+            }
+            let Some((idx, result)) = best else {
+                break;
+            };
+            let grid = &mut self.grids[idx];
+            grid.rate_step(&result);
+            grid.apply(&result)
+                .expect("steps found on the board fit it");
+        }
+        if self.is_solved() {
+            SolveOutcome::Solved
+        } else {
+            SolveOutcome::StuckAt(
+                self.grids
+                    .iter()
+                    .map(|grid| grid.empty_cells().count())
+                    .sum(),
+            )
+        }
+    }
+
+    /// Combined rating of all grids.
+    pub fn rating(&self) -> BTreeMap<Strategy, usize> {
+        let mut rating = BTreeMap::new();
+        for grid in &self.grids {
+            for (strategy, &count) in &grid.rating {
+                *rating.entry(strategy.clone()).or_insert(0) += count;
+            }
+        }
+        rating
+    }
+
+    /// Combined difficulty of all grids, calculated like [`Sudoku::difficulty`].
+    pub fn difficulty(&self) -> f64 {
+        let rating = self.rating();
+        let candidates_removed = rating.values().sum::<usize>();
+        let total_rating: i32 = rating
+            .iter()
+            .map(|(strategy, &count)| strategy.difficulty() * count as i32)
+            .sum();
+        (total_rating as f64) / (candidates_removed as f64)
+    }
+}
@@ -0,0 +1,400 @@
+//! HTTP server exposing the rater as a JSON API.
+//!
+//! Endpoints:
+//! - `POST /rate` with `{"sudoku": "<81 digits>"}` rates the Sudoku (6×6 and 16×16 grids
+//!   are accepted as well, see [`Sudoku::set_board_string`])
+//! - `POST /solve` with `{"sudoku": "<81 digits>"}` solves the Sudoku
+//! - `POST /hint` with `{"sudoku": "<81 digits>"}` returns the next logical step. An
+//!   optional `"candidates"` pencilmark grid, see [`Sudoku::from_pencilmarks`], replaces
+//!   the notes calculated from the digits. Hints are cached per position.
+//! - `POST /hints` with a list of hint requests returns a hint, or an `error`, for each
+//! - `GET /generate?difficulty=hard` generates a Sudoku of the requested difficulty level
+
+use crate::{
+    Budget, CancelToken, DifficultyLevel, Metadata, RATING_ALGORITHM, SolveOutcome, Strategy,
+    StrategyInfo, StrategyResult, Sudoku, oneshot,
+};
+use axum::{
+    Json, Router,
+    extract::Query,
+    http::StatusCode,
+    routing::{get, post},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+/// Maximum number of attempts to generate a Sudoku of the requested difficulty level.
+const MAX_GENERATE_ATTEMPTS: usize = 1000;
+
+/// Time the human-like solver may spend on a single request.
+const SOLVE_TIME_LIMIT: Duration = Duration::from_secs(5);
+
+/// Number of positions whose hints are cached.
+const HINT_CACHE_CAPACITY: usize = 1024;
+
+type ApiError = (StatusCode, String);
+
+#[derive(Debug, Deserialize)]
+struct SudokuRequest {
+    sudoku: String,
+    /// Echoed in the response, e.g. to match it with the puzzle's ID
+    #[serde(default)]
+    metadata: Metadata,
+}
+
+#[derive(Debug, Deserialize)]
+struct HintRequest {
+    sudoku: String,
+    /// Pencilmark grid of the position; the notes are calculated from the digits if
+    /// it's missing
+    #[serde(default)]
+    candidates: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GenerateParams {
+    difficulty: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct StrategyCount {
+    strategy: Strategy,
+    name: String,
+    effort: i32,
+    count: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct RateResponse {
+    sudoku: String,
+    solved: bool,
+    outcome: SolveOutcome,
+    difficulty: f64,
+    level: DifficultyLevel,
+    /// Version of the rating algorithm, see [`RATING_ALGORITHM`]
+    algorithm: &'static str,
+    rating: Vec<StrategyCount>,
+    solution: String,
+    #[serde(skip_serializing_if = "Metadata::is_empty")]
+    metadata: Metadata,
+}
+
+#[derive(Debug, Serialize)]
+struct SolveResponse {
+    sudoku: String,
+    solved_by_logic: bool,
+    solution: String,
+    #[serde(skip_serializing_if = "Metadata::is_empty")]
+    metadata: Metadata,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct HintResponse {
+    sudoku: String,
+    name: String,
+    /// Category, description and link of the strategy, for a "learn more" panel
+    info: StrategyInfo,
+    hint: StrategyResult,
+}
+
+/// Hint for one position of a batch, or why there is none.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum BatchHint {
+    Hint(Box<HintResponse>),
+    Error { error: String },
+}
+
+#[derive(Debug, Serialize)]
+struct GenerateResponse {
+    sudoku: String,
+    difficulty: f64,
+    level: DifficultyLevel,
+}
+
+/// Parse the Sudoku from the request. Its solution is checked on a worker thread, see
+/// [`unique_solution`].
+fn parse_sudoku(request: &SudokuRequest) -> Result<Sudoku, ApiError> {
+    let board = oneshot::parse_board(&request.sudoku)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+    Ok(Sudoku::from_board(&board).with_metadata(request.metadata.clone()))
+}
+
+/// The solution of the Sudoku, making sure it's the only one. The backtracking search
+/// has no bound of its own, so it stops when the budget runs out.
+fn unique_solution(sudoku: &Sudoku, budget: &Budget) -> Result<Vec<Vec<u8>>, ApiError> {
+    let invalid = |e: oneshot::Error| (StatusCode::UNPROCESSABLE_ENTITY, e.to_string());
+    if sudoku.has_conflicts() {
+        return Err(invalid(oneshot::Error::Conflict));
+    }
+    let mut solutions = sudoku.solutions().within(budget);
+    let found: Vec<Vec<Vec<u8>>> = solutions.by_ref().take(2).collect();
+    if solutions.budget_exceeded() {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "The solutions can't be counted in time".to_string(),
+        ));
+    }
+    match <[_; 1]>::try_from(found) {
+        Ok([solution]) => Ok(solution),
+        Err(found) if found.is_empty() => Err(invalid(oneshot::Error::NoSolution)),
+        Err(_) => Err(invalid(oneshot::Error::MultipleSolutions)),
+    }
+}
+
+fn strategy_counts(sudoku: &Sudoku) -> Vec<StrategyCount> {
+    let mut rating: Vec<StrategyCount> = sudoku
+        .rating
+        .iter()
+        .map(|(strategy, &count)| StrategyCount {
+            strategy: strategy.clone(),
+            name: format!("{}", strategy),
+            effort: strategy.difficulty(),
+            count,
+        })
+        .collect();
+    rating.sort_by_key(|entry| entry.effort);
+    rating
+}
+
+/// Cancels the work of a request when dropped, e.g. because the client went away
+/// before it finished.
+struct CancelOnDrop(CancelToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+/// Run CPU-bound work for a request on the blocking pool of the runtime, keeping it
+/// off the async workers, with a budget that runs out after [`SOLVE_TIME_LIMIT`] or
+/// when the request is dropped.
+async fn run_on_worker<T: Send + 'static>(
+    work: impl FnOnce(Budget) -> Result<T, ApiError> + Send + 'static,
+) -> Result<T, ApiError> {
+    let cancel = CancelToken::new();
+    let budget = Budget::unlimited()
+        .with_max_duration(SOLVE_TIME_LIMIT)
+        .with_cancel(cancel.clone());
+    let _cancel_on_drop = CancelOnDrop(cancel);
+    tokio::task::spawn_blocking(move || work(budget))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+}
+
+async fn rate(Json(request): Json<SudokuRequest>) -> Result<Json<RateResponse>, ApiError> {
+    let mut sudoku = parse_sudoku(&request)?;
+    let response = run_on_worker(move |budget| {
+        let mut solution = sudoku.clone();
+        solution.board = unique_solution(&sudoku, &budget)?;
+        let outcome = sudoku.solve_human_like_within(&budget);
+        let difficulty = sudoku.difficulty();
+        Ok(RateResponse {
+            sudoku: sudoku.original_board(),
+            solved: outcome == SolveOutcome::Solved,
+            outcome,
+            difficulty,
+            level: DifficultyLevel::of(difficulty),
+            algorithm: RATING_ALGORITHM,
+            rating: strategy_counts(&sudoku),
+            solution: solution.serialized(),
+            metadata: sudoku.metadata,
+        })
+    })
+    .await?;
+    Ok(Json(response))
+}
+
+async fn solve(Json(request): Json<SudokuRequest>) -> Result<Json<SolveResponse>, ApiError> {
+    let mut sudoku = parse_sudoku(&request)?;
+    let response = run_on_worker(move |budget| {
+        let solution = unique_solution(&sudoku, &budget)?;
+        let solved_by_logic = sudoku.solve_human_like_within(&budget) == SolveOutcome::Solved;
+        if !solved_by_logic {
+            sudoku.board = solution;
+        }
+        Ok(SolveResponse {
+            sudoku: sudoku.original_board(),
+            solved_by_logic,
+            solution: sudoku.serialized(),
+            metadata: sudoku.metadata,
+        })
+    })
+    .await?;
+    Ok(Json(response))
+}
+
+/// Hints of recently requested positions, keyed by their digits and candidates. When
+/// full, the least recently used hint makes room. Spectators of the same game ask for
+/// the same position many times, so this spares checking the solution and running the
+/// strategies again.
+#[derive(Default)]
+struct HintCache {
+    /// Hint or error of each position, with the time it was last used
+    entries: HashMap<String, (u64, Result<HintResponse, ApiError>)>,
+    clock: u64,
+}
+
+impl HintCache {
+    fn get(&mut self, key: &str) -> Option<Result<HintResponse, ApiError>> {
+        self.clock += 1;
+        let (used, hint) = self.entries.get_mut(key)?;
+        *used = self.clock;
+        Some(hint.clone())
+    }
+
+    fn insert(&mut self, key: String, hint: Result<HintResponse, ApiError>) {
+        if self.entries.len() >= HINT_CACHE_CAPACITY && !self.entries.contains_key(&key) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (used, _))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.clock += 1;
+        self.entries.insert(key, (self.clock, hint));
+    }
+}
+
+static HINT_CACHE: LazyLock<Mutex<HintCache>> = LazyLock::new(Mutex::default);
+
+/// The position of a hint request, with the candidates of the request or the notes
+/// calculated from its digits.
+fn hint_position(request: &HintRequest) -> Result<Sudoku, ApiError> {
+    let board = oneshot::parse_board(&request.sudoku)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+    let mut sudoku = Sudoku::from_board(&board);
+    match &request.candidates {
+        Some(grid) => {
+            let position = Sudoku::from_pencilmarks(grid)
+                .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+            if position.board != sudoku.board {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "The candidates don't match the digits of the Sudoku".to_string(),
+                ));
+            }
+            sudoku.candidates = position.candidates;
+        }
+        None => sudoku.calc_all_notes(),
+    }
+    Ok(sudoku)
+}
+
+/// The hint for the position after checking, within the budget, that the Sudoku has a
+/// unique solution.
+fn find_hint(mut sudoku: Sudoku, budget: &Budget) -> Result<HintResponse, ApiError> {
+    unique_solution(&sudoku, budget).map(|_| {
+        let hint = sudoku.next_step();
+        HintResponse {
+            sudoku: sudoku.serialized(),
+            name: format!("{}", hint.strategy),
+            info: hint.strategy.info(),
+            hint,
+        }
+    })
+}
+
+/// The hint for the request, from the cache if the position was asked for recently.
+async fn cached_hint(request: &HintRequest) -> Result<HintResponse, ApiError> {
+    let sudoku = hint_position(request)?;
+    let key = sudoku.pencilmarks();
+    if let Some(hint) = HINT_CACHE.lock().unwrap().get(&key) {
+        return hint;
+    }
+    let response = run_on_worker(move |budget| find_hint(sudoku, &budget)).await;
+    // Running out of time or failing otherwise says nothing about the position, so
+    // only cache hints and the errors of the request itself
+    let cacheable = match &response {
+        Ok(_) => true,
+        Err((status, _)) => status.is_client_error(),
+    };
+    if cacheable {
+        HINT_CACHE.lock().unwrap().insert(key, response.clone());
+    }
+    response
+}
+
+async fn hint(Json(request): Json<HintRequest>) -> Result<Json<HintResponse>, ApiError> {
+    cached_hint(&request).await.map(Json)
+}
+
+async fn hints(Json(requests): Json<Vec<HintRequest>>) -> Json<Vec<BatchHint>> {
+    let mut hints = Vec::with_capacity(requests.len());
+    for request in &requests {
+        hints.push(match cached_hint(request).await {
+            Ok(hint) => BatchHint::Hint(Box::new(hint)),
+            Err((_, error)) => BatchHint::Error { error },
+        });
+    }
+    Json(hints)
+}
+
+async fn generate(
+    Query(params): Query<GenerateParams>,
+) -> Result<Json<GenerateResponse>, ApiError> {
+    let level = match params.difficulty {
+        Some(difficulty) => difficulty
+            .parse::<DifficultyLevel>()
+            .map_err(|e| (StatusCode::BAD_REQUEST, e))?,
+        None => DifficultyLevel::Medium,
+    };
+    // Generating is CPU bound, so keep it off the async workers
+    let generated = tokio::task::spawn_blocking(move || {
+        for _ in 0..MAX_GENERATE_ATTEMPTS {
+            let Some(sudoku) = Sudoku::generate(level.filled_cells()) else {
+                continue;
+            };
+            let mut solved = sudoku.clone();
+            if !solved.solve_human_like().is_solved() {
+                continue;
+            }
+            let difficulty = solved.difficulty();
+            if DifficultyLevel::of(difficulty) == level {
+                return Some(GenerateResponse {
+                    sudoku: sudoku.serialized(),
+                    difficulty,
+                    level,
+                });
+            }
+        }
+        None
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    match generated {
+        Some(response) => Ok(Json(response)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("Could not generate a Sudoku of level {}", level),
+        )),
+    }
+}
+
+/// Build the router with all API endpoints.
+pub fn router() -> Router {
+    Router::new()
+        .route("/rate", post(rate))
+        .route("/solve", post(solve))
+        .route("/hint", post(hint))
+        .route("/hints", post(hints))
+        .route("/generate", get(generate))
+}
+
+/// Run the API server on the given address, e.g. `127.0.0.1:8080`.
+pub fn run(addr: &str) -> std::io::Result<()> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        log::info!("Listening on {}", listener.local_addr()?);
+        axum::serve(listener, router()).await
+    })
+}
@@ -0,0 +1,166 @@
+//! Saving an in-progress solve and restoring it later, e.g. when a frontend is closed
+//! halfway through a puzzle.
+//!
+//! Variant clues, e.g. cages or thermometers, aren't part of the session; load it into
+//! a Sudoku set up with the same clues.
+
+use crate::{Board, DigitSet, EMPTY, Strategy, Sudoku, SudokuError};
+use serde::{Deserialize, Serialize};
+
+/// Version of the session format written by [`Sudoku::save_session`].
+const SESSION_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Session {
+    version: u32,
+    givens: String,
+    position: Position,
+    /// Positions restored by undoing, the oldest first
+    history: Vec<Position>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Position {
+    board: String,
+    notes: Vec<String>,
+    rating: Vec<(Strategy, usize)>,
+}
+
+impl Position {
+    fn of(sudoku: &Sudoku) -> Position {
+        let spec = sudoku.spec;
+        let notes = sudoku
+            .candidates
+            .iter()
+            .flatten()
+            .map(|notes| notes.iter().map(|num| spec.digit_char(num)).collect())
+            .collect();
+        // Strategies in the order the solver tries them, so that saving is deterministic
+        let rating = std::iter::once(Strategy::None)
+            .chain(Strategy::ALL)
+            .filter_map(|strategy| {
+                let count = *sudoku.rating.get(&strategy)?;
+                Some((strategy, count))
+            })
+            .collect();
+        Position {
+            board: sudoku.serialized(),
+            notes,
+            rating,
+        }
+    }
+
+    /// Set the digits, notes and rating of the Sudoku to those of the position, after
+    /// checking them against its grid size and givens.
+    fn restore(self, sudoku: &mut Sudoku) -> Result<(), SudokuError> {
+        let spec = sudoku.spec;
+        let size = spec.size();
+        let board = self.board.parse::<Board>().map_err(SudokuError::Parse)?;
+        if board.spec != spec {
+            return Err(SudokuError::InvalidBoard(format!(
+                "The board has {} cells instead of {}",
+                board.spec.cell_count(),
+                spec.cell_count()
+            )));
+        }
+        for (row, (values, givens)) in board.values.iter().zip(&sudoku.original_board).enumerate() {
+            for (col, (&num, &given)) in values.iter().zip(givens).enumerate() {
+                if given != EMPTY && num != given {
+                    return Err(SudokuError::InvalidBoard(format!(
+                        "r{}c{} holds {} instead of the given {}",
+                        row + 1,
+                        col + 1,
+                        spec.digit_char(num),
+                        spec.digit_char(given)
+                    )));
+                }
+            }
+        }
+        if self.notes.len() != spec.cell_count() {
+            return Err(SudokuError::Parse(format!(
+                "Expected notes for {} cells, found {}",
+                spec.cell_count(),
+                self.notes.len()
+            )));
+        }
+        let mut candidates = vec![vec![DigitSet::new(); size]; size];
+        for (idx, notes) in self.notes.iter().enumerate() {
+            for c in notes.chars() {
+                match spec.parse_cell(c) {
+                    Some(num) if num != EMPTY => {
+                        candidates[idx / size][idx % size].insert(num);
+                    }
+                    _ => return Err(SudokuError::Parse(format!("Invalid note '{}'", c))),
+                }
+            }
+        }
+        sudoku.board = board.values;
+        sudoku.candidates = candidates;
+        sudoku.rating = self.rating.into_iter().collect();
+        Ok(())
+    }
+}
+
+impl Sudoku {
+    /// Save the givens, digits, notes, undo history and rating so far as a session.
+    ///
+    /// A session is a JSON document holding the givens, the current position and the
+    /// positions the undo history leads back to. Each position has the digits, serialized
+    /// like [`Sudoku::serialized`], the notes of each cell in row-major order as a string
+    /// of digits, and the rating so far:
+    ///
+    /// ```json
+    /// {
+    ///   "version": 1,
+    ///   "givens": "017003009…",
+    ///   "position": { "board": "417003009…", "notes": ["", "", "", "2568", …], "rating": [["HiddenSingle", 1]] },
+    ///   "history": [ { "board": "017003009…", … } ]
+    /// }
+    /// ```
+    pub fn save_session(&self) -> String {
+        let session = Session {
+            version: SESSION_VERSION,
+            givens: self.original_board(),
+            position: Position::of(self),
+            history: self.undo_stack.iter().map(Position::of).collect(),
+        };
+        serde_json::to_string_pretty(&session).expect("sessions serialize to JSON")
+    }
+
+    /// Restore a session saved by [`Sudoku::save_session`], so that solving and undoing
+    /// continue where they left off. Variant clues of this Sudoku are kept if the size
+    /// of the grid doesn't change; otherwise it's reset like by
+    /// [`Sudoku::set_board_string`]. On error, the Sudoku is left unchanged.
+    pub fn load_session(&mut self, session: &str) -> Result<(), SudokuError> {
+        let session: Session = serde_json::from_str(session)
+            .map_err(|e| SudokuError::Parse(format!("Invalid session: {}", e)))?;
+        if session.version != SESSION_VERSION {
+            return Err(SudokuError::Parse(format!(
+                "Unsupported session version {}",
+                session.version
+            )));
+        }
+        let givens = session
+            .givens
+            .parse::<Board>()
+            .map_err(SudokuError::Parse)?;
+        let spec = givens.spec;
+        let mut sudoku = if spec == self.spec {
+            self.clone()
+        } else {
+            Sudoku::with_spec(spec)
+        };
+        sudoku.original_board = givens.values;
+        sudoku.undo_stack = Vec::new();
+        let mut history = Vec::with_capacity(session.history.len());
+        for position in session.history {
+            let mut state = sudoku.clone();
+            position.restore(&mut state)?;
+            history.push(state);
+        }
+        session.position.restore(&mut sudoku)?;
+        sudoku.undo_stack = history;
+        *self = sudoku;
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+//! Snapshots of the board and the differences between them, e.g. to highlight what
+//! a solver step changed.
+
+use crate::io::{frame_line, frame_line_below};
+use crate::{Candidate, Cell, DigitSet, EMPTY, GridSpec, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+const ANSI_PLACED: &str = "\x1b[1;32m";
+const ANSI_ADDED: &str = "\x1b[32m";
+const ANSI_REMOVED: &str = "\x1b[9;31m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Digits and candidates of all cells at some point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BoardState {
+    pub board: Vec<Vec<u8>>,
+    pub candidates: Vec<Vec<DigitSet>>,
+}
+
+/// Changes between two board states, each list in row-major order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BoardDiff {
+    /// Digits placed in previously empty cells
+    pub placed: Vec<Cell>,
+    /// Digits removed from cells, e.g. by undoing a step
+    pub cleared: Vec<Cell>,
+    pub removed_candidates: Vec<Candidate>,
+    pub added_candidates: Vec<Candidate>,
+}
+
+impl BoardDiff {
+    pub fn is_empty(&self) -> bool {
+        self.placed.is_empty()
+            && self.cleared.is_empty()
+            && self.removed_candidates.is_empty()
+            && self.added_candidates.is_empty()
+    }
+}
+
+/// The changes as lists, one line per kind of change, e.g. `Placed: r3c5=7`.
+impl fmt::Display for BoardDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn list<T: fmt::Display>(f: &mut fmt::Formatter, label: &str, items: &[T]) -> fmt::Result {
+            if items.is_empty() {
+                return Ok(());
+            }
+            let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+            writeln!(f, "{}: {}", label, items.join(", "))
+        }
+        list(f, "Placed", &self.placed)?;
+        list(f, "Cleared", &self.cleared)?;
+        list(f, "Removed", &self.removed_candidates)?;
+        list(f, "Added", &self.added_candidates)
+    }
+}
+
+impl BoardState {
+    /// What changed from `before` to this state. Both states must be of the same size.
+    pub fn diff(&self, before: &BoardState) -> BoardDiff {
+        let mut diff = BoardDiff::default();
+        for (row, (cells, cells_before)) in self.board.iter().zip(&before.board).enumerate() {
+            for (col, (&num, &num_before)) in cells.iter().zip(cells_before).enumerate() {
+                if num == num_before {
+                    continue;
+                }
+                if num_before != EMPTY {
+                    diff.cleared.push(Cell {
+                        row,
+                        col,
+                        num: num_before,
+                    });
+                }
+                if num != EMPTY {
+                    diff.placed.push(Cell { row, col, num });
+                }
+            }
+        }
+        for (row, (notes, notes_before)) in
+            self.candidates.iter().zip(&before.candidates).enumerate()
+        {
+            for (col, (notes, notes_before)) in notes.iter().zip(notes_before).enumerate() {
+                diff.removed_candidates.extend(
+                    notes_before
+                        .difference(notes)
+                        .iter()
+                        .map(|num| Candidate { row, col, num }),
+                );
+                diff.added_candidates.extend(
+                    notes
+                        .difference(notes_before)
+                        .iter()
+                        .map(|num| Candidate { row, col, num }),
+                );
+            }
+        }
+        diff
+    }
+}
+
+/// Multipliers of the two lanes of [`position_hash`], odd 64-bit constants with well
+/// mixed bits.
+const HASH_MULTIPLIERS: [u64; 2] = [0x9E37_79B9_7F4A_7C15, 0xC2B2_AE3D_27D4_EB4F];
+
+/// Hash the digit and the notes of each cell, in row-major order, with two independent
+/// multiply-rotate lanes, each finished with the SplitMix64 finalizer.
+fn position_hash<'a>(board: &'a [Vec<u8>], candidates: &'a [Vec<DigitSet>]) -> u128 {
+    let mut lanes = [0u64; 2];
+    for (row, notes) in board.iter().zip(candidates) {
+        for (&num, notes) in row.iter().zip(notes) {
+            let cell = u64::from(num) << 32 | u64::from(notes.bits());
+            for (lane, multiplier) in lanes.iter_mut().zip(HASH_MULTIPLIERS) {
+                *lane = (lane.rotate_left(5) ^ cell).wrapping_mul(multiplier);
+            }
+        }
+    }
+    let [high, low] = lanes.map(|mut z| {
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    });
+    u128::from(high) << 64 | u128::from(low)
+}
+
+impl BoardState {
+    /// A 128-bit hash of the digits and notes, see [`Sudoku::position_hash`].
+    pub fn position_hash(&self) -> u128 {
+        position_hash(&self.board, &self.candidates)
+    }
+}
+
+impl Sudoku {
+    pub fn snapshot(&self) -> BoardState {
+        BoardState {
+            board: self.board.clone(),
+            candidates: self.candidates.clone(),
+        }
+    }
+
+    /// What changed since the snapshot was taken.
+    pub fn diff(&self, before: &BoardState) -> BoardDiff {
+        self.snapshot().diff(before)
+    }
+
+    /// A fast 128-bit hash of the digits and notes, e.g. to recognize a position that
+    /// was reached before in another order of steps. Only the position counts: the
+    /// givens, the rules and the rating don't. The hash may change between versions.
+    pub fn position_hash(&self) -> u128 {
+        position_hash(&self.board, &self.candidates)
+    }
+}
+
+/// The state `after` as a framed grid with the notes of each empty cell, like
+/// [`Sudoku::dump_notes`], marking what changed since `before`: digits placed since are
+/// shown as `*5*`, removed candidates as `-` and added ones as `+`. The changes follow
+/// as lists, see [`BoardDiff`]. Both states must be of the same size.
+pub fn render_diff(before: &BoardState, after: &BoardState) -> String {
+    render(before, after, false)
+}
+
+/// Like [`render_diff`], but for terminals: digits placed since are shown in bold
+/// green, removed candidates struck through in red and added ones in green.
+pub fn render_diff_ansi(before: &BoardState, after: &BoardState) -> String {
+    render(before, after, true)
+}
+
+fn render(before: &BoardState, after: &BoardState, ansi: bool) -> String {
+    let size = after.board.len();
+    let spec = GridSpec::from_cell_count(size * size).expect("boards have a supported size");
+    let GridSpec { box_rows, box_cols } = spec;
+    let width = box_cols + 2;
+    let mut text = frame_line(spec, width, ('╔', '═', '╤', '╦', '╗'));
+    text.push('\n');
+    for row in 0..size {
+        for line in 0..box_rows {
+            text += "║ ";
+            for col in 0..size {
+                let num = after.board[row][col];
+                if num != EMPTY {
+                    let digit = spec.digit_char(num);
+                    let placed = before.board[row][col] != num;
+                    text += &match (line == box_rows / 2, placed, ansi) {
+                        (false, _, _) => " ".repeat(box_cols),
+                        (true, false, _) => format!("{:^1$}", digit, box_cols),
+                        (true, true, false) => format!("{:^1$}", format!("*{}*", digit), box_cols),
+                        (true, true, true) => {
+                            format!("{}{:^3$}{}", ANSI_PLACED, digit, ANSI_RESET, box_cols)
+                        }
+                    };
+                } else {
+                    let notes = after.candidates[row][col];
+                    let notes_before = before.candidates[row][col];
+                    for k in 0..box_cols {
+                        let num = (box_cols * line + k + 1) as u8;
+                        let digit = spec.digit_char(num);
+                        match (notes.contains(&num), notes_before.contains(&num), ansi) {
+                            (true, true, _) => text.push(digit),
+                            (false, false, _) => text.push('.'),
+                            (false, true, false) => text.push('-'),
+                            (true, false, false) => text.push('+'),
+                            (false, true, true) => {
+                                text += &format!("{}{}{}", ANSI_REMOVED, digit, ANSI_RESET)
+                            }
+                            (true, false, true) => {
+                                text += &format!("{}{}{}", ANSI_ADDED, digit, ANSI_RESET)
+                            }
+                        }
+                    }
+                }
+                text += if (col + 1).is_multiple_of(box_cols) {
+                    " ║ "
+                } else {
+                    " │ "
+                };
+            }
+            text.truncate(text.trim_end().len());
+            text.push('\n');
+        }
+        text += &frame_line_below(spec, width, row);
+        text.push('\n');
+    }
+    text + &after.diff(before).to_string()
+}
@@ -0,0 +1,125 @@
+//! Lazy enumeration of all solutions by backtracking, optionally within a budget, and
+//! random solutions.
+
+use crate::{Budget, EMPTY, Sudoku};
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "rand")]
+use rand::seq::SliceRandom;
+use std::time::Instant;
+
+/// Iterator over the solutions of a Sudoku, see [`Sudoku::solutions`].
+#[derive(Debug, Clone)]
+pub struct Solutions {
+    sudoku: Sudoku,
+    /// Cells guessed so far, with the digits possible there and how many of them
+    /// have been tried
+    guesses: Vec<(usize, usize, Vec<u8>, usize)>,
+    started: bool,
+    /// The budget limiting the search, with its deadline
+    budget: Option<(Budget, Option<Instant>)>,
+    budget_exceeded: bool,
+}
+
+impl Solutions {
+    /// Stop the search once the time of the budget runs out or its token is
+    /// cancelled, see [`Solutions::budget_exceeded`]. Guesses aren't steps of the
+    /// solver, so the maximum number of steps doesn't apply.
+    pub fn within(self, budget: &Budget) -> Solutions {
+        let budget = Budget {
+            max_steps: None,
+            ..budget.clone()
+        };
+        let deadline = budget.deadline();
+        Solutions {
+            budget: Some((budget, deadline)),
+            ..self
+        }
+    }
+
+    /// Whether the search stopped because the budget ran out, so that the solutions
+    /// found may not be all.
+    pub fn budget_exceeded(&self) -> bool {
+        self.budget_exceeded
+    }
+}
+
+impl Iterator for Solutions {
+    type Item = Vec<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            match self.sudoku.most_constrained_cell() {
+                // Nothing to fill in
+                None => return Some(self.sudoku.board.clone()),
+                Some((row, col, nums)) => self.guesses.push((row, col, nums, 0)),
+            }
+        }
+        while let Some((row, col, nums, tried)) = self.guesses.last_mut() {
+            if let Some((budget, deadline)) = &self.budget
+                && budget.exceeded(0, *deadline).is_some()
+            {
+                self.budget_exceeded = true;
+                return None;
+            }
+            let (row, col) = (*row, *col);
+            let Some(&num) = nums.get(*tried) else {
+                // Backtrack
+                self.sudoku.board[row][col] = EMPTY;
+                self.guesses.pop();
+                continue;
+            };
+            *tried += 1;
+            self.sudoku.board[row][col] = num;
+            match self.sudoku.most_constrained_cell() {
+                None => return Some(self.sudoku.board.clone()),
+                Some((row, col, nums)) => self.guesses.push((row, col, nums, 0)),
+            }
+        }
+        None
+    }
+}
+
+impl Sudoku {
+    /// All boards completing this one, found one after the other by backtracking, e.g.
+    /// `sudoku.solutions().take(2)` to look at the solutions of an ambiguous puzzle.
+    pub fn solutions(&self) -> Solutions {
+        let mut sudoku = self.clone();
+        sudoku.undo_stack = Vec::new();
+        Solutions {
+            sudoku,
+            guesses: Vec::new(),
+            started: false,
+            budget: None,
+            budget_exceeded: false,
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Sudoku {
+    /// A random board completing this one, or `None` if there's no solution. The digits
+    /// are tried in random order while backtracking, so for an empty grid every valid
+    /// grid can come up, though not with exactly the same probability.
+    pub fn random_solution<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Vec<Vec<u8>>> {
+        let mut sudoku = self.clone();
+        sudoku.undo_stack = Vec::new();
+        sudoku.fill_randomly(rng).then_some(sudoku.board)
+    }
+
+    fn fill_randomly<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        let Some((row, col, mut nums)) = self.most_constrained_cell() else {
+            return true;
+        };
+        nums.shuffle(rng);
+        for num in nums {
+            self.board[row][col] = num;
+            if self.fill_randomly(rng) {
+                return true;
+            }
+        }
+        self.board[row][col] = EMPTY;
+        false
+    }
+}
@@ -0,0 +1,365 @@
+//! Running the human-like solver within limits, so that an expensive strategy can't
+//! keep a server request busy forever, and cancelling long operations.
+
+use crate::{Cell, Coord, EMPTY, Strategy, StrategyResult, Sudoku, Unit};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Shared flag to abort a long operation from another thread, e.g. when the user
+/// navigates away. Clones refer to the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Limits for the human-like solver. `None` means unlimited.
+#[derive(Debug, Clone, Default)]
+pub struct Budget {
+    /// Maximum number of strategy applications
+    pub max_steps: Option<usize>,
+    /// Maximum time to spend solving
+    pub max_duration: Option<Duration>,
+    /// Token to stop solving early
+    pub cancel: Option<CancelToken>,
+}
+
+impl Budget {
+    pub fn unlimited() -> Budget {
+        Budget::default()
+    }
+
+    /// When the time runs out, for a solve starting now. Only asks for the time if
+    /// needed: `Instant` isn't available everywhere.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.max_duration.map(|duration| Instant::now() + duration)
+    }
+
+    /// Why the solver must stop after `steps` steps, if it must.
+    pub(crate) fn exceeded(&self, steps: usize, deadline: Option<Instant>) -> Option<SolveOutcome> {
+        if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+            return Some(SolveOutcome::Cancelled);
+        }
+        if self.max_steps.is_some_and(|max_steps| steps >= max_steps)
+            || deadline.is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Some(SolveOutcome::BudgetExceeded);
+        }
+        None
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Budget {
+        Budget {
+            max_steps: Some(max_steps),
+            ..self
+        }
+    }
+
+    pub fn with_max_duration(self, max_duration: Duration) -> Budget {
+        Budget {
+            max_duration: Some(max_duration),
+            ..self
+        }
+    }
+
+    pub fn with_cancel(self, cancel: CancelToken) -> Budget {
+        Budget {
+            cancel: Some(cancel),
+            ..self
+        }
+    }
+}
+
+/// Proof that the position can't be solved anymore.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Contradiction {
+    /// The empty cell has no candidates left
+    EmptyCell(Coord),
+    /// The digit can go nowhere in the unit, which needs every digit
+    MissingDigit { unit: Unit, index: usize, num: u8 },
+    /// Backtracking found no solution at all
+    NoSolution,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Contradiction::EmptyCell(coord) => write!(f, "{} has no candidates left", coord),
+            Contradiction::MissingDigit { unit, index, num } => {
+                write!(f, "{} has no place left in {} {}", num, unit, index + 1)
+            }
+            Contradiction::NoSolution => write!(f, "No solution"),
+        }
+    }
+}
+
+impl std::error::Error for Contradiction {}
+
+/// How a solving attempt ended.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum SolveOutcome {
+    /// All cells are filled
+    Solved,
+    /// None of the strategies can make progress, with the given number of cells
+    /// still empty
+    StuckAt(usize),
+    /// The solver ran out of steps or time. The board and the rating reflect the
+    /// progress made so far.
+    BudgetExceeded,
+    /// The operation was cancelled through its [`CancelToken`]. As with an exceeded
+    /// budget, the progress made so far is kept.
+    Cancelled,
+    /// The position can't be solved, e.g. because a wrong digit was placed before
+    Contradiction(Contradiction),
+    /// Backtracking found more than one solution. The board holds the first one.
+    MultipleSolutions,
+}
+
+impl SolveOutcome {
+    pub fn is_solved(&self) -> bool {
+        *self == SolveOutcome::Solved
+    }
+}
+
+/// Callbacks of the human-like solver, e.g. to show progress or log the steps. All
+/// methods do nothing by default, so an observer implements only those it needs.
+pub trait SolveObserver {
+    /// Called after each step has been applied, with the position afterwards.
+    fn on_step(&mut self, _sudoku: &Sudoku, _step: &StrategyResult) {}
+
+    /// Called after a step placed a digit, right before [`SolveObserver::on_step`].
+    fn on_placement(&mut self, _cell: &Cell) {}
+
+    /// Called when the position turns out to be unsolvable, before the solver stops.
+    fn on_contradiction(&mut self, _contradiction: &Contradiction) {}
+}
+
+/// The observer ignoring everything.
+impl SolveObserver for () {}
+
+/// Prints the board and the notes after every step.
+#[cfg(feature = "dump")]
+struct DumpSteps;
+
+#[cfg(feature = "dump")]
+impl SolveObserver for DumpSteps {
+    fn on_step(&mut self, sudoku: &Sudoku, _step: &StrategyResult) {
+        sudoku.print();
+        sudoku.dump_notes();
+    }
+}
+
+impl Sudoku {
+    /// Look for an empty cell without candidates, or a digit that has no place left
+    /// in a unit that must contain every digit. Expects the notes to be up to date.
+    pub fn find_contradiction(&self) -> Option<Contradiction> {
+        let size = self.size();
+        if let Some(cell) = self
+            .empty_cells()
+            .find(|cell| self.candidates[cell.row][cell.col].is_empty())
+        {
+            return Some(Contradiction::EmptyCell(cell));
+        }
+        for group in self.complete_groups() {
+            for num in 1..=size as u8 {
+                let possible = group.cells.iter().any(|&(row, col)| {
+                    self.board[row][col] == num
+                        || (self.board[row][col] == EMPTY
+                            && self.candidates[row][col].contains(&num))
+                });
+                if !possible {
+                    return Some(Contradiction::MissingDigit {
+                        unit: group.unit,
+                        index: group.index,
+                        num,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Solve with human-like strategies like [`Sudoku::solve_human_like`], but stop
+    /// once the budget is used up.
+    pub fn solve_human_like_within(&mut self, budget: &Budget) -> SolveOutcome {
+        self.solve_human_like_observed(budget, &mut ())
+    }
+
+    /// Solve like [`Sudoku::solve_human_like_within`], telling the observer about
+    /// every step.
+    pub fn solve_human_like_observed(
+        &mut self,
+        budget: &Budget,
+        observer: &mut dyn SolveObserver,
+    ) -> SolveOutcome {
+        let deadline = budget.deadline();
+        // The first step always is to calculate the notes
+        self.calc_all_notes();
+        // Since we're starting from scratch, we clear the rating
+        self.rating.clear();
+        self.solve_from_here(budget, deadline, observer, &mut Sudoku::next_step)
+    }
+
+    /// Solve with human-like strategies like [`Sudoku::solve_human_like`], but start from
+    /// the current notes instead of calculating them, e.g. for a position read with
+    /// [`Sudoku::from_pencilmarks`]. The rating covers the steps from here on.
+    pub fn solve_from_notes(&mut self) -> SolveOutcome {
+        self.rating.clear();
+        self.solve_from_here(&Budget::unlimited(), None, &mut (), &mut Sudoku::next_step)
+    }
+
+    /// Take steps found with `find_step` from the current notes until the Sudoku is
+    /// solved, stuck or out of budget, adding them to the rating.
+    pub(crate) fn solve_from_here(
+        &mut self,
+        budget: &Budget,
+        deadline: Option<Instant>,
+        observer: &mut dyn SolveObserver,
+        find_step: &mut dyn FnMut(&mut Sudoku) -> StrategyResult,
+    ) -> SolveOutcome {
+        let mut steps = 0;
+        while self.unsolved() {
+            if let Some(contradiction) = self.find_contradiction() {
+                observer.on_contradiction(&contradiction);
+                return SolveOutcome::Contradiction(contradiction);
+            }
+            if let Some(outcome) = budget.exceeded(steps, deadline) {
+                return outcome;
+            }
+            let result = find_step(self);
+            if result.strategy == Strategy::None {
+                return SolveOutcome::StuckAt(self.empty_cells().count());
+            }
+            self.apply(&result)
+                .expect("steps found on the board fit it");
+            if let Some(cell) = &result.removals.sets_cell {
+                observer.on_placement(cell);
+            }
+            observer.on_step(self, &result);
+            steps += 1;
+        }
+        SolveOutcome::Solved
+    }
+}
+
+impl Sudoku {
+    /// Find the empty cell with the fewest digits that can be placed in it, together
+    /// with these digits. Returns `None` if there's no empty cell left.
+    pub(crate) fn most_constrained_cell(&self) -> Option<(usize, usize, Vec<u8>)> {
+        let size = self.size();
+        let mut best: Option<(usize, usize, Vec<u8>)> = None;
+        for Coord { row, col } in self.empty_cells() {
+            let nums: Vec<u8> = (1..=size as u8)
+                .filter(|&num| self.can_place(row, col, num))
+                .collect();
+            if best
+                .as_ref()
+                .is_none_or(|(_, _, best_nums)| nums.len() < best_nums.len())
+            {
+                let dead_end = nums.is_empty();
+                best = Some((row, col, nums));
+                if dead_end {
+                    return best;
+                }
+            }
+        }
+        best
+    }
+
+    /// Fill the board with a solution found by backtracking. The search goes on until
+    /// a second solution turns up or the first one is proven unique.
+    pub fn solve_by_backtracking(&mut self) -> SolveOutcome {
+        let mut solutions = self.solutions();
+        let Some(board) = solutions.next() else {
+            return SolveOutcome::Contradiction(Contradiction::NoSolution);
+        };
+        let unique = solutions.next().is_none();
+        self.board = board;
+        if unique {
+            SolveOutcome::Solved
+        } else {
+            SolveOutcome::MultipleSolutions
+        }
+    }
+
+    /// Count the solutions of the Sudoku by backtracking, stopping as soon as
+    /// `max_count` solutions have been found.
+    pub fn count_solutions(&self, max_count: usize) -> usize {
+        self.solutions().take(max_count).count()
+    }
+
+    /// Place digits by last digits, obvious singles and hidden singles only, until
+    /// none is left, and return the number of digits placed. This fast-forwards the
+    /// trivial stretches of a puzzle, e.g. for an "auto-fill" button, and works on the
+    /// current notes, which need to be complete. The steps count towards the rating
+    /// only if `record_rating` is set; each of them can be undone with
+    /// [`Sudoku::undo`].
+    pub fn fill_singles(&mut self, record_rating: bool) -> usize {
+        let rating = self.rating.clone();
+        let mut placed = 0;
+        while self.unsolved() {
+            let Some(result) = [
+                Sudoku::find_last_digit,
+                Sudoku::find_obvious_single,
+                Sudoku::find_hidden_single,
+            ]
+            .into_iter()
+            .map(|find| find(self))
+            .find(|result| result.removals.sets_cell.is_some()) else {
+                break;
+            };
+            self.rate_step(&result);
+            self.apply(&result)
+                .expect("steps found on the board fit it");
+            placed += 1;
+        }
+        if !record_rating {
+            self.rating = rating;
+        }
+        placed
+    }
+
+    pub fn solve_human_like(&mut self) -> SolveOutcome {
+        self.solve_human_like_within(&Budget::unlimited())
+    }
+
+    #[cfg(feature = "dump")]
+    pub fn solve_puzzle(&mut self) {
+        let outcome = self.solve_human_like_observed(&Budget::unlimited(), &mut DumpSteps);
+        println!();
+        self.print();
+        match outcome {
+            SolveOutcome::Solved => println!("\n**** SUDOKU SOLVED ****\n"),
+            SolveOutcome::Contradiction(contradiction) => {
+                println!("\n**** CONTRADICTION: {} ****\n", contradiction);
+                self.dump_notes();
+            }
+            _ => {
+                println!("\n**** SUDOKU NOT SOLVED ****\n");
+                self.dump_notes();
+                if let Some(report) = self.stuck_report() {
+                    println!();
+                    print!("{}", report);
+                }
+            }
+        }
+        self.dump_rating();
+    }
+}
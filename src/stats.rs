@@ -0,0 +1,294 @@
+//! Statistics over a collection of rated puzzles, e.g. to judge a generator or compare
+//! puzzle collections.
+
+use crate::calibrate::correlation;
+use crate::rating::hardest_technique;
+use crate::{DifficultyLevel, Error, Strategy, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+const LEVELS: [DifficultyLevel; 4] = [
+    DifficultyLevel::Easy,
+    DifficultyLevel::Medium,
+    DifficultyLevel::Hard,
+    DifficultyLevel::Expert,
+];
+
+/// Outcome of rating a single puzzle of a corpus.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RatedPuzzle {
+    pub puzzle: String,
+    pub givens: usize,
+    /// Whether the human-like solver solved the puzzle. The difficulty and the
+    /// strategies of unsolved puzzles cover the steps up to where the solver got stuck.
+    pub solved: bool,
+    pub difficulty: f64,
+    /// Number of candidates removed or cells set per strategy
+    pub strategies: BTreeMap<Strategy, usize>,
+}
+
+impl RatedPuzzle {
+    /// Solve the puzzle with the human-like solver and rate it. Fails with
+    /// [`Error::Parse`] if the puzzle can't be read.
+    pub fn rate(puzzle: &str) -> Result<RatedPuzzle, Error> {
+        let mut sudoku = puzzle.parse::<Sudoku>().map_err(Error::Parse)?;
+        let givens = sudoku.givens_count();
+        let solved = sudoku.solve_human_like().is_solved();
+        Ok(RatedPuzzle {
+            puzzle: puzzle.to_string(),
+            givens,
+            solved,
+            difficulty: sudoku.difficulty(),
+            strategies: sudoku.rating(),
+        })
+    }
+
+    /// The hardest strategy the solver used, if any.
+    pub fn hardest_technique(&self) -> Option<Strategy> {
+        hardest_technique(&self.strategies)
+    }
+
+    /// The rating as a single line of space-separated `key=value` fields after the
+    /// puzzle, for `grep` and `awk`, e.g.
+    /// `<puzzle> solved=yes difficulty=3.42 effort=140 hardest=X-Wing clues=27`. The
+    /// effort is that of the hardest strategy, whose name has its spaces replaced by
+    /// hyphens, or `none` without a step.
+    pub fn oneline(&self) -> String {
+        let hardest = self.hardest_technique();
+        format!(
+            "{} solved={} difficulty={:.2} effort={} hardest={} clues={}",
+            self.puzzle,
+            if self.solved { "yes" } else { "no" },
+            self.difficulty,
+            hardest.as_ref().map_or(0, Strategy::difficulty),
+            hardest.map_or("none".to_string(), |strategy| strategy
+                .to_string()
+                .replace(' ', "-")),
+            self.givens
+        )
+    }
+}
+
+/// Distributions over a corpus. Only the puzzles solved by the human-like solver are
+/// taken into account, except for the count of all puzzles. Complete grids, which have
+/// no difficulty, are left out as well.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CorpusStats {
+    pub puzzles: usize,
+    /// Number of puzzles the statistics are based on
+    pub rated: usize,
+    /// Number of puzzles per difficulty interval `[start, start + 1)`, by ascending start
+    pub difficulty_histogram: Vec<(f64, usize)>,
+    /// Number of puzzles per level
+    pub level_counts: Vec<(DifficultyLevel, usize)>,
+    /// Strategies used in the corpus, in the order the solver tries them
+    pub strategies: Vec<Strategy>,
+    /// Number of puzzles using each strategy, per level: `strategy_frequency[level][i]`
+    /// belongs to `strategies[i]`
+    pub strategy_frequency: Vec<(DifficultyLevel, Vec<usize>)>,
+    /// Pearson correlation between the number of givens and the difficulty
+    pub givens_correlation: f64,
+}
+
+impl CorpusStats {
+    pub fn new(rated: &[RatedPuzzle]) -> CorpusStats {
+        let solved: Vec<&RatedPuzzle> = rated
+            .iter()
+            .filter(|puzzle| puzzle.solved && puzzle.difficulty.is_finite())
+            .collect();
+        let mut histogram: Vec<(f64, usize)> = Vec::new();
+        for puzzle in &solved {
+            let start = puzzle.difficulty.floor();
+            match histogram.iter_mut().find(|(bin, _)| *bin == start) {
+                Some((_, count)) => *count += 1,
+                None => histogram.push((start, 1)),
+            }
+        }
+        histogram.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let level_counts = LEVELS
+            .iter()
+            .map(|&level| {
+                let count = solved
+                    .iter()
+                    .filter(|puzzle| DifficultyLevel::of(puzzle.difficulty) == level)
+                    .count();
+                (level, count)
+            })
+            .collect();
+        let strategies: Vec<Strategy> = Strategy::ALL
+            .iter()
+            .filter(|strategy| {
+                solved
+                    .iter()
+                    .any(|puzzle| puzzle.strategies.contains_key(strategy))
+            })
+            .cloned()
+            .collect();
+        let strategy_frequency = LEVELS
+            .iter()
+            .map(|&level| {
+                let at_level: Vec<&&RatedPuzzle> = solved
+                    .iter()
+                    .filter(|puzzle| DifficultyLevel::of(puzzle.difficulty) == level)
+                    .collect();
+                let counts = strategies
+                    .iter()
+                    .map(|strategy| {
+                        at_level
+                            .iter()
+                            .filter(|puzzle| puzzle.strategies.contains_key(strategy))
+                            .count()
+                    })
+                    .collect();
+                (level, counts)
+            })
+            .collect();
+        let givens: Vec<f64> = solved.iter().map(|puzzle| puzzle.givens as f64).collect();
+        let difficulties: Vec<f64> = solved.iter().map(|puzzle| puzzle.difficulty).collect();
+        CorpusStats {
+            puzzles: rated.len(),
+            rated: solved.len(),
+            difficulty_histogram: histogram,
+            level_counts,
+            strategies,
+            strategy_frequency,
+            givens_correlation: correlation(&givens, &difficulties),
+        }
+    }
+}
+
+impl fmt::Display for CorpusStats {
+    /// Summary tables for the command line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Puzzles: {}, rated: {}", self.puzzles, self.rated)?;
+        writeln!(f, "\nDifficulty   Puzzles")?;
+        let largest = self
+            .difficulty_histogram
+            .iter()
+            .map(|&(_, count)| count)
+            .max()
+            .unwrap_or_default();
+        for &(start, count) in &self.difficulty_histogram {
+            let bar = "#".repeat((count * 40).div_ceil(largest.max(1)));
+            writeln!(f, "{:>4}–{:<4} {:>8} {}", start, start + 1.0, count, bar)?;
+        }
+        write!(f, "\n{:<20}", "Strategy")?;
+        for (level, _) in &self.strategy_frequency {
+            write!(f, " {:>7}", level.to_string())?;
+        }
+        writeln!(f)?;
+        write!(f, "{:<20}", "(puzzles)")?;
+        for (_, count) in &self.level_counts {
+            write!(f, " {:>7}", count)?;
+        }
+        writeln!(f)?;
+        for (i, strategy) in self.strategies.iter().enumerate() {
+            write!(f, "{:<20}", strategy.to_string())?;
+            for (_, counts) in &self.strategy_frequency {
+                write!(f, " {:>7}", counts[i])?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(
+            f,
+            "\nCorrelation of givens and difficulty: {:.3}",
+            self.givens_correlation
+        )
+    }
+}
+
+/// How many puzzles of a corpus need a strategy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct StrategyCoverage {
+    pub strategy: Strategy,
+    /// Number of solved puzzles whose solve uses the strategy
+    pub used: usize,
+    /// Number of solved puzzles for which it's the hardest strategy needed
+    pub hardest: usize,
+}
+
+/// Which strategies the puzzles of a corpus need, and which puzzles the strategies
+/// don't solve, e.g. to decide which strategy to implement next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Coverage {
+    pub puzzles: usize,
+    /// Strategies used by any solved puzzle, in the order the solver tries them
+    pub strategies: Vec<StrategyCoverage>,
+    /// Puzzles the strategies don't solve, in the order of the corpus
+    pub unsolved: Vec<String>,
+}
+
+impl Coverage {
+    pub fn new(rated: &[RatedPuzzle]) -> Coverage {
+        let solved: Vec<&RatedPuzzle> = rated.iter().filter(|puzzle| puzzle.solved).collect();
+        let strategies = Strategy::ALL
+            .iter()
+            .map(|strategy| StrategyCoverage {
+                strategy: strategy.clone(),
+                used: solved
+                    .iter()
+                    .filter(|puzzle| puzzle.strategies.contains_key(strategy))
+                    .count(),
+                hardest: solved
+                    .iter()
+                    .filter(|puzzle| puzzle.hardest_technique().as_ref() == Some(strategy))
+                    .count(),
+            })
+            .filter(|coverage| coverage.used > 0)
+            .collect();
+        Coverage {
+            puzzles: rated.len(),
+            strategies,
+            unsolved: rated
+                .iter()
+                .filter(|puzzle| !puzzle.solved)
+                .map(|puzzle| puzzle.puzzle.clone())
+                .collect(),
+        }
+    }
+
+    /// The count as a share of all puzzles, from 0 to 1, or 0 without puzzles.
+    pub fn share(&self, count: usize) -> f64 {
+        if self.puzzles == 0 {
+            return 0.0;
+        }
+        count as f64 / self.puzzles as f64
+    }
+}
+
+impl fmt::Display for Coverage {
+    /// A table of the strategies in percent of all puzzles, followed by the unsolved
+    /// puzzles, one per line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Puzzles: {}, unsolved: {} ({:.1}%)",
+            self.puzzles,
+            self.unsolved.len(),
+            100.0 * self.share(self.unsolved.len())
+        )?;
+        writeln!(f, "\n{:<20} {:>7} {:>7}", "Strategy", "Used", "Hardest")?;
+        for coverage in &self.strategies {
+            writeln!(
+                f,
+                "{:<20} {:>6.1}% {:>6.1}%",
+                coverage.strategy.to_string(),
+                100.0 * self.share(coverage.used),
+                100.0 * self.share(coverage.hardest)
+            )?;
+        }
+        if !self.unsolved.is_empty() {
+            writeln!(f, "\nUnsolved:")?;
+            for puzzle in &self.unsolved {
+                writeln!(f, "{}", puzzle)?;
+            }
+        }
+        Ok(())
+    }
+}
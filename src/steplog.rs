@@ -0,0 +1,111 @@
+//! Machine-readable log of a solve, one JSON object per applied step, e.g. as training
+//! data or to compare solver runs while debugging.
+
+use crate::{Candidate, Cell, EMPTY, SolveObserver, Strategy, StrategyResult, Sudoku};
+use serde::Serialize;
+use std::io::{self, Write};
+use std::time::Instant;
+
+/// One line of the log.
+#[derive(Debug, Clone, Serialize)]
+pub struct StepRecord {
+    /// Number of the step, counted from 1
+    pub step: usize,
+    pub strategy: Strategy,
+    /// Digit placed by the step, if any
+    pub placed: Option<Cell>,
+    /// Cells forming the pattern of the step
+    pub cells: Vec<Cell>,
+    /// Candidates removed by the step, in row-major order
+    pub eliminations: Vec<Candidate>,
+    /// Hash of the digits and notes after the step, see [`Sudoku::state_hash`]
+    pub state_hash: u64,
+    /// Milliseconds since the log was created
+    pub elapsed_ms: f64,
+}
+
+/// Observer writing a [`StepRecord`] per step to `writer` as a JSON line. Pass it to
+/// [`Sudoku::solve_human_like_observed`], then call [`StepLog::finish`] to learn
+/// whether all lines were written.
+pub struct StepLog<W: Write> {
+    writer: W,
+    start: Instant,
+    steps: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> StepLog<W> {
+    pub fn new(writer: W) -> StepLog<W> {
+        StepLog {
+            writer,
+            start: Instant::now(),
+            steps: 0,
+            error: None,
+        }
+    }
+
+    /// Flush the writer and return it, or the first error that occurred while logging.
+    /// Logging stops at the first error.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write(&mut self, record: &StepRecord) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")
+    }
+}
+
+impl<W: Write> SolveObserver for StepLog<W> {
+    fn on_step(&mut self, sudoku: &Sudoku, step: &StrategyResult) {
+        self.steps += 1;
+        if self.error.is_some() {
+            return;
+        }
+        let removals = &step.removals;
+        let mut eliminations: Vec<Candidate> = removals
+            .candidates_about_to_be_removed
+            .iter()
+            .cloned()
+            .collect();
+        eliminations.sort_by_key(|candidate| (candidate.row, candidate.col, candidate.num));
+        let record = StepRecord {
+            step: self.steps,
+            strategy: step.strategy.clone(),
+            placed: removals.sets_cell.clone(),
+            cells: removals.cells_affected.to_vec(),
+            eliminations,
+            state_hash: sudoku.state_hash(),
+            elapsed_ms: 1e-3 * self.start.elapsed().as_micros() as f64,
+        };
+        if let Err(e) = self.write(&record) {
+            self.error = Some(e);
+        }
+    }
+}
+
+impl Sudoku {
+    /// Hash of the digits and notes, which stays the same across platforms and
+    /// versions of the crate (64-bit FNV-1a), so that logs can be compared.
+    pub fn state_hash(&self) -> u64 {
+        const PRIME: u64 = 0x100_0000_01b3;
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for (row, cells) in self.board.iter().enumerate() {
+            for (col, &num) in cells.iter().enumerate() {
+                let notes: u32 = if num == EMPTY {
+                    self.candidates[row][col].bits()
+                } else {
+                    0
+                };
+                for byte in [num].into_iter().chain(notes.to_le_bytes()) {
+                    hash = (hash ^ byte as u64).wrapping_mul(PRIME);
+                }
+            }
+        }
+        hash
+    }
+}
@@ -0,0 +1,599 @@
+//! The solving strategies, and the steps they report.
+
+use crate::analysis::{Hits, Search};
+use crate::{Candidate, Cell, Coord, EMPTY, House, Link, Locale, Sudoku, Unit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use smallvec::{SmallVec, smallvec};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::ControlFlow;
+
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Strategy {
+    None,
+    LastDigit,
+    ObviousSingle,
+    HiddenSingle,
+    ObviousPair,
+    HiddenPair,
+    ObviousTriple,
+    HiddenTriple,
+    ObviousQuad,
+    HiddenQuad,
+    PointingPair,
+    ClaimingPair,
+    XWing,
+    CageCombination,
+    InniesOuties,
+    LawOfLeftovers,
+    XV,
+    Kropki,
+    Thermometer,
+    Arrow,
+}
+
+impl Strategy {
+    /// All strategies except [`Strategy::None`], in the order the solver tries them.
+    pub const ALL: [Strategy; 19] = [
+        Strategy::LastDigit,
+        Strategy::ObviousSingle,
+        Strategy::HiddenSingle,
+        Strategy::XV,
+        Strategy::Kropki,
+        Strategy::Thermometer,
+        Strategy::Arrow,
+        Strategy::CageCombination,
+        Strategy::InniesOuties,
+        Strategy::PointingPair,
+        Strategy::ClaimingPair,
+        Strategy::LawOfLeftovers,
+        Strategy::ObviousPair,
+        Strategy::HiddenPair,
+        Strategy::ObviousTriple,
+        Strategy::HiddenTriple,
+        Strategy::ObviousQuad,
+        Strategy::XWing,
+        Strategy::HiddenQuad,
+    ];
+
+    pub(crate) fn to_string(&self) -> &'static str {
+        match self {
+            Strategy::None => "None",
+            Strategy::LastDigit => "Last Digit",
+            Strategy::ObviousSingle => "Obvious Single",
+            Strategy::HiddenSingle => "Hidden Single",
+            Strategy::PointingPair => "Pointing Pair",
+            Strategy::ClaimingPair => "Claiming Pair",
+            Strategy::ObviousPair => "Obvious Pair",
+            Strategy::HiddenPair => "Hidden Pair",
+            Strategy::ObviousTriple => "Obvious Triple",
+            Strategy::HiddenTriple => "Hidden Triple",
+            Strategy::ObviousQuad => "Obvious Quad",
+            Strategy::HiddenQuad => "Hidden Quad",
+            Strategy::XWing => "X-Wing",
+            Strategy::CageCombination => "Cage Combination",
+            Strategy::InniesOuties => "Innies & Outies",
+            Strategy::LawOfLeftovers => "Law of Leftovers",
+            Strategy::XV => "XV",
+            Strategy::Kropki => "Kropki Dots",
+            Strategy::Thermometer => "Thermometer",
+            Strategy::Arrow => "Arrow",
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    /// The English name in the default naming scheme, see [`Strategy::name_in`].
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+/// Changes a strategy is about to make. Strategies are probed many times while rating,
+/// and most steps involve a few cells in one or two units, so the lists keep that
+/// many elements inline instead of allocating.
+///
+/// Every step tells the pattern it found apart from what it changes: the pattern consists
+/// of `cells_affected` and `candidates_affected`, the changes of `sets_cell` and
+/// `candidates_about_to_be_removed`. Steps reasoning along a chain also list its
+/// `links`, in the order of the chain.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RemovalResult {
+    /// Cell the step places a digit in, if any
+    pub sets_cell: Option<Cell>,
+    /// Cells forming the pattern, with their digit or [`EMPTY`]. For placements, the
+    /// cell placed in.
+    pub cells_affected: SmallVec<[Cell; 1]>,
+    /// Candidates forming the pattern, which the step leaves in place. For placements,
+    /// the digit placed.
+    pub candidates_affected: SmallVec<[Candidate; 8]>,
+    /// Candidates the step eliminates, including the other candidates of a cell placed in
+    pub candidates_about_to_be_removed: BTreeSet<Candidate>,
+    pub unit: Option<Unit>,
+    pub unit_index: Option<SmallVec<[usize; 2]>>,
+    /// Inferences between the candidates of the pattern, for steps based on chains.
+    /// Empty for the other steps.
+    pub links: Vec<Link>,
+}
+
+impl RemovalResult {
+    fn empty() -> Self {
+        RemovalResult {
+            sets_cell: None,
+            cells_affected: SmallVec::new(),
+            candidates_affected: SmallVec::new(),
+            candidates_about_to_be_removed: BTreeSet::new(),
+            unit: None,
+            unit_index: None,
+            links: Vec::new(),
+        }
+    }
+    /// Add a candidate to the pattern, and its cell if it isn't part of it yet.
+    pub(crate) fn add_pattern_candidate(&mut self, candidate: Candidate) {
+        if !self
+            .cells_affected
+            .iter()
+            .any(|cell| (cell.row, cell.col) == (candidate.row, candidate.col))
+        {
+            self.cells_affected.push(Cell {
+                row: candidate.row,
+                col: candidate.col,
+                num: EMPTY,
+            });
+        }
+        self.candidates_affected.push(candidate);
+    }
+    pub(crate) fn will_remove_candidates(&self) -> bool {
+        !self.candidates_about_to_be_removed.is_empty()
+    }
+    fn clear(&mut self) {
+        self.sets_cell = None;
+        self.cells_affected.clear();
+        self.candidates_affected.clear();
+        self.candidates_about_to_be_removed.clear();
+        self.unit = None;
+        self.unit_index = None;
+        self.links.clear();
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct StrategyResult {
+    pub strategy: Strategy,
+    pub removals: RemovalResult,
+}
+
+impl StrategyResult {
+    pub(crate) fn new(strategy: Strategy) -> Self {
+        StrategyResult {
+            strategy,
+            removals: RemovalResult::empty(),
+        }
+    }
+    pub fn empty() -> Self {
+        StrategyResult {
+            strategy: Strategy::None,
+            removals: RemovalResult::empty(),
+        }
+    }
+    pub fn clear(&mut self) {
+        self.strategy = Strategy::None;
+        self.removals.clear();
+    }
+}
+
+#[derive(Debug)]
+pub struct Resolution {
+    pub nums_removed: usize,
+    pub strategy: Strategy,
+}
+
+impl Resolution {
+    #[allow(dead_code)]
+    pub fn nums_removed(&self) -> usize {
+        self.nums_removed
+    }
+    #[allow(dead_code)]
+    pub fn strategy(&self) -> Strategy {
+        self.strategy.clone()
+    }
+}
+
+impl Sudoku {
+    /// Check if there's a unit with a single empty cell left. If so, set the missing
+    /// digit there and remove it from the notes in all units containing the cell.
+    pub fn find_last_digit(&self) -> StrategyResult {
+        self.first_hit(Strategy::LastDigit, Sudoku::search_last_digit)
+    }
+
+    pub(crate) fn search_last_digit(&self, hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::LastDigit);
+        log::info!("Finding last digits");
+        for group in self.complete_groups() {
+            let empty_cells: Vec<&(usize, usize)> = group
+                .cells
+                .iter()
+                .filter(|&&(row, col)| self.board[row][col] == EMPTY)
+                .collect();
+            if empty_cells.len() != 1 {
+                continue;
+            }
+            let &(row, col) = empty_cells[0];
+            let missing_digits: Vec<u8> = (1..=self.size() as u8)
+                .filter(|&num| !group.cells.iter().any(|&(r, c)| self.board[r][c] == num))
+                .collect();
+            if missing_digits.len() != 1 {
+                continue;
+            }
+            result.removals = self.collect_set_num(missing_digits[0], row, col);
+            result.removals.unit = Some(group.unit);
+            result.removals.unit_index = Some(smallvec![group.index]);
+            hits.report(&mut result)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    pub fn find_obvious_single(&self) -> StrategyResult {
+        self.first_hit(Strategy::ObviousSingle, Sudoku::search_obvious_single)
+    }
+
+    pub(crate) fn search_obvious_single(&self, hits: &mut Hits) -> ControlFlow<()> {
+        for Coord { row, col } in self.empty_cells() {
+            if self.candidates[row][col].len() != 1 {
+                continue;
+            }
+            let num = self.candidates[row][col].first().unwrap();
+            log::info!("Found obvious single {} at ({}, {})", num, row, col);
+            hits.report(&mut StrategyResult {
+                strategy: Strategy::ObviousSingle,
+                removals: self.collect_set_num(num, row, col),
+            })?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Finds and resolves "hidden single" candidates in the Sudoku puzzle.
+    ///
+    /// A hidden single occurs when a digit can only go in one cell within a unit (row, column, box, …),
+    /// even though that cell may have multiple candidates.
+    ///
+    /// Returns the number of notes removed as a result of placing new digits.
+    pub fn find_hidden_single(&self) -> StrategyResult {
+        self.first_hit(Strategy::HiddenSingle, Sudoku::search_hidden_single)
+    }
+
+    pub(crate) fn search_hidden_single(&self, hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::HiddenSingle);
+        log::info!("Finding hidden singles");
+        let mut groups = self.complete_groups();
+        // Look at the boxes first
+        groups.sort_by_key(|group| group.unit != Unit::Box);
+        for group in groups {
+            for &(row, col) in &group.cells {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                for num in self.candidates[row][col] {
+                    let found = group.cells.iter().any(|&(check_row, check_col)| {
+                        (check_row, check_col) != (row, col)
+                            && self.candidates[check_row][check_col].contains(&num)
+                    });
+                    if !found {
+                        result.removals = self.collect_set_num(num, row, col);
+                        result.removals.unit = Some(group.unit.clone());
+                        result.removals.unit_index = Some(smallvec![group.index]);
+                        hits.report(&mut result)?;
+                    }
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Report `num` as locked into `cells`, which lie in both a box and `line`.
+    /// The digit must go into one of them, so it can be removed from the rest of `target`.
+    fn report_locked_candidates(
+        &self,
+        strategy: Strategy,
+        cells: &[(usize, usize)],
+        num: u8,
+        line: House,
+        target: House,
+        hits: &mut Hits,
+    ) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(strategy);
+        for (row, col) in self.cells_of(target) {
+            if !cells.contains(&(row, col)) && self.candidates[row][col].contains(&num) {
+                result
+                    .removals
+                    .candidates_about_to_be_removed
+                    .insert(Candidate { row, col, num });
+            }
+        }
+        if !result.removals.will_remove_candidates() {
+            return ControlFlow::Continue(());
+        }
+        for &(row, col) in cells {
+            result
+                .removals
+                .add_pattern_candidate(Candidate { row, col, num });
+        }
+        result.removals.unit = Some(line.unit());
+        result.removals.unit_index = Some(smallvec![line.index()]);
+        hits.report(&mut result)
+    }
+
+    /// Find a digit whose candidates in a row or column, two or three of them, all lie
+    /// in the same box. It can be removed from the other cells of that box.
+    pub fn find_claiming_pair(&self) -> StrategyResult {
+        self.first_hit(Strategy::ClaimingPair, Sudoku::search_claiming_pair)
+    }
+
+    pub(crate) fn search_claiming_pair(&self, hits: &mut Hits) -> ControlFlow<()> {
+        log::info!("Finding claiming pairs");
+        for line in self.lines() {
+            for num in 1..=self.size() as u8 {
+                let cells: Vec<(usize, usize)> = self
+                    .cells_of(line)
+                    .filter(|&(row, col)| self.candidates[row][col].contains(&num))
+                    .collect();
+                // A single candidate is a hidden single
+                if cells.len() < 2 {
+                    continue;
+                }
+                let (row, col) = cells[0];
+                let region = self.regions[row][col];
+                if cells.iter().any(|&(r, c)| self.regions[r][c] != region) {
+                    continue;
+                }
+                self.report_locked_candidates(
+                    Strategy::ClaimingPair,
+                    &cells,
+                    num,
+                    line,
+                    House::Box(region),
+                    hits,
+                )?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Find a digit whose candidates in a box, two or three of them, all lie in the same
+    /// row or column. It can be removed from the other cells of that line.
+    pub fn find_pointing_pair(&self) -> StrategyResult {
+        self.first_hit(Strategy::PointingPair, Sudoku::search_pointing_pair)
+    }
+
+    pub(crate) fn search_pointing_pair(&self, hits: &mut Hits) -> ControlFlow<()> {
+        for kind in [Unit::Row, Unit::Column] {
+            log::info!("Finding pointing pairs in {:?}s", kind);
+            for box_index in 0..self.size() {
+                for num in 1..=self.size() as u8 {
+                    let cells: Vec<(usize, usize)> = self
+                        .cells_of(House::Box(box_index))
+                        .filter(|&(row, col)| self.candidates[row][col].contains(&num))
+                        .collect();
+                    // A single candidate is a hidden single
+                    if cells.len() < 2 {
+                        continue;
+                    }
+                    let line = self.house_at(&kind, cells[0].0, cells[0].1);
+                    if !cells
+                        .iter()
+                        .all(|&(row, col)| self.house_contains(line, row, col))
+                    {
+                        continue;
+                    }
+                    self.report_locked_candidates(
+                        Strategy::PointingPair,
+                        &cells,
+                        num,
+                        line,
+                        line,
+                        hits,
+                    )?;
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Find and resolve X-Wing candidates.
+    /// An X-Wing occurs when a digit can only go in two rows and two columns, forming a rectangle.
+    /// In this case, the digit can be removed from all other cells in the same rows and columns.
+    pub fn find_xwing(&self) -> StrategyResult {
+        self.first_hit(Strategy::XWing, Sudoku::search_xwing)
+    }
+
+    pub(crate) fn search_xwing(&self, hits: &mut Hits) -> ControlFlow<()> {
+        for (kind, cross) in [(Unit::Row, Unit::Column), (Unit::Column, Unit::Row)] {
+            log::info!("Finding X-Wings in {:?}s", kind);
+            let lines: Vec<House> = self.lines().filter(|line| line.unit() == kind).collect();
+            let digits: Vec<u8> = (1..=self.size() as u8).collect();
+            hits.search_each(digits, |num, hits| self.xwing_of(num, &cross, &lines, hits))?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Find an X-Wing of the digit in two of the lines, crossed by lines of kind `cross`.
+    fn xwing_of(&self, num: u8, cross: &Unit, lines: &[House], hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::XWing);
+        // Crossing lines with candidate `num` in each line, e.g. the columns for a row
+        let crossings: Vec<Vec<House>> = lines
+            .iter()
+            .map(|&line| {
+                self.cells_of(line)
+                    .filter(|&(row, col)| self.candidates[row][col].contains(&num))
+                    .map(|(row, col)| self.house_at(cross, row, col))
+                    .collect()
+            })
+            .collect();
+        for (i, &line1) in lines.iter().enumerate() {
+            if crossings[i].len() != 2 {
+                continue;
+            }
+            // Find another line with the same crossing lines
+            for (j, &line2) in lines.iter().enumerate().skip(i + 1) {
+                if crossings[j] != crossings[i] {
+                    continue;
+                }
+                log::info!(
+                    "Found X-Wing {} in {:?} and {:?} at {:?}",
+                    num,
+                    line1,
+                    line2,
+                    crossings[i]
+                );
+                // Remove the candidate from other cells in the crossing lines
+                for &crossing in &crossings[i] {
+                    for (row, col) in self.cells_of(crossing) {
+                        if !self.house_contains(line1, row, col)
+                            && !self.house_contains(line2, row, col)
+                            && self.candidates[row][col].contains(&num)
+                        {
+                            result
+                                .removals
+                                .candidates_about_to_be_removed
+                                .insert(Candidate { row, col, num });
+                        }
+                    }
+                }
+                if result.removals.will_remove_candidates() {
+                    for line in [line1, line2] {
+                        for (row, col) in self.cells_of(line) {
+                            if self.candidates[row][col].contains(&num) {
+                                result
+                                    .removals
+                                    .add_pattern_candidate(Candidate { row, col, num });
+                            }
+                        }
+                    }
+                    // A loop of strong links along the lines and weak ones across them
+                    let corners = &result.removals.candidates_affected;
+                    let [a, b, c, d] = [0, 1, 3, 2].map(|i| corners[i].clone());
+                    result.removals.links = vec![
+                        Link::strong(a.clone(), b.clone()),
+                        Link::weak(b, c.clone()),
+                        Link::strong(c, d.clone()),
+                        Link::weak(d, a),
+                    ];
+                    result.removals.unit = Some(line1.unit());
+                    result.removals.unit_index = Some(smallvec![line1.index()]);
+                    hits.report(&mut result)?;
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Add the cells to the pattern of the step, along with the candidates the step
+    /// leaves in them.
+    pub(crate) fn add_pattern_cells(
+        &self,
+        removals: &mut RemovalResult,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+    ) {
+        for (row, col) in cells {
+            removals.cells_affected.push(Cell {
+                row,
+                col,
+                num: self.board[row][col],
+            });
+            for num in self.candidates[row][col] {
+                let candidate = Candidate { row, col, num };
+                if !removals.candidates_about_to_be_removed.contains(&candidate) {
+                    removals.candidates_affected.push(candidate);
+                }
+            }
+        }
+    }
+
+    /// Remove candidates from the notes in all units containing the cell where we've set a digit.
+    fn collect_candidates(&self, nums: &[u8], row: usize, col: usize) -> RemovalResult {
+        let mut result = RemovalResult::empty();
+        let cells = self
+            .groups()
+            .into_iter()
+            .filter(|group| group.contains(row, col))
+            .flat_map(|group| group.cells)
+            .chain(self.constraint_peers(row, col));
+        for (r, c) in cells {
+            for &num in nums {
+                if self.candidates[r][c].contains(&num) {
+                    result.candidates_about_to_be_removed.insert(Candidate {
+                        row: r,
+                        col: c,
+                        num,
+                    });
+                }
+            }
+        }
+        result
+    }
+
+    /// Collect all candidates that are about to be removed when setting a digit in a cell.
+    pub fn collect_set_num(&self, num: u8, row: usize, col: usize) -> RemovalResult {
+        let cell = Cell { row, col, num };
+        let removal_result = self.collect_candidates(&[num], row, col);
+        RemovalResult {
+            sets_cell: Some(cell.clone()),
+            cells_affected: smallvec![cell],
+            candidates_affected: smallvec![Candidate { row, col, num }],
+            candidates_about_to_be_removed: {
+                let mut candidates = removal_result.candidates_about_to_be_removed;
+                candidates.insert(Candidate { row, col, num });
+                for n in self.candidates[row][col] {
+                    if n != num {
+                        candidates.insert(Candidate { row, col, num: n });
+                    }
+                }
+                candidates
+            },
+            unit: None,
+            unit_index: None,
+            links: Vec::new(),
+        }
+    }
+
+    /// Undo the last step.
+    pub fn prev_step(&mut self) -> Resolution {
+        self.undo();
+        Resolution {
+            nums_removed: 0,
+            strategy: Strategy::None,
+        }
+    }
+
+    /// Find the next step to solve the Sudoku puzzle, trying the strategies in the
+    /// [`SearchOrder`](crate::SearchOrder) of the Sudoku.
+    pub fn next_step(&mut self) -> StrategyResult {
+        self.next_step_with(|sudoku, strategy, search| sudoku.first_hit(strategy, search))
+    }
+
+    /// Find the next step like [`Sudoku::next_step`], running each detector with
+    /// `run`, e.g. to time it.
+    pub(crate) fn next_step_with(
+        &mut self,
+        mut run: impl FnMut(&Sudoku, Strategy, Search) -> StrategyResult,
+    ) -> StrategyResult {
+        let search_order = self.search_order;
+        for (strategy, search) in search_order.searches() {
+            let result = run(self, strategy.clone(), search);
+            if result.removals.will_remove_candidates() {
+                self.rate_step(&result);
+                return StrategyResult {
+                    removals: result.removals,
+                    strategy,
+                };
+            }
+        }
+
+        StrategyResult::empty()
+    }
+}
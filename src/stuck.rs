@@ -0,0 +1,131 @@
+//! Explaining where the human-like solver gets stuck: what the position looks like,
+//! what was tried, and how few guesses would let the strategies finish the solve.
+
+use crate::{BoardState, Cell, Coord, SolveOutcome, Strategy, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Most guesses [`Sudoku::stuck_report`] looks for a path with.
+const MAX_PATH_GUESSES: usize = 2;
+
+/// The position in which the human-like solver got stuck, see [`Sudoku::stuck_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct StuckReport {
+    pub position: BoardState,
+    pub empty_cells: usize,
+    /// Candidates left in the empty cells
+    pub candidates: usize,
+    /// Empty cells with exactly two candidates
+    pub bivalue_cells: usize,
+    /// Strategies the solver tried in the position, in the order it tried them
+    pub strategies_tried: Vec<Strategy>,
+    /// Fewest digits of the solution to place after which the strategies solve the rest,
+    /// each placed where the solver got stuck after the previous one. `None` if the
+    /// puzzle has no unique solution or more than two guesses are needed.
+    pub shortest_path: Option<Vec<Cell>>,
+}
+
+/// A summary of the report on a few lines, without the position.
+impl fmt::Display for StuckReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Stuck with {} empty cells, {} candidates, {} bivalue cells",
+            self.empty_cells, self.candidates, self.bivalue_cells
+        )?;
+        let tried: Vec<String> = self
+            .strategies_tried
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        writeln!(f, "Tried: {}", tried.join(", "))?;
+        match &self.shortest_path {
+            Some(path) => {
+                let cells: Vec<String> = path.iter().map(ToString::to_string).collect();
+                writeln!(
+                    f,
+                    "Shortest path: {} ({} {})",
+                    cells.join(", "),
+                    path.len(),
+                    if path.len() == 1 { "guess" } else { "guesses" }
+                )
+            }
+            None => writeln!(f, "Shortest path: none within {} guesses", MAX_PATH_GUESSES),
+        }
+    }
+}
+
+impl Sudoku {
+    /// Solve a copy of the Sudoku like the human-like solver, and if the strategies get
+    /// stuck, describe the position. Returns `None` if the copy is solved or turns out
+    /// to contradict itself.
+    pub fn stuck_report(&self) -> Option<StuckReport> {
+        let mut stuck = self.clone();
+        stuck.undo_stack.clear();
+        let SolveOutcome::StuckAt(empty_cells) = stuck.solve_human_like() else {
+            return None;
+        };
+        stuck.undo_stack.clear();
+        let mut solutions = stuck.solutions();
+        let solution = solutions.next().filter(|_| solutions.next().is_none());
+        let mut dead_ends = BTreeSet::new();
+        let shortest_path = solution.and_then(|solution| {
+            (1..=MAX_PATH_GUESSES)
+                .find_map(|guesses| stuck.path_to(&solution, guesses, &mut dead_ends))
+        });
+        Some(StuckReport {
+            position: stuck.snapshot(),
+            empty_cells,
+            candidates: stuck.candidate_count(),
+            bivalue_cells: stuck.bivalue_cells().count(),
+            strategies_tried: stuck.search_order.strategies(),
+            shortest_path,
+        })
+    }
+
+    /// Find `guesses` digits of the solution to place one after the other, each followed
+    /// by solving from the notes, that solve the stuck position. Cells with fewer
+    /// candidates are tried first. Guessing the same digits in another order often
+    /// leads to the same position, so `dead_ends` remembers the positions, by their
+    /// hash, and the number of guesses that didn't solve them.
+    fn path_to(
+        &self,
+        solution: &[Vec<u8>],
+        guesses: usize,
+        dead_ends: &mut BTreeSet<(u128, usize)>,
+    ) -> Option<Vec<Cell>> {
+        if dead_ends.contains(&(self.position_hash(), guesses)) {
+            return None;
+        }
+        let mut cells: Vec<Coord> = self.empty_cells().collect();
+        cells.sort_by_key(|c| self.candidates[c.row][c.col].len());
+        for Coord { row, col } in cells {
+            let cell = Cell {
+                row,
+                col,
+                num: solution[row][col],
+            };
+            let mut next = self.clone();
+            if next.set_cell(row, col, cell.num).is_err() {
+                continue;
+            }
+            let outcome = next.solve_from_notes();
+            next.undo_stack.clear();
+            let rest = match outcome {
+                SolveOutcome::Solved => Some(Vec::new()),
+                SolveOutcome::StuckAt(_) if guesses > 1 => {
+                    next.path_to(solution, guesses - 1, dead_ends)
+                }
+                _ => None,
+            };
+            if let Some(rest) = rest {
+                return Some([vec![cell], rest].concat());
+            }
+        }
+        dead_ends.insert((self.position_hash(), guesses));
+        None
+    }
+}
@@ -0,0 +1,327 @@
+//! Obvious (naked) and hidden subsets of two to four cells of a unit, found by one
+//! engine for all sizes. A step is tagged with the strategy of its kind and size, e.g.
+//! [`Strategy::HiddenTriple`], which the rating counts separately.
+
+use crate::analysis::{Hits, SEARCHES};
+use crate::{Candidate, EMPTY, Group, Strategy, StrategyResult, Sudoku, Unit};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use smallvec::smallvec;
+use std::ops::ControlFlow;
+
+/// Smallest number of cells in a subset.
+pub const MIN_SUBSET_SIZE: usize = 2;
+/// Largest number of cells in a subset. Larger obvious subsets of a unit come with
+/// hidden ones of the remaining cells, and the other way round.
+pub const MAX_SUBSET_SIZE: usize = 4;
+
+/// How the cells of a subset are found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum SubsetKind {
+    /// As many cells as digits having no other candidates. The digits can be removed
+    /// from the other cells of the unit.
+    Obvious,
+    /// As many digits as cells fitting nowhere else in the unit. The other candidates
+    /// can be removed from the cells.
+    Hidden,
+}
+
+impl Strategy {
+    /// The strategy of the subsets of the kind with `size` cells, `None` for sizes
+    /// outside of [`MIN_SUBSET_SIZE`] to [`MAX_SUBSET_SIZE`].
+    pub fn subset(kind: SubsetKind, size: usize) -> Option<Strategy> {
+        match (kind, size) {
+            (SubsetKind::Obvious, 2) => Some(Strategy::ObviousPair),
+            (SubsetKind::Obvious, 3) => Some(Strategy::ObviousTriple),
+            (SubsetKind::Obvious, 4) => Some(Strategy::ObviousQuad),
+            (SubsetKind::Hidden, 2) => Some(Strategy::HiddenPair),
+            (SubsetKind::Hidden, 3) => Some(Strategy::HiddenTriple),
+            (SubsetKind::Hidden, 4) => Some(Strategy::HiddenQuad),
+            _ => None,
+        }
+    }
+
+    /// Kind and number of cells of a subset strategy, `None` for other strategies.
+    pub fn subset_kind(&self) -> Option<(SubsetKind, usize)> {
+        match self {
+            Strategy::ObviousPair => Some((SubsetKind::Obvious, 2)),
+            Strategy::ObviousTriple => Some((SubsetKind::Obvious, 3)),
+            Strategy::ObviousQuad => Some((SubsetKind::Obvious, 4)),
+            Strategy::HiddenPair => Some((SubsetKind::Hidden, 2)),
+            Strategy::HiddenTriple => Some((SubsetKind::Hidden, 3)),
+            Strategy::HiddenQuad => Some((SubsetKind::Hidden, 4)),
+            _ => None,
+        }
+    }
+}
+
+/// Call `visit` with the indices of every `size` of the `sets` whose union has exactly
+/// `size` elements, and the union, in lexicographic order of the indices. The sets are
+/// bit masks.
+fn locked_sets(
+    sets: &[u32],
+    size: usize,
+    visit: &mut impl FnMut(&[usize], u32) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    fn extend(
+        sets: &[u32],
+        size: usize,
+        start: usize,
+        picked: &mut Vec<usize>,
+        union: u32,
+        visit: &mut impl FnMut(&[usize], u32) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        if picked.len() == size {
+            return if union.count_ones() as usize == size {
+                visit(picked, union)
+            } else {
+                ControlFlow::Continue(())
+            };
+        }
+        for (i, &set) in sets.iter().enumerate().skip(start) {
+            if (union | set).count_ones() as usize > size {
+                continue;
+            }
+            picked.push(i);
+            let flow = extend(sets, size, i + 1, picked, union | set, visit);
+            picked.pop();
+            flow?;
+        }
+        ControlFlow::Continue(())
+    }
+    extend(sets, size, 0, &mut Vec::with_capacity(size), 0, visit)
+}
+
+impl Sudoku {
+    /// Find the first obvious or hidden subset with `size` cells that removes
+    /// candidates. Returns an empty result for sizes without a strategy, see
+    /// [`Strategy::subset`].
+    pub fn find_subset(&self, kind: SubsetKind, size: usize) -> StrategyResult {
+        let search = SEARCHES
+            .into_iter()
+            .find(|(strategy, _)| strategy.subset_kind() == Some((kind, size)));
+        match search {
+            Some((strategy, search)) => self.first_hit(strategy, search),
+            None => StrategyResult::empty(),
+        }
+    }
+
+    /// Find two cells of a unit with the same two candidates. These digits must go
+    /// into these two cells, so they can be removed from the other cells of the unit.
+    pub fn find_obvious_pair(&self) -> StrategyResult {
+        self.first_hit(Strategy::ObviousPair, Sudoku::search_obvious_pair)
+    }
+
+    /// Find three cells of a unit with only three digits between them as candidates.
+    /// These digits can be removed from the other cells of the unit.
+    pub fn find_obvious_triple(&self) -> StrategyResult {
+        self.first_hit(Strategy::ObviousTriple, Sudoku::search_obvious_triple)
+    }
+
+    /// Find four cells of a unit with only four digits between them as candidates.
+    /// These digits can be removed from the other cells of the unit.
+    pub fn find_obvious_quad(&self) -> StrategyResult {
+        self.first_hit(Strategy::ObviousQuad, Sudoku::search_obvious_quad)
+    }
+
+    /// Find two digits that can only go into the same two cells of a unit. All
+    /// other candidates can be removed from these two cells.
+    pub fn find_hidden_pair(&self) -> StrategyResult {
+        self.first_hit(Strategy::HiddenPair, Sudoku::search_hidden_pair)
+    }
+
+    /// Find three digits that can only go into the same three cells of a unit. All
+    /// other candidates can be removed from these cells.
+    pub fn find_hidden_triple(&self) -> StrategyResult {
+        self.first_hit(Strategy::HiddenTriple, Sudoku::search_hidden_triple)
+    }
+
+    /// Find four digits that can only go into the same four cells of a unit. All
+    /// other candidates can be removed from these cells.
+    pub fn find_hidden_quad(&self) -> StrategyResult {
+        self.first_hit(Strategy::HiddenQuad, Sudoku::search_hidden_quad)
+    }
+
+    pub(crate) fn search_obvious_pair(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_subsets(SubsetKind::Obvious, 2, hits)
+    }
+
+    pub(crate) fn search_obvious_triple(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_subsets(SubsetKind::Obvious, 3, hits)
+    }
+
+    pub(crate) fn search_obvious_quad(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_subsets(SubsetKind::Obvious, 4, hits)
+    }
+
+    pub(crate) fn search_hidden_pair(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_subsets(SubsetKind::Hidden, 2, hits)
+    }
+
+    pub(crate) fn search_hidden_triple(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_subsets(SubsetKind::Hidden, 3, hits)
+    }
+
+    pub(crate) fn search_hidden_quad(&self, hits: &mut Hits) -> ControlFlow<()> {
+        self.search_subsets(SubsetKind::Hidden, 4, hits)
+    }
+
+    /// Obvious subsets are looked for in all units, as the digits of any unit differ.
+    /// Hidden subsets need units that contain every digit, and are looked for in the
+    /// boxes first.
+    fn search_subsets(&self, kind: SubsetKind, size: usize, hits: &mut Hits) -> ControlFlow<()> {
+        let Some(strategy) = Strategy::subset(kind, size) else {
+            return ControlFlow::Continue(());
+        };
+        log::info!("Finding {}s", strategy.to_string().to_lowercase());
+        match kind {
+            SubsetKind::Obvious => hits.search_each(self.groups(), |group, hits| {
+                self.obvious_subset_in(&group, size, strategy.clone(), hits)
+            }),
+            SubsetKind::Hidden => {
+                let mut groups = self.complete_groups();
+                groups.sort_by_key(|group| group.unit != Unit::Box);
+                hits.search_each(groups, |group, hits| {
+                    self.hidden_subset_in(&group, size, strategy.clone(), hits)
+                })
+            }
+        }
+    }
+
+    /// Cells of the group with two to `size` candidates, whose candidates together are
+    /// `size` digits.
+    fn obvious_subset_in(
+        &self,
+        group: &Group,
+        size: usize,
+        strategy: Strategy,
+        hits: &mut Hits,
+    ) -> ControlFlow<()> {
+        let cells: Vec<(usize, usize)> = group
+            .cells
+            .iter()
+            .copied()
+            .filter(|&(row, col)| {
+                self.board[row][col] == EMPTY
+                    && (MIN_SUBSET_SIZE..=size).contains(&self.candidates[row][col].len())
+            })
+            .collect();
+        let masks: Vec<u32> = cells
+            .iter()
+            .map(|&(row, col)| digit_mask(self.candidates[row][col].iter()))
+            .collect();
+        let mut result = StrategyResult::new(strategy);
+        locked_sets(&masks, size, &mut |picked, digits| {
+            let subset: Vec<(usize, usize)> = picked.iter().map(|&i| cells[i]).collect();
+            for &(row, col) in &group.cells {
+                if subset.contains(&(row, col)) {
+                    continue;
+                }
+                for num in self.candidates[row][col] {
+                    if digits & 1 << num != 0 {
+                        result
+                            .removals
+                            .candidates_about_to_be_removed
+                            .insert(Candidate { row, col, num });
+                    }
+                }
+            }
+            if !result.removals.will_remove_candidates() {
+                return ControlFlow::Continue(());
+            }
+            log::info!(
+                "{} {:?} in {:?}",
+                result.strategy,
+                digits_of(digits),
+                subset
+            );
+            for &(row, col) in &subset {
+                for num in self.candidates[row][col] {
+                    result
+                        .removals
+                        .add_pattern_candidate(Candidate { row, col, num });
+                }
+            }
+            result.removals.unit = Some(group.unit.clone());
+            result.removals.unit_index = Some(smallvec![group.index]);
+            hits.report(&mut result)
+        })
+    }
+
+    /// Digits missing from the group that fit into two to `size` of its cells, and
+    /// together into `size` cells.
+    fn hidden_subset_in(
+        &self,
+        group: &Group,
+        size: usize,
+        strategy: Strategy,
+        hits: &mut Hits,
+    ) -> ControlFlow<()> {
+        let empty: Vec<(usize, usize)> = group
+            .cells
+            .iter()
+            .copied()
+            .filter(|&(row, col)| self.board[row][col] == EMPTY)
+            .collect();
+        let mut digits = Vec::new();
+        let mut masks = Vec::new();
+        for num in 1..=self.size() as u8 {
+            let places = digit_mask(
+                empty
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &(row, col))| self.candidates[row][col].contains(&num))
+                    .map(|(i, _)| i as u8),
+            );
+            if (MIN_SUBSET_SIZE..=size).contains(&(places.count_ones() as usize)) {
+                digits.push(num);
+                masks.push(places);
+            }
+        }
+        let mut result = StrategyResult::new(strategy);
+        locked_sets(&masks, size, &mut |picked, places| {
+            let nums: Vec<u8> = picked.iter().map(|&i| digits[i]).collect();
+            let subset: Vec<(usize, usize)> = digits_of(places)
+                .into_iter()
+                .map(|i| empty[i as usize])
+                .collect();
+            for &(row, col) in &subset {
+                for num in self.candidates[row][col] {
+                    if !nums.contains(&num) {
+                        result
+                            .removals
+                            .candidates_about_to_be_removed
+                            .insert(Candidate { row, col, num });
+                    }
+                }
+            }
+            if !result.removals.will_remove_candidates() {
+                return ControlFlow::Continue(());
+            }
+            log::info!("{} {:?} in {:?}", result.strategy, nums, subset);
+            for &(row, col) in &subset {
+                for &num in &nums {
+                    if self.candidates[row][col].contains(&num) {
+                        result
+                            .removals
+                            .add_pattern_candidate(Candidate { row, col, num });
+                    }
+                }
+            }
+            result.removals.unit = Some(group.unit.clone());
+            result.removals.unit_index = Some(smallvec![group.index]);
+            hits.report(&mut result)
+        })
+    }
+}
+
+/// Bit mask with the bits of the numbers set.
+fn digit_mask(nums: impl Iterator<Item = u8>) -> u32 {
+    nums.fold(0, |mask, num| mask | 1 << num)
+}
+
+/// Numbers whose bits are set in the mask, in ascending order.
+fn digits_of(mask: u32) -> Vec<u8> {
+    (0..32).filter(|&num| mask & 1 << num != 0).collect()
+}
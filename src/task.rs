@@ -0,0 +1,126 @@
+//! Running the solver on a worker thread, so that a slow puzzle doesn't block the
+//! thread of an async runtime or a user interface.
+
+use crate::{Board, CancelToken, Error, Rating, Solution, Solver};
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+struct Shared<T> {
+    /// The output, or the panic of the work
+    output: Option<std::thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// Work running on its own thread. The output is obtained by awaiting the task from
+/// any async runtime, or by blocking on [`Task::wait`]. If the work panics, the panic
+/// is passed on to the awaiting or waiting thread. Dropping the task before the output
+/// is ready cancels the work.
+pub struct Task<T> {
+    shared: Arc<(Mutex<Shared<T>>, Condvar)>,
+    cancel: CancelToken,
+}
+
+impl<T: Send + 'static> Task<T> {
+    /// Run `work` on a new thread. `cancel` is triggered by [`Task::cancel`], and
+    /// should make `work` return early.
+    pub fn spawn(cancel: CancelToken, work: impl FnOnce() -> T + Send + 'static) -> Task<T> {
+        let shared = Arc::new((
+            Mutex::new(Shared {
+                output: None,
+                waker: None,
+            }),
+            Condvar::new(),
+        ));
+        let worker = Arc::clone(&shared);
+        std::thread::spawn(move || {
+            let output = panic::catch_unwind(AssertUnwindSafe(work));
+            let (lock, done) = &*worker;
+            let mut state = lock.lock().unwrap();
+            state.output = Some(output);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+            done.notify_all();
+        });
+        Task { shared, cancel }
+    }
+
+    /// Ask the work to stop early. It still produces an output, e.g. a rating of the
+    /// steps taken so far.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Whether the output is ready, so that awaiting or waiting won't block.
+    pub fn is_finished(&self) -> bool {
+        self.shared.0.lock().unwrap().output.is_some()
+    }
+
+    /// Block the current thread until the output is ready.
+    pub fn wait(self) -> T {
+        let (lock, done) = &*self.shared;
+        let mut state = done
+            .wait_while(lock.lock().unwrap(), |state| state.output.is_none())
+            .unwrap();
+        let output = state.output.take().unwrap();
+        drop(state);
+        output.unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+}
+
+/// Dropping the task cancels the work, e.g. when the request awaiting it goes away.
+impl<T> Drop for Task<T> {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
+
+impl<T> Future for Task<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.shared.0.lock().unwrap();
+        match state.output.take() {
+            Some(Ok(output)) => Poll::Ready(output),
+            Some(Err(payload)) => {
+                drop(state);
+                panic::resume_unwind(payload)
+            }
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Solver {
+    /// The solver with a cancel token attached to its budget, reusing the one it
+    /// already has.
+    fn cancellable(&self) -> (Solver, CancelToken) {
+        let mut budget = self.budget.clone();
+        let cancel = budget.cancel.get_or_insert_with(CancelToken::new).clone();
+        (
+            Solver {
+                budget,
+                ..self.clone()
+            },
+            cancel,
+        )
+    }
+
+    /// Rate the board on a worker thread, see [`Solver::rate`].
+    pub fn spawn_rate(&self, board: Board) -> Task<Result<Rating, Error>> {
+        let (solver, cancel) = self.cancellable();
+        Task::spawn(cancel, move || solver.rate(&board))
+    }
+
+    /// Solve the board on a worker thread, see [`Solver::solve`].
+    pub fn spawn_solve(&self, board: Board) -> Task<Result<Solution, Error>> {
+        let (solver, cancel) = self.cancellable();
+        Task::spawn(cancel, move || solver.solve(&board))
+    }
+}
@@ -0,0 +1,167 @@
+//! Thermometer Sudoku: digits strictly increase along each thermometer, starting at its bulb.
+
+use crate::analysis::Hits;
+use crate::coord::parse_cell;
+use crate::{Candidate, EMPTY, Strategy, StrategyResult, Sudoku, SudokuError};
+use std::ops::ControlFlow;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Thermometer {
+    /// Cells from the bulb to the tip
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Thermometer {
+    /// Parse thermometers given as whitespace separated paths of cells from the bulb
+    /// to the tip, joined by `-`, e.g. `r1c1-r1c2-r2c3 r9c9-r8c9`. Consecutive cells
+    /// must touch, diagonally or orthogonally.
+    pub fn parse_thermometers(s: &str) -> Result<Vec<Thermometer>, SudokuError> {
+        s.split_whitespace()
+            .map(|path| {
+                let cells = path
+                    .split('-')
+                    .map(|cell| {
+                        parse_cell(cell)
+                            .ok_or_else(|| SudokuError::Parse(format!("Invalid cell '{}'", cell)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if cells.len() < 2 {
+                    return Err(SudokuError::Parse(format!(
+                        "Thermometer '{}' is too short",
+                        path
+                    )));
+                }
+                let touching = cells.windows(2).all(|pair| {
+                    let (a, b) = (pair[0], pair[1]);
+                    a != b && a.0.abs_diff(b.0) <= 1 && a.1.abs_diff(b.1) <= 1
+                });
+                if !touching {
+                    return Err(SudokuError::Parse(format!(
+                        "Cells of thermometer '{}' don't touch",
+                        path
+                    )));
+                }
+                Ok(Thermometer { cells })
+            })
+            .collect()
+    }
+}
+
+impl Sudoku {
+    pub fn set_thermometers(&mut self, thermometers: Vec<Thermometer>) {
+        self.thermometers = thermometers;
+    }
+
+    /// Parse thermometers with [`Thermometer::parse_thermometers`] and attach them to the Sudoku.
+    pub fn set_thermometers_string(&mut self, s: &str) -> Result<(), SudokuError> {
+        let thermometers = Thermometer::parse_thermometers(s)?;
+        let size = self.size();
+        if thermometers
+            .iter()
+            .flat_map(|thermo| thermo.cells.iter())
+            .any(|&(row, col)| row >= size || col >= size)
+        {
+            return Err(SudokuError::InvalidBoard(
+                "Thermometer lies outside the grid".to_string(),
+            ));
+        }
+        self.thermometers = thermometers;
+        Ok(())
+    }
+
+    /// Check if `num` in the given cell leaves room for the digits below and above it
+    /// on all thermometers, and fits the digits already placed on them.
+    pub(crate) fn thermometers_allow(&self, row: usize, col: usize, num: u8) -> bool {
+        for thermo in &self.thermometers {
+            let Some(pos) = thermo.cells.iter().position(|&cell| cell == (row, col)) else {
+                continue;
+            };
+            let below = pos as u8;
+            let above = (thermo.cells.len() - 1 - pos) as u8;
+            if num <= below || num as usize + above as usize > self.size() {
+                return false;
+            }
+            for (i, &(r, c)) in thermo.cells.iter().enumerate() {
+                let other = self.board[r][c];
+                if other == EMPTY || i == pos {
+                    continue;
+                }
+                let distance = i.abs_diff(pos);
+                let fits = if i < pos {
+                    other as usize + distance <= num as usize
+                } else {
+                    num as usize + distance <= other as usize
+                };
+                if !fits {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Narrow down the candidates along thermometers: each cell must be greater than
+    /// the smallest possible digit of the cell before it and smaller than the largest
+    /// possible digit of the cell after it.
+    pub fn find_thermometer(&self) -> StrategyResult {
+        self.first_hit(Strategy::Thermometer, Sudoku::search_thermometer)
+    }
+
+    pub(crate) fn search_thermometer(&self, hits: &mut Hits) -> ControlFlow<()> {
+        let mut result = StrategyResult::new(Strategy::Thermometer);
+        for thermo in &self.thermometers {
+            let digits: Vec<Vec<u8>> = thermo
+                .cells
+                .iter()
+                .map(|&(row, col)| {
+                    if self.board[row][col] != EMPTY {
+                        vec![self.board[row][col]]
+                    } else {
+                        self.candidates[row][col].iter().collect()
+                    }
+                })
+                .collect();
+            // Smallest possible digit of each cell going up from the bulb
+            let mut lowest = Vec::with_capacity(digits.len());
+            let mut floor = 0;
+            for cell_digits in &digits {
+                floor = cell_digits
+                    .iter()
+                    .cloned()
+                    .find(|&num| num > floor)
+                    .unwrap_or(u8::MAX);
+                lowest.push(floor);
+            }
+            // Largest possible digit of each cell going down from the tip
+            let mut highest = vec![0; digits.len()];
+            let mut ceiling = u8::MAX;
+            for (i, cell_digits) in digits.iter().enumerate().rev() {
+                ceiling = cell_digits
+                    .iter()
+                    .rev()
+                    .cloned()
+                    .find(|&num| num < ceiling)
+                    .unwrap_or(0);
+                highest[i] = ceiling;
+            }
+            for (i, &(row, col)) in thermo.cells.iter().enumerate() {
+                if self.board[row][col] != EMPTY {
+                    continue;
+                }
+                for num in self.candidates[row][col] {
+                    if num < lowest[i] || num > highest[i] {
+                        result
+                            .removals
+                            .candidates_about_to_be_removed
+                            .insert(Candidate { row, col, num });
+                    }
+                }
+            }
+            if result.removals.will_remove_candidates() {
+                self.add_pattern_cells(&mut result.removals, thermo.cells.iter().cloned());
+                hits.report(&mut result)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
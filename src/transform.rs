@@ -0,0 +1,156 @@
+//! Transformations that turn a Sudoku into an isomorphic one, which is as hard but
+//! looks different, e.g. to present a puzzle more than once. The rating may still
+//! differ a little, as the solver takes the first step it finds in row-major order.
+
+use crate::{DigitSet, EMPTY, SeededRandom, Sudoku, SudokuError};
+
+/// How the digits are relabeled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum Relabel {
+    #[default]
+    None,
+    /// The new digit of each digit, starting with the new digit of 1
+    Mapping(Vec<u8>),
+    /// A random permutation of the digits, the same for the same seed
+    Seed(u64),
+}
+
+/// A combination of isomorphic transformations, applied in the order transposing,
+/// rotating, relabeling. The default changes nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transform {
+    transpose: bool,
+    /// Clockwise quarter turns
+    quarter_turns: u32,
+    relabel: Relabel,
+}
+
+impl Transform {
+    pub fn new() -> Transform {
+        Transform::default()
+    }
+
+    /// Mirror the board at its main diagonal, which needs square boxes.
+    pub fn with_transpose(mut self) -> Transform {
+        self.transpose = !self.transpose;
+        self
+    }
+
+    /// Rotate the board clockwise by the number of quarter turns. Odd numbers of
+    /// quarter turns need square boxes.
+    pub fn with_rotation(mut self, quarter_turns: u32) -> Transform {
+        self.quarter_turns = (self.quarter_turns + quarter_turns) % 4;
+        self
+    }
+
+    /// Replace each digit `d` by `mapping[d - 1]`. The mapping must be a permutation
+    /// of the digits of the grid.
+    pub fn with_relabeling(mut self, mapping: Vec<u8>) -> Transform {
+        self.relabel = Relabel::Mapping(mapping);
+        self
+    }
+
+    /// Replace the digits by a random permutation of them, which depends on the seed
+    /// only.
+    pub fn with_relabel_seed(mut self, seed: u64) -> Transform {
+        self.relabel = Relabel::Seed(seed);
+        self
+    }
+
+    /// The new digit of each digit for a grid of `size` digits, index 0 standing for
+    /// [`EMPTY`].
+    fn labels(&self, size: usize) -> Result<Vec<u8>, SudokuError> {
+        let mut labels: Vec<u8> = (0..=size as u8).collect();
+        match &self.relabel {
+            Relabel::None => {}
+            Relabel::Mapping(mapping) => {
+                if mapping.len() != size
+                    || mapping
+                        .iter()
+                        .any(|&num| num == EMPTY || num as usize > size)
+                    || mapping.iter().copied().collect::<DigitSet>().len() != size
+                {
+                    return Err(SudokuError::Parse(format!(
+                        "The relabeling must be a permutation of the digits 1 to {}",
+                        size
+                    )));
+                }
+                labels[1..].copy_from_slice(mapping);
+            }
+            Relabel::Seed(seed) => {
+                let mut random = SeededRandom::new(*seed);
+                for i in (2..=size).rev() {
+                    let j = 1 + (random.next_u64() % i as u64) as usize;
+                    labels.swap(i, j);
+                }
+            }
+        }
+        Ok(labels)
+    }
+}
+
+/// The grid with each cell taken from the cell `source` returns for it.
+fn remap<T: Copy>(grid: &[Vec<T>], source: impl Fn(usize, usize) -> (usize, usize)) -> Vec<Vec<T>> {
+    (0..grid.len())
+        .map(|row| {
+            (0..grid.len())
+                .map(|col| {
+                    let (row, col) = source(row, col);
+                    grid[row][col]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl Sudoku {
+    /// The Sudoku after the transformation, with the digits, givens and notes moved
+    /// and relabeled. The rating and the metadata are kept, the undo history isn't.
+    /// Available for classic Sudokus; transposing and quarter turns need square boxes.
+    pub fn transformed(&self, transform: &Transform) -> Result<Sudoku, SudokuError> {
+        if !self.is_classic() {
+            return Err(SudokuError::VariantUnsupported(
+                "transformations need a classic Sudoku".to_string(),
+            ));
+        }
+        if (transform.transpose || transform.quarter_turns % 2 == 1)
+            && self.spec.box_rows != self.spec.box_cols
+        {
+            return Err(SudokuError::VariantUnsupported(
+                "transposing and quarter turns need square boxes".to_string(),
+            ));
+        }
+        let labels = transform.labels(self.size())?;
+        let last = self.size() - 1;
+        let transpose = transform.transpose;
+        let turns = transform.quarter_turns;
+        let source = move |row: usize, col: usize| {
+            // Undo the rotation first, as it's applied last
+            let (row, col) = match turns {
+                1 => (last - col, row),
+                2 => (last - row, last - col),
+                3 => (col, last - row),
+                _ => (row, col),
+            };
+            if transpose { (col, row) } else { (row, col) }
+        };
+        let relabel = |grid: Vec<Vec<u8>>| -> Vec<Vec<u8>> {
+            grid.into_iter()
+                .map(|row| row.into_iter().map(|num| labels[num as usize]).collect())
+                .collect()
+        };
+        let mut sudoku = self.clone();
+        sudoku.board = relabel(remap(&self.board, source));
+        sudoku.original_board = relabel(remap(&self.original_board, source));
+        sudoku.candidates = remap(&self.candidates, source)
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|notes| notes.iter().map(|num| labels[num as usize]).collect())
+                    .collect()
+            })
+            .collect();
+        sudoku.undo_stack.clear();
+        Ok(sudoku)
+    }
+}
@@ -0,0 +1,277 @@
+//! Interactive trainer in the terminal.
+//!
+//! Keys:
+//! - arrow keys: move the cursor
+//! - digits: place a digit, or toggle it in the notes in pencil mode
+//! - `0`, Backspace, Delete: clear the cell
+//! - `p`: switch pencil mode on or off
+//! - `n`: fill in all notes
+//! - `h`: show a hint, `a`: apply the hint shown
+//! - `u`: undo
+//! - `q`, Esc: quit
+
+use crate::{Candidate, EMPTY, StrategyResult, Sudoku, SudokuError};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+};
+
+const HELP: [&str; 8] = [
+    "←↑↓→  move",
+    "1-9   place digit",
+    "0 ⌫   clear cell",
+    "p     pencil mode",
+    "n     fill in all notes",
+    "h a   show / apply hint",
+    "u     undo",
+    "q     quit",
+];
+
+struct Trainer {
+    sudoku: Sudoku,
+    row: usize,
+    col: usize,
+    pencil: bool,
+    hint: Option<StrategyResult>,
+    message: String,
+}
+
+impl Trainer {
+    fn new(sudoku: Sudoku) -> Trainer {
+        Trainer {
+            sudoku,
+            row: 0,
+            col: 0,
+            pencil: false,
+            hint: None,
+            message: String::new(),
+        }
+    }
+
+    /// Handle a key press. Returns `false` to quit.
+    fn handle(&mut self, code: KeyCode) -> bool {
+        let size = self.sudoku.size();
+        let (row, col) = (self.row, self.col);
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up => self.row = (row + size - 1) % size,
+            KeyCode::Down => self.row = (row + 1) % size,
+            KeyCode::Left => self.col = (col + size - 1) % size,
+            KeyCode::Right => self.col = (col + 1) % size,
+            KeyCode::Char('p') => {
+                self.pencil = !self.pencil;
+                self.message.clear();
+            }
+            KeyCode::Char('n') => {
+                self.sudoku.calc_all_notes();
+                self.changed("Notes filled in");
+            }
+            KeyCode::Char('u') => {
+                self.sudoku.undo();
+                self.changed("Undone");
+            }
+            KeyCode::Char('h') => self.show_hint(),
+            KeyCode::Char('a') => match self.hint.take() {
+                Some(hint) => match self.sudoku.apply(&hint) {
+                    Ok(_) => self.changed(&format!("Applied {}", hint.strategy)),
+                    Err(e) => self.message = e.to_string(),
+                },
+                None => self.message = "No hint to apply, press h first".to_string(),
+            },
+            KeyCode::Char('0') | KeyCode::Backspace | KeyCode::Delete => {
+                match self.sudoku.clear_cell(row, col) {
+                    Ok(()) => self.changed(""),
+                    Err(e) => self.message = e.to_string(),
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(num) = self.sudoku.spec.parse_cell(c).filter(|&num| num != EMPTY) {
+                    let result = if self.pencil {
+                        self.sudoku.toggle_note(row, col, num).map(|_| ())
+                    } else {
+                        self.sudoku.set_cell(row, col, num)
+                    };
+                    match result {
+                        Ok(()) if self.sudoku.is_solved() => self.changed("Solved!"),
+                        Ok(()) => self.changed(""),
+                        Err(e) => self.message = e.to_string(),
+                    }
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Forget the hint, which may not fit the board anymore.
+    fn changed(&mut self, message: &str) {
+        self.hint = None;
+        self.message = message.to_string();
+    }
+
+    fn show_hint(&mut self) {
+        if self
+            .sudoku
+            .candidates
+            .iter()
+            .flatten()
+            .all(|notes| notes.is_empty())
+        {
+            self.sudoku.calc_all_notes();
+        }
+        let hint = self.sudoku.next_step();
+        if hint.removals.candidates_about_to_be_removed.is_empty() {
+            self.hint = None;
+            self.message = "No strategy applies".to_string();
+            return;
+        }
+        self.message = match &hint.removals.sets_cell {
+            Some(cell) => format!(
+                "{}: {} goes to r{}c{}",
+                hint.strategy,
+                self.sudoku.spec.digit_char(cell.num),
+                cell.row + 1,
+                cell.col + 1
+            ),
+            None => format!(
+                "{}: removes {} candidates",
+                hint.strategy,
+                hint.removals.candidates_about_to_be_removed.len()
+            ),
+        };
+        self.hint = Some(hint);
+    }
+
+    /// Lines of the grid. Each cell shows its digit in the middle, or its notes laid
+    /// out like the digits in a box.
+    fn grid(&self) -> Vec<Line<'static>> {
+        let sudoku = &self.sudoku;
+        let spec = sudoku.spec;
+        let size = sudoku.size();
+        let cell_width = 2 * spec.box_cols + 1;
+        let separator = {
+            let stack = "-".repeat(cell_width * spec.box_cols);
+            Line::from(format!("+{}+", vec![stack; size / spec.box_cols].join("+")))
+        };
+        let affected = |row: usize, col: usize| {
+            self.hint.as_ref().is_some_and(|hint| {
+                hint.removals
+                    .cells_affected
+                    .iter()
+                    .any(|cell| cell.row == row && cell.col == col)
+            })
+        };
+        let removed = |row: usize, col: usize, num: u8| {
+            self.hint.as_ref().is_some_and(|hint| {
+                hint.removals
+                    .candidates_about_to_be_removed
+                    .contains(&Candidate { row, col, num })
+            })
+        };
+        let mut lines = vec![separator.clone()];
+        for row in 0..size {
+            for sub_row in 0..spec.box_rows {
+                let mut spans = vec![Span::raw("|")];
+                for col in 0..size {
+                    let mut base = Style::default();
+                    if affected(row, col) {
+                        base = base.bg(Color::Yellow).fg(Color::Black);
+                    }
+                    if (row, col) == (self.row, self.col) {
+                        base = base.add_modifier(Modifier::REVERSED);
+                    }
+                    let num = sudoku.board[row][col];
+                    if num != EMPTY {
+                        let text = if sub_row == spec.box_rows / 2 {
+                            format!("{:^cell_width$}", spec.digit_char(num))
+                        } else {
+                            " ".repeat(cell_width)
+                        };
+                        let style = if sudoku.is_given(row, col) {
+                            base.add_modifier(Modifier::BOLD)
+                        } else {
+                            base.fg(Color::Cyan)
+                        };
+                        spans.push(Span::styled(text, style));
+                    } else {
+                        spans.push(Span::styled(" ", base));
+                        for sub_col in 0..spec.box_cols {
+                            let note = (sub_row * spec.box_cols + sub_col + 1) as u8;
+                            let (text, style) = if !sudoku.candidates[row][col].contains(&note) {
+                                (" ".to_string(), base)
+                            } else if removed(row, col, note) {
+                                (spec.digit_char(note).to_string(), base.fg(Color::Red))
+                            } else {
+                                (spec.digit_char(note).to_string(), base.fg(Color::DarkGray))
+                            };
+                            spans.push(Span::styled(text, style));
+                            spans.push(Span::styled(" ", base));
+                        }
+                    }
+                    if (col + 1) % spec.box_cols == 0 {
+                        spans.push(Span::raw("|"));
+                    }
+                }
+                lines.push(Line::from(spans));
+            }
+            if (row + 1) % spec.box_rows == 0 {
+                lines.push(separator.clone());
+            }
+        }
+        lines
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let grid = self.grid();
+        let width = grid.first().map_or(0, Line::width) as u16;
+        let [board, side] = Layout::horizontal([Constraint::Length(width), Constraint::Min(24)])
+            .spacing(2)
+            .areas(frame.area());
+        frame.render_widget(Paragraph::new(grid), board);
+        let mut lines = vec![
+            Line::from(format!(
+                "Mode: {}",
+                if self.pencil { "pencil" } else { "digits" }
+            )),
+            Line::from(format!(
+                "Empty cells: {}",
+                self.sudoku.empty_cells().count()
+            )),
+            Line::from(""),
+            Line::from(self.message.clone()),
+            Line::from(""),
+        ];
+        lines.extend(HELP.iter().map(|&help| Line::from(help)));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::bordered().title(" Sudoku trainer ")),
+            side,
+        );
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && !self.handle(key.code)
+            {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Run the trainer on the puzzle until the player quits.
+pub fn run(puzzle: &str) -> Result<(), SudokuError> {
+    let sudoku = puzzle.parse::<Sudoku>().map_err(SudokuError::Parse)?;
+    let mut terminal = ratatui::init();
+    let result = Trainer::new(sudoku).run(&mut terminal);
+    ratatui::restore();
+    result.map_err(|e| SudokuError::Io(e.to_string()))
+}
@@ -1,6 +1,6 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use rate_my_sudoku::{EMPTY, Resolution, Strategy, StrategyResult, Sudoku, Unit};
+use rate_my_sudoku::{EMPTY, GridSpec, Locale, Strategy, StrategyResult, Sudoku, Unit};
 
 use eframe::Storage;
 use eframe::egui;
@@ -82,6 +82,7 @@ impl SudokuApp {
         let thick_line_color = Color32::BLACK;
         let filled_cell_color = Color32::from_rgb(235, 235, 235);
         let text_color = Color32::BLACK;
+        let solved_color = Color32::from_rgb(30, 80, 200);
         let notes_color = Color32::from_gray(100);
         let shade_color = Color32::from_gray(170);
 
@@ -90,8 +91,9 @@ impl SudokuApp {
         let thick_stroke = Stroke::new(2.0, thick_line_color);
 
         // Calculate size and spacing
+        let size = self.sudoku.size();
         let board_size = ui.available_width().min(ui.available_height());
-        let cell_size = board_size / 9.0;
+        let cell_size = board_size / size as f32;
 
         // Create a painter for custom drawing
         let (response, painter) = ui.allocate_painter(
@@ -111,7 +113,7 @@ impl SudokuApp {
             let rel_pos = mouse_pos - response.rect.min;
             let cell_x = (rel_pos.x / cell_size) as usize;
             let cell_y = (rel_pos.y / cell_size) as usize;
-            if cell_x < 9 && cell_y < 9 {
+            if cell_x < size && cell_y < size {
                 // Handle cell selection
             }
         }
@@ -119,8 +121,8 @@ impl SudokuApp {
         let sudoku = &self.sudoku;
 
         // Draw filled cells for digits
-        for row in 0..9 {
-            for col in 0..9 {
+        for row in 0..size {
+            for col in 0..size {
                 match &self.strategy_result.removals.unit_index {
                     None => {
                         if sudoku.get_num(row, col) != EMPTY {
@@ -139,48 +141,57 @@ impl SudokuApp {
                     }
                     Some(unit) => match self.strategy_result.removals.unit {
                         None => {}
-                        Some(Unit::Row) => {
-                            if !unit.contains(&row) {
-                                painter.rect_filled(
-                                    Rect::from_min_size(
-                                        Pos2::new(
-                                            response.rect.min.x,
-                                            response.rect.min.y + row as f32 * cell_size,
-                                        ),
-                                        Vec2::new(board_size, cell_size),
+                        Some(Unit::Row) if !unit.contains(&row) => {
+                            painter.rect_filled(
+                                Rect::from_min_size(
+                                    Pos2::new(
+                                        response.rect.min.x,
+                                        response.rect.min.y + row as f32 * cell_size,
                                     ),
-                                    0.0,
-                                    shade_color,
-                                );
-                            }
+                                    Vec2::new(board_size, cell_size),
+                                ),
+                                0.0,
+                                shade_color,
+                            );
                         }
-                        Some(Unit::Column) => {
-                            if !unit.contains(&col) {
-                                painter.rect_filled(
-                                    Rect::from_min_size(
-                                        Pos2::new(
-                                            response.rect.min.x + col as f32 * cell_size,
-                                            response.rect.min.y,
-                                        ),
-                                        Vec2::new(cell_size, board_size),
+                        Some(Unit::Column) if !unit.contains(&col) => {
+                            painter.rect_filled(
+                                Rect::from_min_size(
+                                    Pos2::new(
+                                        response.rect.min.x + col as f32 * cell_size,
+                                        response.rect.min.y,
                                     ),
-                                    0.0,
-                                    shade_color,
-                                );
-                            }
+                                    Vec2::new(cell_size, board_size),
+                                ),
+                                0.0,
+                                shade_color,
+                            );
                         }
-                        Some(Unit::Box) => {
-                            let box_row = row / 3;
-                            let box_col = col / 3;
-                            let box_index = (box_row * 3) + box_col;
-                            if !unit.contains(&box_index) {
+                        Some(Unit::Box) if !unit.contains(&sudoku.regions[row][col]) => {
+                            painter.rect_filled(
+                                Rect::from_min_size(
+                                    Pos2::new(
+                                        response.rect.min.x + col as f32 * cell_size,
+                                        response.rect.min.y + row as f32 * cell_size,
+                                    ),
+                                    Vec2::new(cell_size, cell_size),
+                                ),
+                                0.0,
+                                shade_color,
+                            );
+                        }
+                        Some(ref other) => {
+                            let in_unit = unit
+                                .iter()
+                                .any(|&idx| sudoku.unit_cells(other, idx).contains(&(row, col)));
+                            if !in_unit {
                                 painter.rect_filled(
                                     Rect::from_min_size(
                                         Pos2::new(
-                                            response.rect.min.x + box_col as f32 * 3.0 * cell_size,
-                                            response.rect.min.y + box_row as f32 * 3.0 * cell_size,
+                                            response.rect.min.x + col as f32 * cell_size,
+                                            response.rect.min.y + row as f32 * cell_size,
                                         ),
-                                        Vec2::new(3.0 * cell_size, 3.0 * cell_size),
+                                        Vec2::new(cell_size, cell_size),
                                     ),
                                     0.0,
                                     shade_color,
@@ -193,13 +204,7 @@ impl SudokuApp {
         }
 
         // Draw the grid lines
-        for i in 0..=9 {
-            let line_stroke = if i % 3 == 0 {
-                thick_stroke
-            } else {
-                thin_stroke
-            };
-
+        for i in 0..=size {
             // Vertical lines
             painter.line_segment(
                 [
@@ -212,7 +217,7 @@ impl SudokuApp {
                         response.rect.min.y + board_size,
                     ),
                 ],
-                line_stroke,
+                thin_stroke,
             );
 
             // Horizontal lines
@@ -227,13 +232,47 @@ impl SudokuApp {
                         response.rect.min.y + i as f32 * cell_size,
                     ),
                 ],
-                line_stroke,
+                thin_stroke,
             );
         }
 
+        // Draw thick lines around the boxes (or jigsaw regions)
+        painter.rect_stroke(
+            Rect::from_min_size(response.rect.min, Vec2::new(board_size, board_size)),
+            0.0,
+            thick_stroke,
+            egui::StrokeKind::Middle,
+        );
+        for row in 0..size {
+            for col in 0..size {
+                let x = response.rect.min.x + col as f32 * cell_size;
+                let y = response.rect.min.y + row as f32 * cell_size;
+                // Right edge of the cell
+                if col + 1 < size && sudoku.regions[row][col] != sudoku.regions[row][col + 1] {
+                    painter.line_segment(
+                        [
+                            Pos2::new(x + cell_size, y),
+                            Pos2::new(x + cell_size, y + cell_size),
+                        ],
+                        thick_stroke,
+                    );
+                }
+                // Bottom edge of the cell
+                if row + 1 < size && sudoku.regions[row][col] != sudoku.regions[row + 1][col] {
+                    painter.line_segment(
+                        [
+                            Pos2::new(x, y + cell_size),
+                            Pos2::new(x + cell_size, y + cell_size),
+                        ],
+                        thick_stroke,
+                    );
+                }
+            }
+        }
+
         // Draw values and notes
-        for row in 0..9 {
-            for col in 0..9 {
+        for row in 0..size {
+            for col in 0..size {
                 let cell_rect = Rect::from_min_size(
                     Pos2::new(
                         response.rect.min.x + col as f32 * cell_size,
@@ -244,22 +283,27 @@ impl SudokuApp {
 
                 if sudoku.get_num(row, col) != EMPTY {
                     // Draw the digit for filled cells
-                    let digit = sudoku.get_num(row, col).to_string();
+                    let digit = sudoku.spec.digit_char(sudoku.get_num(row, col)).to_string();
                     painter.text(
                         cell_rect.center(),
                         egui::Align2::CENTER_CENTER,
                         digit,
                         FontId::proportional(cell_size * 0.7),
-                        text_color,
+                        if sudoku.is_given(row, col) {
+                            text_color
+                        } else {
+                            solved_color
+                        },
                     );
                 } else {
-                    // Draw the notes in a 3x3 grid
-                    let note_size = cell_size / 3.0;
+                    // Draw the notes in a grid shaped like a box
+                    let box_cols = sudoku.spec.box_cols;
+                    let note_size = cell_size / box_cols.max(sudoku.spec.box_rows) as f32;
 
-                    for n in 1..=9 {
+                    for n in 1..=size as u8 {
                         if sudoku.get_notes(row, col).contains(&n) {
-                            let note_row = (n - 1) / 3;
-                            let note_col = (n - 1) % 3;
+                            let note_row = (n as usize - 1) / box_cols;
+                            let note_col = (n as usize - 1) % box_cols;
 
                             let note_pos = Pos2::new(
                                 cell_rect.min.x + note_col as f32 * note_size + note_size / 2.0,
@@ -316,7 +360,7 @@ impl SudokuApp {
                             painter.text(
                                 note_pos,
                                 egui::Align2::CENTER_CENTER,
-                                n.to_string(),
+                                sudoku.spec.digit_char(n).to_string(),
                                 FontId::proportional(note_size * 0.7),
                                 notes_color,
                             );
@@ -334,8 +378,11 @@ impl eframe::App for SudokuApp {
             if ctx.input(|i| {
                 i.events.iter().any(|e| match e {
                     Event::Paste(text) => {
-                        let digits: String = text.chars().filter(|c| c.is_ascii_digit()).collect();
-                        if digits.len() != 81 {
+                        let digits: String = text
+                            .chars()
+                            .filter(|c| c.is_ascii_hexdigit() || *c == '.')
+                            .collect();
+                        if GridSpec::from_cell_count(digits.len()).is_none() {
                             return false;
                         }
                         log::info!("Pasted sudoku: {}", &digits);
@@ -399,16 +446,7 @@ impl eframe::App for SudokuApp {
                     }
                     // Status information display
                     let status_text = if self.strategy_result.strategy != Strategy::None {
-                        if self.strategy_result.removals.unit.is_some() {
-                            format!(
-                                "Strategy: {} in {} {:?}",
-                                self.strategy_result.strategy,
-                                self.strategy_result.removals.unit.as_ref().unwrap(),
-                                self.strategy_result.removals.unit_index.as_ref().unwrap()
-                            )
-                        } else {
-                            format!("Strategy: {}", self.strategy_result.strategy)
-                        }
+                        self.strategy_result.explain(Locale::English)
                     } else if self.sudoku.is_solved() {
                         std::fmt::format(format_args!(
                             "Solved! Effort: {:.1}",
@@ -458,8 +496,10 @@ impl SudokuApp {
                 self.state = State::ApplyingStrategy;
             }
             State::ApplyingStrategy => {
-                let resolution: Resolution = self.sudoku.apply(&self.strategy_result);
-                log::info!("{:?}", resolution);
+                match self.sudoku.apply(&self.strategy_result) {
+                    Ok(resolution) => log::info!("{:?}", resolution),
+                    Err(e) => log::error!("Can't apply step: {}", e),
+                }
                 self.strategy_result.clear();
                 self.state = State::TryingStrategy;
             }
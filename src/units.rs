@@ -0,0 +1,290 @@
+//! Units: groups of cells whose digits must all differ. Besides rows, columns, and
+//! boxes, these can be the diagonals, the windows of Hyper Sudoku, cages, or any
+//! set of cells.
+
+use crate::{Constraints, Coord, DigitSet, EMPTY, Sudoku, SudokuError};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Unit {
+    Row,
+    Column,
+    Box,
+    Cage,
+    Diagonal,
+    Window,
+    Custom,
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unit::Row => write!(f, "Row"),
+            Unit::Column => write!(f, "Column"),
+            Unit::Box => write!(f, "Box"),
+            Unit::Cage => write!(f, "Cage"),
+            Unit::Diagonal => write!(f, "Diagonal"),
+            Unit::Window => write!(f, "Window"),
+            Unit::Custom => write!(f, "Unit"),
+        }
+    }
+}
+
+/// A row, column, or box, identified by its index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum House {
+    Row(usize),
+    Column(usize),
+    Box(usize),
+}
+
+impl House {
+    pub fn unit(self) -> Unit {
+        match self {
+            House::Row(_) => Unit::Row,
+            House::Column(_) => Unit::Column,
+            House::Box(_) => Unit::Box,
+        }
+    }
+
+    pub fn index(self) -> usize {
+        match self {
+            House::Row(index) | House::Column(index) | House::Box(index) => index,
+        }
+    }
+}
+
+/// A unit with its cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub unit: Unit,
+    pub index: usize,
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Group {
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        self.cells.contains(&(row, col))
+    }
+}
+
+/// A row, column, or box together with the Sudoku it belongs to, to read its cells
+/// without index arithmetic, see [`Sudoku::rows`].
+#[derive(Debug, Clone, Copy)]
+pub struct HouseView<'a> {
+    sudoku: &'a Sudoku,
+    house: House,
+}
+
+impl<'a> HouseView<'a> {
+    pub fn house(&self) -> House {
+        self.house
+    }
+
+    /// The cells with their digits, or [`EMPTY`], in row-major order.
+    pub fn cells(&self) -> impl Iterator<Item = (Coord, u8)> + 'a {
+        let sudoku = self.sudoku;
+        sudoku
+            .cells_of(self.house)
+            .map(|(row, col)| (Coord::new(row, col), sudoku.board[row][col]))
+    }
+
+    /// The cells without a digit, in row-major order.
+    pub fn empty_cells(&self) -> impl Iterator<Item = Coord> + 'a {
+        self.cells()
+            .filter(|&(_, num)| num == EMPTY)
+            .map(|(coord, _)| coord)
+    }
+
+    /// The digits placed in the house.
+    pub fn digits(&self) -> DigitSet {
+        self.cells()
+            .map(|(_, num)| num)
+            .filter(|&num| num != EMPTY)
+            .collect()
+    }
+
+    /// The empty cells that have the digit as a candidate.
+    pub fn places_for(&self, num: u8) -> impl Iterator<Item = Coord> + 'a {
+        let sudoku = self.sudoku;
+        self.empty_cells()
+            .filter(move |coord| sudoku.candidates[coord.row][coord.col].contains(&num))
+    }
+}
+
+impl Sudoku {
+    /// Cells of the house, in row-major order.
+    pub fn cells_of(&self, house: House) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let size = self.size();
+        let cells: Box<dyn Iterator<Item = (usize, usize)>> = match house {
+            House::Row(row) => Box::new((0..size).map(move |col| (row, col))),
+            House::Column(col) => Box::new((0..size).map(move |row| (row, col))),
+            House::Box(index) => Box::new(self.region_cells[index].iter().cloned()),
+        };
+        cells
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = HouseView<'_>> {
+        (0..self.size()).map(|row| self.house_view(House::Row(row)))
+    }
+
+    /// The columns from left to right.
+    pub fn cols(&self) -> impl Iterator<Item = HouseView<'_>> {
+        (0..self.size()).map(|col| self.house_view(House::Column(col)))
+    }
+
+    /// The boxes, or jigsaw regions, in the order of their indices.
+    pub fn boxes(&self) -> impl Iterator<Item = HouseView<'_>> {
+        (0..self.size()).map(|index| self.house_view(House::Box(index)))
+    }
+
+    pub fn house_view(&self, house: House) -> HouseView<'_> {
+        HouseView {
+            sudoku: self,
+            house,
+        }
+    }
+
+    /// All rows, followed by all columns.
+    pub fn lines(&self) -> impl Iterator<Item = House> + use<> {
+        let size = self.size();
+        (0..size)
+            .map(House::Row)
+            .chain((0..size).map(House::Column))
+    }
+
+    /// All rows, columns, and boxes, in this order.
+    pub fn houses(&self) -> impl Iterator<Item = House> + use<> {
+        self.lines().chain((0..self.size()).map(House::Box))
+    }
+
+    /// The row, column, or box (depending on `unit`) containing the cell.
+    pub(crate) fn house_at(&self, unit: &Unit, row: usize, col: usize) -> House {
+        match unit {
+            Unit::Row => House::Row(row),
+            Unit::Column => House::Column(col),
+            Unit::Box => House::Box(self.regions[row][col]),
+            _ => unreachable!("{} is not a house", unit),
+        }
+    }
+
+    pub fn house_contains(&self, house: House, row: usize, col: usize) -> bool {
+        self.house_at(&house.unit(), row, col) == house
+    }
+
+    /// Add a set of cells whose digits must all differ. If it has as many cells as
+    /// the grid has rows, it must contain every digit.
+    pub fn add_unit(&mut self, cells: Vec<(usize, usize)>) -> Result<(), SudokuError> {
+        let size = self.size();
+        if cells.is_empty() || cells.len() > size {
+            return Err(SudokuError::InvalidBoard(format!(
+                "A unit must consist of 1 to {} cells",
+                size
+            )));
+        }
+        if cells.iter().any(|&(row, col)| row >= size || col >= size) {
+            return Err(SudokuError::InvalidBoard(
+                "Unit lies outside the grid".to_string(),
+            ));
+        }
+        if (1..cells.len()).any(|i| cells[..i].contains(&cells[i])) {
+            return Err(SudokuError::InvalidBoard(
+                "Unit contains a cell twice".to_string(),
+            ));
+        }
+        self.custom_units.push(cells);
+        Ok(())
+    }
+
+    /// Top-left cells of the windows of Hyper Sudoku: one box-sized window in each
+    /// gap of one cell between the windows and the border.
+    fn window_origins(&self) -> Vec<(usize, usize)> {
+        let size = self.size();
+        let starts = |step: usize| -> Vec<usize> {
+            (0..)
+                .map(|i| 1 + i * (step + 1))
+                .take_while(|start| start + step < size)
+                .collect()
+        };
+        let rows = starts(self.spec.box_rows);
+        let cols = starts(self.spec.box_cols);
+        rows.iter()
+            .flat_map(|&row| cols.iter().map(move |&col| (row, col)))
+            .collect()
+    }
+
+    /// Cells of the unit with the given index.
+    pub fn unit_cells(&self, unit: &Unit, index: usize) -> Vec<(usize, usize)> {
+        let size = self.size();
+        match unit {
+            Unit::Row => self.cells_of(House::Row(index)).collect(),
+            Unit::Column => self.cells_of(House::Column(index)).collect(),
+            Unit::Box => self.cells_of(House::Box(index)).collect(),
+            Unit::Diagonal if index == 0 => (0..size).map(|i| (i, i)).collect(),
+            Unit::Diagonal => (0..size).map(|i| (i, size - 1 - i)).collect(),
+            Unit::Window => {
+                let (top, left) = self.window_origins()[index];
+                (0..size)
+                    .map(|i| (top + i / self.spec.box_cols, left + i % self.spec.box_cols))
+                    .collect()
+            }
+            Unit::Cage => self.cages[index].cells.clone(),
+            Unit::Custom => self.custom_units[index].clone(),
+        }
+    }
+
+    /// Units beyond rows, columns, boxes and cages: diagonals, windows, and custom units.
+    pub(crate) fn extra_groups(&self) -> Vec<Group> {
+        let mut units = Vec::new();
+        if self.constraints.contains(Constraints::DIAGONAL) {
+            units.extend((0..2).map(|index| (Unit::Diagonal, index)));
+        }
+        if self.constraints.contains(Constraints::WINDOWS) {
+            units.extend((0..self.window_origins().len()).map(|index| (Unit::Window, index)));
+        }
+        units.extend((0..self.custom_units.len()).map(|index| (Unit::Custom, index)));
+        units
+            .into_iter()
+            .map(|(unit, index)| Group {
+                cells: self.unit_cells(&unit, index),
+                unit,
+                index,
+            })
+            .collect()
+    }
+
+    /// All units of the Sudoku: rows, columns, boxes, diagonals, windows, custom
+    /// units, and cages, in this order.
+    pub fn groups(&self) -> Vec<Group> {
+        let mut groups: Vec<Group> = self
+            .houses()
+            .map(|house| Group {
+                unit: house.unit(),
+                index: house.index(),
+                cells: self.cells_of(house).collect(),
+            })
+            .collect();
+        groups.extend(self.extra_groups());
+        groups.extend(self.cages.iter().enumerate().map(|(index, cage)| Group {
+            unit: Unit::Cage,
+            index,
+            cells: cage.cells.clone(),
+        }));
+        groups
+    }
+
+    /// Units that contain every digit, i.e. all units with as many cells as the grid
+    /// has rows.
+    pub fn complete_groups(&self) -> Vec<Group> {
+        let size = self.size();
+        self.groups()
+            .into_iter()
+            .filter(|group| group.cells.len() == size)
+            .collect()
+    }
+}
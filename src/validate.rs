@@ -0,0 +1,170 @@
+//! Checking puzzles before they're published: that they can be read, that the givens
+//! don't repeat within a unit, that the solution is unique, and whether every given is
+//! needed.
+
+use crate::{Coord, EMPTY, GridSpec, Sudoku, SudokuError, Unit};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+/// A digit given more than once in a unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Duplicate {
+    pub unit: Unit,
+    /// Index of the unit among those of its kind, counted from 0
+    pub index: usize,
+    pub num: u8,
+    /// The cells holding the digit, in row-major order
+    pub cells: Vec<Coord>,
+}
+
+/// Findings about the givens of a puzzle, see [`Sudoku::validation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Validation {
+    pub givens: usize,
+    /// Digits given more than once in a unit, by unit
+    pub duplicates: Vec<Duplicate>,
+    /// Number of solutions, counted up to 2. `None` if there are duplicates.
+    pub solutions: Option<usize>,
+    /// Givens whose removal alone keeps the solution unique. `None` unless the solution
+    /// is unique.
+    pub redundant_givens: Option<Vec<Coord>>,
+}
+
+impl Validation {
+    /// Whether the givens don't repeat within a unit and lead to a unique solution.
+    pub fn is_valid(&self) -> bool {
+        self.duplicates.is_empty() && self.solutions == Some(1)
+    }
+
+    /// Whether the solution is unique and every given is needed for that.
+    pub fn is_minimal(&self) -> bool {
+        self.redundant_givens
+            .as_ref()
+            .is_some_and(|redundant| redundant.is_empty())
+    }
+}
+
+fn cell_list(cells: &[Coord]) -> String {
+    let cells: Vec<String> = cells.iter().map(ToString::to_string).collect();
+    cells.join(", ")
+}
+
+/// A report with a line per finding.
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Clues: {}", self.givens)?;
+        for duplicate in &self.duplicates {
+            writeln!(
+                f,
+                "Duplicate {} in {} {}: {}",
+                duplicate.num,
+                duplicate.unit.to_string().to_lowercase(),
+                duplicate.index + 1,
+                cell_list(&duplicate.cells)
+            )?;
+        }
+        match self.solutions {
+            None => {}
+            Some(0) => writeln!(f, "Solutions: none")?,
+            Some(1) => writeln!(f, "Solutions: 1")?,
+            Some(_) => writeln!(f, "Solutions: more than one")?,
+        }
+        match &self.redundant_givens {
+            None => Ok(()),
+            Some(redundant) if redundant.is_empty() => writeln!(f, "Minimal: yes"),
+            Some(redundant) => writeln!(
+                f,
+                "Minimal: no, each of these givens can be removed: {}",
+                cell_list(redundant)
+            ),
+        }
+    }
+}
+
+impl Sudoku {
+    /// Check the givens: which digits repeat within a unit, including cages and the
+    /// units of the variants, how many solutions there are, and which givens could be
+    /// removed one at a time without losing uniqueness. Digits placed since are ignored.
+    pub fn validation(&self) -> Validation {
+        let mut puzzle = self.clone();
+        puzzle.board = self.original_board.clone();
+        let mut duplicates = Vec::new();
+        for group in puzzle.groups() {
+            for num in 1..=puzzle.size() as u8 {
+                let cells: Vec<Coord> = group
+                    .cells
+                    .iter()
+                    .filter(|&&(row, col)| puzzle.board[row][col] == num)
+                    .map(|&(row, col)| Coord::new(row, col))
+                    .collect();
+                if cells.len() > 1 {
+                    duplicates.push(Duplicate {
+                        unit: group.unit.clone(),
+                        index: group.index,
+                        num,
+                        cells,
+                    });
+                }
+            }
+        }
+        let solutions = duplicates.is_empty().then(|| puzzle.count_solutions(2));
+        let redundant_givens = (solutions == Some(1)).then(|| {
+            puzzle
+                .given_cells()
+                .filter(|coord| {
+                    let mut fewer = puzzle.clone();
+                    fewer.board[coord.row][coord.col] = EMPTY;
+                    fewer.count_solutions(2) == 1
+                })
+                .collect()
+        });
+        Validation {
+            givens: puzzle.givens_count(),
+            duplicates,
+            solutions,
+            redundant_givens,
+        }
+    }
+}
+
+/// Read a puzzle strictly and check it, see [`Sudoku::validation`]. Unlike
+/// [`Sudoku::set_board_string`], which skips any character that isn't a cell, only
+/// whitespace may appear between the cells; other characters are reported with their
+/// positions, counted from 1. A wrong number of cells is reported along with them, the
+/// characters then being checked against the grid size closest to the cell count.
+pub fn validate(puzzle: &str) -> Result<Validation, SudokuError> {
+    let cells: Vec<(usize, char)> = puzzle
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(i, c)| (i + 1, c))
+        .collect();
+    let mut problems = Vec::new();
+    let spec = GridSpec::from_cell_count(cells.len()).unwrap_or_else(|| {
+        problems.push(format!(
+            "must contain exactly 36, 81 or 256 cells, found {}",
+            cells.len()
+        ));
+        [GridSpec::MINI, GridSpec::CLASSIC, GridSpec::HEXA]
+            .into_iter()
+            .min_by_key(|spec| spec.cell_count().abs_diff(cells.len()))
+            .expect("there are grid sizes")
+    });
+    let invalid: Vec<String> = cells
+        .iter()
+        .filter(|&&(_, c)| spec.parse_cell(c).is_none())
+        .map(|(position, c)| format!("'{}' at position {}", c, position))
+        .collect();
+    if !invalid.is_empty() {
+        problems.push(format!("invalid cells {}", invalid.join(", ")));
+    }
+    if !problems.is_empty() {
+        return Err(SudokuError::Parse(problems.join("; ")));
+    }
+    let board: String = cells.into_iter().map(|(_, c)| c).collect();
+    let sudoku = board.parse::<Sudoku>().map_err(SudokuError::Parse)?;
+    Ok(sudoku.validation())
+}
@@ -0,0 +1,91 @@
+//! Checking filled grids against the rules and against a known solution, e.g. to
+//! tell a player whether their grid is correct.
+
+use crate::{Board, Coord, EMPTY, Sudoku};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::fmt;
+
+/// Reason why the grid doesn't match a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Mismatch {
+    /// The solution isn't a valid Sudoku board
+    Parse(String),
+    /// The solution has a different size than the grid
+    Size { expected: usize, found: usize },
+    /// The solution has empty cells or breaks a rule of the Sudoku
+    InvalidSolution,
+    /// The cell of the grid is still empty
+    Empty(Coord),
+    /// The cell of the grid holds a digit other than the one of the solution
+    Digit {
+        cell: Coord,
+        expected: u8,
+        found: u8,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Mismatch::Parse(e) => write!(f, "Invalid solution: {}", e),
+            Mismatch::Size { expected, found } => write!(
+                f,
+                "The solution has {}×{} cells instead of {}×{}",
+                found, found, expected, expected
+            ),
+            Mismatch::InvalidSolution => write!(f, "The solution breaks the rules"),
+            Mismatch::Empty(cell) => write!(f, "{} is empty", cell),
+            Mismatch::Digit {
+                cell,
+                expected,
+                found,
+            } => write!(f, "{} holds {} instead of {}", cell, found, expected),
+        }
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+impl Sudoku {
+    /// Whether every cell is filled and the digits obey all rules of the Sudoku: every
+    /// unit, cage, and variant clue. Unlike [`Sudoku::is_solved`], which only checks
+    /// for empty cells, this rejects filled grids with mistakes.
+    pub fn is_valid_solution(&self) -> bool {
+        self.is_solved() && !self.has_conflicts()
+    }
+
+    /// Check that the grid is filled with exactly the digits of `solution`, given in
+    /// the format of [`Sudoku::set_board_string`], which has to be a valid solution
+    /// under the rules of this Sudoku. Reports the first cell that differs in
+    /// row-major order.
+    pub fn verify_against(&self, solution: &str) -> Result<(), Mismatch> {
+        let board = solution.trim().parse::<Board>().map_err(Mismatch::Parse)?;
+        if board.size() != self.size() {
+            return Err(Mismatch::Size {
+                expected: self.size(),
+                found: board.size(),
+            });
+        }
+        let mut solved = self.clone();
+        solved.board = board.values;
+        if !solved.is_valid_solution() {
+            return Err(Mismatch::InvalidSolution);
+        }
+        for (cell, found) in self.cells() {
+            let expected = solved.board[cell.row][cell.col];
+            if found == EMPTY {
+                return Err(Mismatch::Empty(cell));
+            }
+            if found != expected {
+                return Err(Mismatch::Digit {
+                    cell,
+                    expected,
+                    found,
+                });
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+//! A write-up of a solve in Markdown, step by step with a small grid after each, to
+//! paste into a blog post or a forum thread.
+
+use crate::{
+    Budget, DifficultyLevel, EMPTY, GridSpec, Locale, SolveObserver, SolveOutcome, StrategyResult,
+    Sudoku,
+};
+use std::fmt::Write;
+
+/// Collects each step with the digits after it.
+#[derive(Default)]
+struct Steps(Vec<(StrategyResult, Vec<Vec<u8>>)>);
+
+impl SolveObserver for Steps {
+    fn on_step(&mut self, sudoku: &Sudoku, step: &StrategyResult) {
+        self.0.push((step.clone(), sudoku.board.clone()));
+    }
+}
+
+/// The digits in a fenced code block, with ASCII lines between the boxes. The digit the
+/// step placed is shown as `*5*`, empty cells the step removed candidates from without
+/// placing a digit as `-`.
+fn mini_grid(spec: GridSpec, board: &[Vec<u8>], step: Option<&StrategyResult>) -> String {
+    let separator = format!(
+        "+{}\n",
+        vec!["-".repeat(3 * spec.box_cols); spec.size() / spec.box_cols].join("+") + "+"
+    );
+    let mut grid = String::from("```text\n");
+    for (row, values) in board.iter().enumerate() {
+        if row.is_multiple_of(spec.box_rows) {
+            grid += &separator;
+        }
+        for (col, &num) in values.iter().enumerate() {
+            if col.is_multiple_of(spec.box_cols) {
+                grid.push('|');
+            }
+            let removals = step.map(|step| &step.removals);
+            let placed = removals
+                .and_then(|removals| removals.sets_cell.as_ref())
+                .is_some_and(|cell| (cell.row, cell.col) == (row, col));
+            let eliminated = removals.is_some_and(|removals| {
+                removals.sets_cell.is_none()
+                    && removals
+                        .candidates_about_to_be_removed
+                        .iter()
+                        .any(|candidate| (candidate.row, candidate.col) == (row, col))
+            });
+            let digit = spec.digit_char(num);
+            grid += &match (num, placed, eliminated) {
+                (_, true, _) => format!("*{}*", digit),
+                (EMPTY, _, true) => " - ".to_string(),
+                (EMPTY, _, false) => " . ".to_string(),
+                _ => format!(" {} ", digit),
+            };
+        }
+        grid += "|\n";
+    }
+    grid += &separator;
+    grid + "```\n"
+}
+
+impl Sudoku {
+    /// Solve a copy of the Sudoku like [`Sudoku::solve_human_like`] and write the solve
+    /// up in Markdown: the starting position, a section per step with its explanation
+    /// and the digits afterwards, and the outcome with the rating.
+    pub fn walkthrough_markdown(&self) -> String {
+        let mut sudoku = self.clone();
+        sudoku.undo_stack.clear();
+        let start = sudoku.board.clone();
+        let mut steps = Steps::default();
+        let outcome = sudoku.solve_human_like_observed(&Budget::unlimited(), &mut steps);
+
+        let mut text = String::from("# Walkthrough\n\n");
+        let _ = writeln!(text, "Puzzle: `{}`\n", self.serialized());
+        text += "In the grids, `*5*` marks the digit a step placed and `-` the empty cells \
+                 a step removed candidates from without placing a digit.\n\n";
+        text += &mini_grid(self.spec, &start, None);
+        for (number, (step, board)) in steps.0.iter().enumerate() {
+            let _ = writeln!(text, "\n## Step {}: {}\n", number + 1, step.strategy);
+            let _ = writeln!(text, "{}.\n", step.explain(Locale::English));
+            text += &mini_grid(self.spec, board, Some(step));
+        }
+
+        text += "\n## Result\n\n";
+        let steps = match steps.0.len() {
+            1 => "1 step".to_string(),
+            count => format!("{} steps", count),
+        };
+        let difficulty = sudoku.difficulty();
+        let _ = match outcome {
+            SolveOutcome::Solved => writeln!(
+                text,
+                "Solved in {}. Difficulty {:.2} ({}), hardest strategy: {}.",
+                steps,
+                difficulty,
+                DifficultyLevel::of(difficulty),
+                sudoku
+                    .hardest_technique()
+                    .map_or("none".to_string(), |strategy| format!("{}", strategy))
+            ),
+            SolveOutcome::StuckAt(empty_cells) => writeln!(
+                text,
+                "The strategies get stuck after {}, with {} cells left empty.",
+                steps, empty_cells
+            ),
+            SolveOutcome::Contradiction(contradiction) => writeln!(
+                text,
+                "The position contradicts itself after {}: {}.",
+                steps, contradiction
+            ),
+            SolveOutcome::BudgetExceeded
+            | SolveOutcome::Cancelled
+            | SolveOutcome::MultipleSolutions => {
+                writeln!(text, "The solve stopped after {}.", steps)
+            }
+        };
+        text
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Coord, Strategy, Sudoku};
+    use std::collections::HashSet;
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_analyze_lists_all_steps() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let before = sudoku.snapshot();
+        let analysis = sudoku.analyze();
+        assert!(sudoku.diff(&before).is_empty());
+        let (strategy, steps) = &analysis.strategies[0];
+        let next = sudoku.next_step();
+        assert_eq!(*strategy, next.strategy);
+        assert_eq!(steps[0].removals.sets_cell, next.removals.sets_cell);
+        assert_eq!(
+            steps[0].removals.candidates_about_to_be_removed,
+            next.removals.candidates_about_to_be_removed
+        );
+        let hidden_singles = analysis.steps(&Strategy::HiddenSingle);
+        assert!(hidden_singles.len() > 1);
+        assert!(hidden_singles.iter().all(
+            |step| step.strategy == Strategy::HiddenSingle && step.removals.sets_cell.is_some()
+        ));
+        assert!(analysis.step_count() >= analysis.technique_count());
+        assert!(analysis.steps(&Strategy::InniesOuties).is_empty());
+    }
+
+    #[test]
+    fn test_analyze_counts_overlapping_steps_once() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let analysis = sudoku.analyze();
+        // Hidden singles are looked for in rows, columns and boxes, and many of them are
+        // found in more than one of those
+        let hidden_singles = analysis.steps(&Strategy::HiddenSingle);
+        let placements: HashSet<_> = hidden_singles
+            .iter()
+            .map(|step| step.removals.sets_cell.as_ref().unwrap().coord())
+            .collect();
+        assert_eq!(placements.len(), hidden_singles.len());
+        for (_, steps) in &analysis.strategies {
+            let changes: HashSet<_> = steps
+                .iter()
+                .map(|step| {
+                    let removals = &step.removals;
+                    (
+                        removals
+                            .sets_cell
+                            .as_ref()
+                            .map(|cell| (cell.coord(), cell.num)),
+                        removals.candidates_about_to_be_removed.clone(),
+                    )
+                })
+                .collect();
+            assert_eq!(changes.len(), steps.len());
+        }
+    }
+
+    #[test]
+    fn test_analyze_solved() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.solve_by_backtracking();
+        sudoku.calc_all_notes();
+        assert!(sudoku.analyze().is_empty());
+    }
+
+    #[test]
+    fn test_is_forced() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let next = sudoku.next_step();
+        let cell = next.removals.sets_cell.unwrap();
+        assert_eq!(
+            sudoku.is_forced(cell.row, cell.col, cell.num),
+            Some(next.strategy)
+        );
+        let mut solved = sudoku.clone();
+        solved.solve_by_backtracking();
+        let mut forced = 0;
+        for Coord { row, col } in sudoku.empty_cells() {
+            for num in 1..=9 {
+                if sudoku.is_forced(row, col, num).is_some() {
+                    assert_eq!(solved.get_num(row, col), num);
+                    forced += 1;
+                }
+            }
+        }
+        assert!(forced > 1);
+        // A guess isn't forced
+        assert_eq!(sudoku.is_forced(0, 0, 9), None);
+    }
+}
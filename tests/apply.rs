@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{ApplyError, Cell, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_dry_run_matches_apply() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let before = sudoku.snapshot();
+        let result = sudoku.next_step();
+        let dry_run = sudoku.dry_run(&result).unwrap();
+        assert_eq!(sudoku.snapshot(), before);
+        sudoku.apply(&result).unwrap();
+        assert_eq!(dry_run, sudoku.diff(&before));
+    }
+
+    #[test]
+    fn test_apply_rejects_stale_step() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let result = sudoku.next_step();
+        sudoku.apply(&result).unwrap();
+        let after = sudoku.snapshot();
+        let err = sudoku.apply(&result).unwrap_err();
+        assert!(matches!(err, ApplyError::MissingCandidate(_)));
+        assert!(sudoku.dry_run(&result).is_err());
+        assert_eq!(sudoku.snapshot(), after);
+        assert_eq!(sudoku.undo_stack.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_rejects_occupied_cell() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let mut result = sudoku.next_step();
+        result.removals.candidates_about_to_be_removed.clear();
+        result.removals.sets_cell = Some(Cell {
+            row: 0,
+            col: 2,
+            num: 8,
+        });
+        assert_eq!(
+            sudoku.validate(&result),
+            Err(ApplyError::Occupied(Cell {
+                row: 0,
+                col: 2,
+                num: 8
+            }))
+        );
+        result.removals.sets_cell = Some(Cell {
+            row: 9,
+            col: 0,
+            num: 1,
+        });
+        assert!(matches!(
+            sudoku.apply(&result),
+            Err(ApplyError::OutOfBounds(_))
+        ));
+    }
+}
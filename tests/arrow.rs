@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Arrow, Strategy, Sudoku};
+
+    const PUZZLE: &str =
+        "000040210000107000003000000000600000000500000000000601027400300000028000000000000";
+    const ARROWS: &str =
+        "r8c8-r9c7-r8c6 r4c5-r5c4-r5c3 r7c4-r6c4-r7c5 r3c2-r3c1-r2c1 r5c7-r5c8-r4c8 r9c6-r9c5-r8c5";
+    const SOLUTION: &str =
+        "769843215482157963153269874378691542614582739295374681927416358531728496846935127";
+
+    #[test]
+    fn test_parse_arrows() {
+        let arrows = Arrow::parse_arrows("r1c1-r2c2-r2c3 r9c9-r8c9").unwrap();
+        assert_eq!(arrows.len(), 2);
+        assert_eq!(arrows[0].circle, (0, 0));
+        assert_eq!(arrows[0].cells, vec![(1, 1), (1, 2)]);
+        assert!(Arrow::parse_arrows("r1c1").is_err());
+        assert!(Arrow::parse_arrows("r1c1-r3c1").is_err());
+        let mut sudoku = Sudoku::new();
+        assert!(sudoku.set_arrows_string("r9c9-r9c10").is_err());
+    }
+
+    #[test]
+    fn test_arrow_restricts_placement() {
+        let mut sudoku = Sudoku::new();
+        sudoku.set_arrows_string("r1c1-r2c1-r3c1").unwrap();
+        // The circle needs room for two digits of at least 1 each
+        assert!(!sudoku.can_place(0, 0, 1));
+        assert!(sudoku.can_place(0, 0, 2));
+        sudoku.board[0][0] = 5;
+        assert!(!sudoku.can_place(1, 0, 5));
+        assert!(sudoku.can_place(1, 0, 4));
+        sudoku.board[1][0] = 3;
+        assert!(sudoku.can_place(2, 0, 2));
+        assert!(!sudoku.can_place(2, 0, 1));
+        sudoku.board[2][0] = 1;
+        assert!(sudoku.has_conflicts());
+    }
+
+    #[test]
+    fn test_solve_arrow() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert!(!sudoku.clone().solve_human_like().is_solved());
+        sudoku.set_arrows_string(ARROWS).unwrap();
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), SOLUTION);
+        assert!(sudoku.rating.contains_key(&Strategy::Arrow));
+    }
+}
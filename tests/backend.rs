@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Backtracking, Board, BruteForceBackend, Error, Solver, Sudoku};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Needs strategies beyond the implemented ones
+    const HARD: &str =
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400";
+
+    /// The backtracker, counting the searches.
+    #[derive(Debug, Default)]
+    struct Counting(Arc<AtomicUsize>);
+
+    impl BruteForceBackend for Counting {
+        fn solutions(&self, sudoku: &Sudoku, max_count: usize) -> Vec<Vec<Vec<u8>>> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Backtracking.solutions(sudoku, max_count)
+        }
+    }
+
+    /// A backend that never finds a solution.
+    #[derive(Debug)]
+    struct Failing;
+
+    impl BruteForceBackend for Failing {
+        fn solutions(&self, _sudoku: &Sudoku, _max_count: usize) -> Vec<Vec<Vec<u8>>> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_backtracking() {
+        let sudoku = Sudoku::from_string(HARD);
+        assert_eq!(Backtracking.count_solutions(&sudoku, 2), 1);
+        assert_eq!(
+            Backtracking.solutions(&sudoku, 2),
+            sudoku.solutions().collect::<Vec<_>>()
+        );
+        let empty = Sudoku::from_string(&"0".repeat(81));
+        assert_eq!(Backtracking.solutions(&empty, 3).len(), 3);
+    }
+
+    #[test]
+    fn test_solver_with_backend() {
+        let board: Board = HARD.parse().unwrap();
+        let searches = Arc::new(AtomicUsize::new(0));
+        let solver = Solver::new().with_backend(Counting(searches.clone()));
+        let solution = solver.solve(&board).unwrap();
+        assert!(!solution.solved_by_logic);
+        assert_eq!(solution, Solver::new().solve(&board).unwrap());
+        // The uniqueness check, and the completion of the solution
+        assert_eq!(searches.load(Ordering::Relaxed), 2);
+        solver.rate(&board).unwrap();
+        assert_eq!(searches.load(Ordering::Relaxed), 3);
+        let failing = Solver::new().with_backend(Failing);
+        assert_eq!(failing.rate(&board).unwrap_err(), Error::NoSolution);
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Board, EMPTY, GridSpec, SolveOutcome, Sudoku, SudokuError, parse_any};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_parse_and_serialize() {
+        let board: Board = PUZZLE.parse().unwrap();
+        assert_eq!(board.spec, GridSpec::CLASSIC);
+        assert_eq!(board.get(0, 2), 8);
+        assert!(board.is_given(0, 2));
+        assert!(!board.is_given(0, 0));
+        assert_eq!(board.serialized(), PUZZLE);
+        assert_eq!(board.original_board(), PUZZLE);
+        assert!(board.to_string().starts_with("0 0 8 0 0 0 0 6 3 \n"));
+        assert!("123".parse::<Board>().is_err());
+    }
+
+    #[test]
+    fn test_solver_leaves_board_untouched() {
+        let board: Board = PUZZLE.parse().unwrap();
+        let mut first = Sudoku::from_board(&board);
+        assert_eq!(first.solve_human_like(), SolveOutcome::Solved);
+        let mut second = Sudoku::from_board(&board);
+        assert_eq!(second.solve_human_like(), SolveOutcome::Solved);
+        assert_eq!(board.serialized(), PUZZLE);
+        assert_eq!(first.serialized(), second.serialized());
+        assert_eq!(first.difficulty(), second.difficulty());
+    }
+
+    #[test]
+    fn test_round_trip_keeps_progress() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        sudoku.set_cell(0, 0, 4).unwrap();
+        let board = sudoku.to_board();
+        assert_eq!(board.get(0, 0), 4);
+        assert!(!board.is_given(0, 0));
+        assert_eq!(board.original_board(), PUZZLE);
+        let restored = Sudoku::from_board(&board);
+        assert_eq!(restored.serialized(), sudoku.serialized());
+        assert_eq!(restored.original_board(), PUZZLE);
+        assert!(!restored.is_given(0, 0));
+        assert_eq!(Board::new(GridSpec::MINI).values, vec![vec![EMPTY; 6]; 6]);
+    }
+
+    #[test]
+    fn test_display_grid() {
+        let mut sudoku = Sudoku::from_string("123456000000000000000000000000000000");
+        sudoku.board[1][0] = 4;
+        let grid = sudoku.to_display_grid();
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(lines.len(), 13);
+        assert_eq!(lines[0], "╔═══╤═══╤═══╦═══╤═══╤═══╗");
+        assert_eq!(lines[1], "║[1]│[2]│[3]║[4]│[5]│[6]║");
+        assert_eq!(lines[2], "╟───┼───┼───╫───┼───┼───╢");
+        assert_eq!(lines[3], "║ 4 │   │   ║   │   │   ║");
+        assert_eq!(lines[4], "╠═══╪═══╪═══╬═══╪═══╪═══╣");
+        assert_eq!(lines[12], "╚═══╧═══╧═══╩═══╧═══╧═══╝");
+    }
+
+    #[test]
+    fn test_parse_any() {
+        assert_eq!(parse_any(PUZZLE).unwrap().serialized(), PUZZLE);
+        let framed: String = PUZZLE
+            .as_bytes()
+            .chunks(9)
+            .map(|row| {
+                let row = std::str::from_utf8(row).unwrap().replace('0', ".");
+                format!("|{}|{}|{}|\n", &row[..3], &row[3..6], &row[6..])
+            })
+            .collect();
+        assert_eq!(parse_any(&framed).unwrap().serialized(), PUZZLE);
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let pencilmarks = parse_any(&sudoku.pencilmarks()).unwrap();
+        assert_eq!(pencilmarks.candidates, sudoku.candidates);
+        let message = |input: &str| match parse_any(input) {
+            Err(SudokuError::Parse(message)) => message,
+            other => panic!(
+                "expected a parse error, got {:?}",
+                other.map(|s| s.serialized())
+            ),
+        };
+        assert_eq!(
+            message(&PUZZLE[1..]),
+            "must contain exactly 36, 81 or 256 cells, found 80"
+        );
+        assert_eq!(
+            message(&format!("{}x\n{}", &PUZZLE[..40], &PUZZLE[40..])),
+            "invalid characters for a 9×9 grid: 'x' at line 1, column 41"
+        );
+        assert!(message(&"1234567".repeat(6)[..36]).contains("'7' at line 1, column 7"));
+        assert!(message(&"x".repeat(81)).ends_with("and 71 more"));
+        assert!(parse_any(&"0".repeat(1 << 17)).is_err());
+    }
+
+    /// Feed random text to `parse_any`, as a fuzzer would.
+    #[test]
+    fn test_parse_any_never_panics() {
+        let alphabet: Vec<char> = "0123456789aAfFgG. \n|-+[]:═║é".chars().collect();
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move |bound: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize % bound
+        };
+        for _ in 0..20_000 {
+            let len = [next(100), 81, 36, 256, 300][next(5)];
+            let input: String = (0..len).map(|_| alphabet[next(alphabet.len())]).collect();
+            let _ = parse_any(&input);
+        }
+    }
+}
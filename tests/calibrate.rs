@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{
+        Reference, Strategy, Sudoku, SudokuError, Weights, calibrate, parse_references,
+    };
+
+    const PUZZLES: [&str; 6] = [
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+        "610040000090008273000705146100000000400609051005480900000010009740000300020000600",
+        "300000790906001000000579000000102000060000504048050001080400005052000009003800060",
+        "020000689000500001400000020100005708030016090000007003003002157009700842200800000",
+        "030120008000060000006000005020400500701800043058000100690080200010000000000610709",
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000",
+    ];
+
+    #[test]
+    fn test_weights_profile() {
+        let mut weights = Weights::default();
+        assert_eq!(weights.effort(&Strategy::XWing), 140.0);
+        weights.set_effort(Strategy::HiddenSingle, 1.25);
+        let profile = weights.to_string();
+        assert!(profile.contains("HiddenSingle 1.25\n"));
+        assert_eq!(profile.parse::<Weights>(), Ok(weights));
+        let partial = "# only singles\nObviousSingle 2\n"
+            .parse::<Weights>()
+            .unwrap();
+        assert_eq!(partial.effort(&Strategy::ObviousSingle), 2.0);
+        assert_eq!(partial.effort(&Strategy::XWing), 140.0);
+        assert!("Guessing 1000".parse::<Weights>().is_err());
+    }
+
+    #[test]
+    fn test_difficulty_with_default_weights() {
+        let mut sudoku = Sudoku::from_string(PUZZLES[5]);
+        sudoku.solve_human_like();
+        let difficulty = sudoku.difficulty_with(&Weights::default());
+        assert!((difficulty - sudoku.difficulty()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_calibrate_to_scaled_ratings() {
+        // A reference scale that is a tenth of ours can be matched exactly
+        let references: Vec<Reference> = PUZZLES
+            .iter()
+            .map(|puzzle| {
+                let mut sudoku = Sudoku::from_string(puzzle);
+                let solved = sudoku.solve_human_like().is_solved();
+                (solved, sudoku.difficulty(), puzzle)
+            })
+            .filter(|&(solved, _, _)| solved)
+            .map(|(_, difficulty, puzzle)| Reference {
+                puzzle: puzzle.to_string(),
+                rating: difficulty / 10.0,
+            })
+            .collect();
+        assert!(references.len() > 3);
+        let calibration = calibrate(&references).unwrap();
+        assert_eq!(calibration.puzzles, references.len());
+        assert!(calibration.correlation_before > 0.999);
+        assert!(calibration.correlation_after > 0.999);
+        let mut sudoku = Sudoku::from_string(PUZZLES[5]);
+        sudoku.solve_human_like();
+        let difficulty = sudoku.difficulty_with(&calibration.weights);
+        assert!((difficulty - sudoku.difficulty() / 10.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_calibrate_skips_complete_grids() {
+        let mut solved = Sudoku::from_string(PUZZLES[5]);
+        solved.solve_human_like();
+        let mut references: Vec<Reference> = PUZZLES
+            .iter()
+            .map(|puzzle| Reference {
+                puzzle: puzzle.to_string(),
+                rating: 3.0,
+            })
+            .collect();
+        let puzzles = calibrate(&references).unwrap().puzzles;
+        references.push(Reference {
+            puzzle: solved.serialized(),
+            rating: 1.0,
+        });
+        let calibration = calibrate(&references).unwrap();
+        assert_eq!(calibration.puzzles, puzzles);
+        assert!(
+            Strategy::ALL
+                .iter()
+                .all(|strategy| calibration.weights.effort(strategy).is_finite())
+        );
+    }
+
+    #[test]
+    fn test_parse_references() {
+        let text = format!("# rating puzzle\n\n2.5 {}\n", PUZZLES[0]);
+        let references = parse_references(&text).unwrap();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].rating, 2.5);
+        assert_eq!(references[0].puzzle, PUZZLES[0]);
+        assert!(matches!(
+            parse_references("easy 0000"),
+            Err(SudokuError::Parse(_))
+        ));
+        assert!(calibrate(&references).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{ConjugatePair, Coord, LinkKind, Sudoku, Unit};
+
+    /// Has a claiming pair of 7s in row 2
+    const POSITION: &str =
+        "318005406000603810006080503864952137123476958795318264030500780000007305000039641";
+
+    #[test]
+    fn test_candidate_queries() {
+        let mut sudoku = Sudoku::new();
+        sudoku.calc_all_notes();
+        assert_eq!(sudoku.candidate_count(), 729);
+        assert_eq!(sudoku.bivalue_cells().count(), 0);
+        assert_eq!(sudoku.cells_with_candidate(5).count(), 81);
+        assert!(sudoku.conjugate_pairs(5).is_empty());
+
+        let mut sudoku = Sudoku::from_string(POSITION);
+        sudoku.calc_all_notes();
+        let counts: Vec<usize> = sudoku
+            .empty_cells()
+            .map(|c| sudoku.candidates[c.row][c.col].len())
+            .collect();
+        assert_eq!(sudoku.candidate_count(), counts.iter().sum::<usize>());
+        let bivalue: Vec<Coord> = sudoku.bivalue_cells().collect();
+        assert_eq!(bivalue.len(), counts.iter().filter(|&&n| n == 2).count());
+        assert!(
+            sudoku
+                .trivalue_cells()
+                .all(|c| sudoku.candidates[c.row][c.col].len() == 3)
+        );
+        let sevens: Vec<Coord> = sudoku.cells_with_candidate(7).collect();
+        assert!(sevens.contains(&Coord::new(1, 1)));
+        assert!(sevens.iter().all(|c| sudoku.get_num(c.row, c.col) == 0));
+    }
+
+    #[test]
+    fn test_conjugate_pairs() {
+        let mut sudoku = Sudoku::from_string(POSITION);
+        sudoku.calc_all_notes();
+        let pairs = sudoku.conjugate_pairs(7);
+        assert!(pairs.contains(&ConjugatePair {
+            unit: Unit::Row,
+            index: 1,
+            num: 7,
+            cells: [Coord::new(1, 1), Coord::new(1, 2)],
+        }));
+        let link = pairs[0].link();
+        assert_eq!(link.kind, LinkKind::Strong);
+        assert_eq!(link.from.num, 7);
+        assert_eq!(
+            (link.from.row, link.from.col, link.to.row, link.to.col),
+            (
+                pairs[0].cells[0].row,
+                pairs[0].cells[0].col,
+                pairs[0].cells[1].row,
+                pairs[0].cells[1].col
+            )
+        );
+        for pair in &pairs {
+            let places: Vec<Coord> = sudoku
+                .unit_cells(&pair.unit, pair.index)
+                .into_iter()
+                .filter(|&(row, col)| sudoku.candidates[row][col].contains(&7))
+                .map(|(row, col)| Coord::new(row, col))
+                .collect();
+            assert_eq!(places, pair.cells);
+        }
+    }
+}
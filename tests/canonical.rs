@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Constraints, Sudoku, find_duplicates};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+    const OTHER: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    /// Transpose, swap the first two bands, swap two columns within a stack, and
+    /// relabel the digits.
+    fn transformed(puzzle: &str) -> String {
+        let cells: Vec<u8> = puzzle.bytes().map(|b| b - b'0').collect();
+        let relabel = [0, 5, 9, 1, 3, 2, 8, 7, 4, 6];
+        let mut out = String::new();
+        for row in 0..9 {
+            let row = match row / 3 {
+                0 => row + 3,
+                1 => row - 3,
+                _ => row,
+            };
+            for col in 0..9 {
+                let col = match col {
+                    6 => 8,
+                    8 => 6,
+                    _ => col,
+                };
+                // Transposed: read column-wise
+                let num = cells[col * 9 + row];
+                out.push((b'0' + relabel[num as usize]) as char);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_canonical_form_of_isomorphic_puzzles() {
+        let canonical = Sudoku::from_string(PUZZLE).canonical_form().unwrap();
+        assert_eq!(canonical.len(), 81);
+        assert_eq!(canonical.chars().filter(|&c| c != '0').count(), 24);
+        let variant = transformed(PUZZLE);
+        assert_ne!(variant, PUZZLE);
+        assert_eq!(
+            Sudoku::from_string(&variant).canonical_form().unwrap(),
+            canonical
+        );
+        // The canonical form is its own canonical form, and the smallest one
+        assert_eq!(
+            Sudoku::from_string(&canonical).canonical_form().unwrap(),
+            canonical
+        );
+        assert!(canonical.as_str() <= PUZZLE && canonical <= variant);
+        assert_ne!(
+            Sudoku::from_string(OTHER).canonical_form().unwrap(),
+            canonical
+        );
+    }
+
+    #[test]
+    fn test_canonical_form_needs_classic_rules() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.set_constraints(Constraints::ANTI_KNIGHT);
+        assert!(sudoku.canonical_form().is_err());
+    }
+
+    #[test]
+    fn test_find_duplicates() {
+        let variant = transformed(PUZZLE);
+        let puzzles = [PUZZLE, OTHER, variant.as_str(), PUZZLE];
+        assert_eq!(find_duplicates(&puzzles), Ok(vec![vec![0, 2, 3]]));
+        assert!(find_duplicates(&["123"]).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::Checkpoint;
+
+    #[test]
+    fn test_round_trip() {
+        let checkpoint = Checkpoint {
+            line: 1200,
+            output_len: 109_200,
+        };
+        assert_eq!(checkpoint.to_string(), "line 1200\noutput 109200\n");
+        assert_eq!(checkpoint.to_string().parse::<Checkpoint>(), Ok(checkpoint));
+        assert!("line x".parse::<Checkpoint>().is_err());
+        assert!("lines 3".parse::<Checkpoint>().is_err());
+        assert!("line".parse::<Checkpoint>().is_err());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("batch-{}.checkpoint", std::process::id()));
+        assert_eq!(Checkpoint::load(&path).unwrap(), None);
+        let checkpoint = Checkpoint {
+            line: 7,
+            output_len: 42,
+        };
+        checkpoint.save(&path).unwrap();
+        assert_eq!(Checkpoint::load(&path).unwrap(), Some(checkpoint));
+        std::fs::write(&path, "nonsense").unwrap();
+        assert!(Checkpoint::load(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
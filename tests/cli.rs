@@ -0,0 +1,43 @@
+#[cfg(all(test, feature = "dump"))]
+mod tests {
+    use std::process::{Command, Output};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+    const EASY: &str =
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+
+    fn rate(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_rate"))
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_several_puzzles() {
+        let output = rate(&[PUZZLE, EASY]);
+        assert_eq!(output.status.code(), Some(0));
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with(&format!("{} solved=yes", PUZZLE)));
+        assert!(lines[1].starts_with(&format!("{} solved=yes", EASY)));
+
+        // Without the 4 in r8c9 the puzzle has four solutions
+        let ambiguous = format!("{}0{}", &PUZZLE[..71], &PUZZLE[72..]);
+        let output = rate(&[EASY, &ambiguous]);
+        assert_eq!(output.status.code(), Some(3));
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(stdout.lines().count(), 2);
+        assert!(stdout.contains(&format!("{} error=multiple-solutions", ambiguous)));
+    }
+
+    #[test]
+    fn test_unknown_option() {
+        let output = rate(&[PUZZLE, "--onelin"]);
+        assert_eq!(output.status.code(), Some(2));
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(stdout, "Unknown option: --onelin\n");
+    }
+}
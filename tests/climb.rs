@@ -0,0 +1,40 @@
+#[cfg(all(test, feature = "generate"))]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rate_my_sudoku::Sudoku;
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    fn rate(sudoku: &Sudoku) -> f64 {
+        let mut sudoku = sudoku.clone();
+        assert!(sudoku.solve_human_like().is_solved());
+        sudoku.difficulty()
+    }
+
+    #[test]
+    fn test_harden() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let mut rng = StdRng::seed_from_u64(42);
+        let hardened = sudoku.harden(&mut rng, 40).unwrap();
+        assert_eq!(hardened.count_solutions(2), 1);
+        assert!(rate(&hardened) > rate(&sudoku));
+        assert_eq!(hardened.board, hardened.original_board);
+        // Givens are only ever taken from the solution
+        let mut solved = sudoku.clone();
+        solved.solve_by_backtracking();
+        for cell in hardened.given_cells() {
+            assert_eq!(
+                hardened.get_num(cell.row, cell.col),
+                solved.get_num(cell.row, cell.col)
+            );
+        }
+    }
+
+    #[test]
+    fn test_harden_needs_unique_solution() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(Sudoku::new().harden(&mut rng, 1).is_none());
+    }
+}
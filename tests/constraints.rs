@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Constraints, Sudoku};
+
+    #[test]
+    fn test_constraint_flags() {
+        let both = Constraints::ANTI_KNIGHT | Constraints::ANTI_KING;
+        assert!(both.contains(Constraints::ANTI_KNIGHT));
+        assert!(both.contains(Constraints::ANTI_KING));
+        assert!(!Constraints::ANTI_KING.contains(Constraints::ANTI_KNIGHT));
+        assert!(Constraints::NONE.is_empty());
+    }
+
+    #[test]
+    fn test_constraint_peers() {
+        let mut sudoku = Sudoku::new();
+        assert!(sudoku.constraint_peers(4, 4).is_empty());
+        sudoku.set_constraints(Constraints::ANTI_KNIGHT);
+        assert_eq!(sudoku.constraint_peers(4, 4).len(), 8);
+        assert_eq!(sudoku.constraint_peers(0, 0).len(), 2);
+        sudoku.set_constraints(Constraints::ANTI_KNIGHT | Constraints::ANTI_KING);
+        assert_eq!(sudoku.constraint_peers(0, 0).len(), 5);
+    }
+
+    #[test]
+    fn test_can_place_with_constraints() {
+        let mut sudoku = Sudoku::new();
+        sudoku.board[2][2] = 5;
+        assert!(sudoku.can_place(3, 3, 5));
+        assert!(sudoku.can_place(4, 3, 5));
+        sudoku.set_constraints(Constraints::ANTI_KING);
+        assert!(!sudoku.can_place(3, 3, 5));
+        assert!(sudoku.can_place(4, 3, 5));
+        sudoku.set_constraints(Constraints::ANTI_KNIGHT);
+        assert!(sudoku.can_place(3, 3, 5));
+        assert!(!sudoku.can_place(4, 3, 5));
+        assert!(!sudoku.has_conflicts());
+        sudoku.board[4][3] = 5;
+        assert!(sudoku.has_conflicts());
+    }
+
+    #[test]
+    fn test_anti_knight() {
+        let puzzle =
+            "023056700400080020000103050030004090000007230800200000312600070040070002000012040";
+        assert!(Sudoku::from_string(puzzle).count_solutions(2) > 1);
+        let mut sudoku = Sudoku::from_string(puzzle);
+        sudoku.set_constraints(Constraints::ANTI_KNIGHT);
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(
+            sudoku.serialized(),
+            "123456789456789123789123456231564897564897231897231564312645978645978312978312645"
+        );
+    }
+
+    #[test]
+    fn test_anti_king() {
+        let puzzle =
+            "003006780000000020700003050010000090305800004890010000530070908042008500008501030";
+        assert!(Sudoku::from_string(puzzle).count_solutions(2) > 1);
+        let mut sudoku = Sudoku::from_string(puzzle);
+        sudoku.set_constraints(Constraints::ANTI_KING);
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(
+            sudoku.serialized(),
+            "123456789456789123789123456214365897365897214897214365531672948642938571978541632"
+        );
+    }
+}
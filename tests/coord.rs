@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Candidate, Cell, Constraints, Coord, Edge, Sudoku};
+
+    #[test]
+    fn test_notation() {
+        assert_eq!("r3c5".parse::<Coord>(), Ok(Coord::new(2, 4)));
+        assert_eq!("R3C5".parse::<Coord>(), Ok(Coord::new(2, 4)));
+        assert_eq!("C5".parse::<Coord>(), Ok(Coord::new(2, 4)));
+        assert_eq!("i9".parse::<Coord>(), Ok(Coord::new(8, 8)));
+        assert!("r0c5".parse::<Coord>().is_err());
+        assert!("5C".parse::<Coord>().is_err());
+        assert!("r3".parse::<Coord>().is_err());
+        assert_eq!(Coord::new(2, 4).to_string(), "r3c5");
+        assert_eq!(Coord::new(2, 4).to_a1(), "C5");
+        let cell = Cell {
+            row: 0,
+            col: 8,
+            num: 7,
+        };
+        assert_eq!(cell.to_string(), "r1c9=7");
+        assert_eq!("r1c9=7".parse::<Cell>(), Ok(cell.clone()));
+        assert_eq!("A9=7".parse::<Cell>(), Ok(cell));
+        assert!("r1c9".parse::<Cell>().is_err());
+        assert!("r1c9=x".parse::<Cell>().is_err());
+        let candidate = Candidate {
+            row: 8,
+            col: 0,
+            num: 3,
+        };
+        assert_eq!(candidate.to_string(), "r9c1<>3");
+        assert_eq!("r9c1<>3".parse::<Candidate>(), Ok(candidate.clone()));
+        assert_eq!("I1<>3".parse::<Candidate>(), Ok(candidate));
+        assert!("r9c1=3".parse::<Candidate>().is_err());
+        assert_eq!(
+            Edge::parse_edges("X:A1-A2").unwrap(),
+            Edge::parse_edges("X:r1c1-r1c2").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_peers() {
+        let mut sudoku = Sudoku::new();
+        let coord = Coord::new(4, 4);
+        assert_eq!(coord.box_index(&sudoku), 4);
+        assert_eq!(coord.peers(&sudoku).len(), 20);
+        assert!(coord.sees(Coord::new(4, 0), &sudoku));
+        assert!(coord.sees(Coord::new(3, 3), &sudoku));
+        assert!(!coord.sees(Coord::new(0, 0), &sudoku));
+        assert!(!coord.sees(coord, &sudoku));
+        sudoku.set_constraints(Constraints::DIAGONAL);
+        assert!(coord.sees(Coord::new(0, 0), &sudoku));
+        assert_eq!(coord.peers(&sudoku).len(), 32);
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Corpus, Record, Records};
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    #[test]
+    fn test_records() {
+        let text = format!("# comment\n{}\n\n{},3.4,easy\r\n", PUZZLE, PUZZLE);
+        let records: Vec<Record> = Records::new(&text).collect();
+        assert_eq!(
+            records,
+            [
+                Record {
+                    line: 2,
+                    puzzle: PUZZLE,
+                    fields: ""
+                },
+                Record {
+                    line: 4,
+                    puzzle: PUZZLE,
+                    fields: "3.4,easy"
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_open() {
+        let path = std::env::temp_dir().join(format!("corpus-{}.sdm", std::process::id()));
+        std::fs::write(&path, format!("{}\n{}\n", PUZZLE, PUZZLE)).unwrap();
+        {
+            let corpus = Corpus::open(&path).unwrap();
+            assert_eq!(corpus.records().count(), 2);
+            assert!(corpus.records().all(|record| record.puzzle == PUZZLE));
+        }
+
+        std::fs::write(&path, "").unwrap();
+        assert_eq!(Corpus::open(&path).unwrap().records().count(), 0);
+
+        std::fs::write(&path, [0xff, 0xfe]).unwrap();
+        let error = Corpus::open(&path).err().unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+        assert!(Corpus::open(&path).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{CostModel, Strategy, Sudoku, Weights};
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    #[test]
+    fn test_without_noise_matches_difficulty() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let mut solved = sudoku.clone();
+        assert!(solved.solve_human_like().is_solved());
+        let plain = sudoku.adjusted_difficulty(&CostModel::new().with_noise_weight(0.0));
+        assert!((plain - solved.difficulty()).abs() < 1e-9);
+
+        let mut weights = Weights::default();
+        weights.set_effort(Strategy::HiddenSingle, 20.0);
+        let model = CostModel::new()
+            .with_weights(weights.clone())
+            .with_noise_weight(0.0);
+        assert!(
+            (sudoku.adjusted_difficulty(&model) - solved.difficulty_with(&weights)).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_noise_raises_difficulty() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let plain = sudoku.adjusted_difficulty(&CostModel::new().with_noise_weight(0.0));
+        let adjusted = sudoku.adjusted_difficulty(&CostModel::new());
+        let heavier = sudoku.adjusted_difficulty(&CostModel::new().with_noise_weight(2.0));
+        assert!(plain < adjusted && adjusted < heavier);
+        assert!(adjusted <= 2.0 * plain);
+    }
+
+    #[test]
+    fn test_search_effort() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let step = sudoku.next_step();
+        let effort = sudoku.search_effort(&step);
+        assert!(effort.cells_scanned > 0);
+        assert!((0.0..=1.0).contains(&effort.noise));
+
+        // With all notes in place, every cell looks the same
+        let mut cluttered = Sudoku::from_string(PUZZLE);
+        for row in 0..9 {
+            for col in 0..9 {
+                cluttered.candidates[row][col] = (1..=9).collect();
+            }
+        }
+        assert_eq!(cluttered.search_effort(&step).noise, 1.0);
+        assert!(effort.noise < 1.0);
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Constraints, Sudoku};
+    use std::collections::HashSet;
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    /// Model of the DIMACS formula for the solution, in the output format of SAT solvers.
+    fn model(solved: &Sudoku) -> String {
+        let size = solved.size();
+        let mut literals = Vec::new();
+        for row in 0..size {
+            for col in 0..size {
+                for num in 1..=size as u8 {
+                    let var = solved.dimacs_variable(row, col, num) as i64;
+                    literals.push(if solved.get_num(row, col) == num {
+                        var
+                    } else {
+                        -var
+                    });
+                }
+            }
+        }
+        let literals: Vec<String> = literals.iter().map(i64::to_string).collect();
+        format!("c solved\ns SATISFIABLE\nv {} 0\n", literals.join(" "))
+    }
+
+    #[test]
+    fn test_exact_cover() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let cover = sudoku.exact_cover();
+        assert_eq!(cover.primary, 4 * 81);
+        assert_eq!(cover.columns.len(), 4 * 81);
+        let givens = sudoku.givens_count();
+        assert_eq!(cover.rows.len(), givens + 9 * (81 - givens));
+        assert!(cover.matrix.iter().all(|row| row.len() == 4));
+
+        let mut solved = sudoku.clone();
+        solved.solve_by_backtracking();
+        let chosen: Vec<usize> = (0..cover.rows.len())
+            .filter(|&i| {
+                let candidate = &cover.rows[i];
+                solved.get_num(candidate.row, candidate.col) == candidate.num
+            })
+            .collect();
+        let covered: HashSet<usize> = chosen
+            .iter()
+            .flat_map(|&i| cover.matrix[i].iter().cloned())
+            .collect();
+        assert_eq!(covered.len(), cover.primary);
+
+        let mut imported = sudoku.clone();
+        imported.import_cover(&cover, &chosen).unwrap();
+        assert_eq!(imported.serialized(), solved.serialized());
+        assert!(imported.import_cover(&cover, &chosen[1..]).is_err());
+
+        let text = cover.to_string();
+        assert_eq!(text.lines().count(), 1 + cover.rows.len());
+        assert!(text.starts_with("r1c1 r1c2"));
+    }
+
+    #[test]
+    fn test_secondary_columns() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.set_constraints(Constraints::DIAGONAL);
+        sudoku.add_unit(vec![(0, 0), (4, 5)]).unwrap();
+        let cover = sudoku.exact_cover();
+        assert_eq!(cover.primary, 4 * 81 + 2 * 9);
+        assert_eq!(cover.columns.len(), cover.primary + 9);
+        assert!(cover.to_string().lines().next().unwrap().contains(" | "));
+    }
+
+    #[test]
+    fn test_dimacs() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let dimacs = sudoku.to_dimacs();
+        let header: Vec<&str> = dimacs.lines().next().unwrap().split(' ').collect();
+        assert_eq!(header[..3], ["p", "cnf", "729"]);
+        let clauses: usize = header[3].parse().unwrap();
+        assert_eq!(dimacs.lines().count(), 1 + clauses);
+        assert!(dimacs.lines().skip(1).all(|line| line.ends_with(" 0")));
+        assert!(dimacs.contains(&format!("\n{} 0\n", sudoku.dimacs_variable(0, 2, 8))));
+
+        let mut solved = sudoku.clone();
+        solved.solve_by_backtracking();
+        let mut imported = sudoku.clone();
+        imported.import_dimacs_model(&model(&solved)).unwrap();
+        assert_eq!(imported.serialized(), solved.serialized());
+    }
+
+    #[test]
+    fn test_invalid_models() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert!(sudoku.import_dimacs_model("s UNSATISFIABLE\n").is_err());
+        assert!(sudoku.import_dimacs_model("v 1 x 0\n").is_err());
+        assert!(sudoku.import_dimacs_model("v 730 0\n").is_err());
+        // Every cell gets the digit 1
+        let ones: Vec<String> = (0..81).map(|i| (i * 9 + 1).to_string()).collect();
+        assert!(sudoku.import_dimacs_model(&ones.join(" ")).is_err());
+        assert_eq!(
+            sudoku.serialized(),
+            Sudoku::from_string(PUZZLE).serialized()
+        );
+    }
+}
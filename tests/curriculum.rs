@@ -0,0 +1,61 @@
+#[cfg(all(test, feature = "generate"))]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rate_my_sudoku::{DifficultyRange, Strategy, Sudoku};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_generate_curriculum() {
+        let levels = [
+            DifficultyRange::new(4.0, 4.0),
+            DifficultyRange::new(4.0, 5.0),
+            DifficultyRange::new(5.0, 12.0),
+        ];
+        let lessons =
+            Sudoku::generate_curriculum(&levels, 2, &mut StdRng::seed_from_u64(1)).unwrap();
+        assert_eq!(lessons.len(), 3);
+        assert_eq!(lessons[0].technique, Strategy::LastDigit);
+        // Each lesson introduces a different technique
+        let techniques: HashSet<&Strategy> = lessons.iter().map(|l| &l.technique).collect();
+        assert_eq!(techniques.len(), 3);
+        let mut known = HashSet::new();
+        for (lesson, range) in lessons.iter().zip(&levels) {
+            assert_eq!(lesson.range, *range);
+            assert_eq!(lesson.puzzles.len(), 2);
+            known.insert(lesson.technique.clone());
+            for puzzle in &lesson.puzzles {
+                assert!(puzzle.solved);
+                assert!(range.contains(puzzle.difficulty));
+                assert!(puzzle.strategies.contains_key(&lesson.technique));
+                assert!(puzzle.strategies.keys().all(|s| known.contains(s)));
+                assert_eq!(Sudoku::from_string(&puzzle.puzzle).count_solutions(2), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_curriculum_without_new_technique() {
+        // The second level admits only the technique of the first
+        let levels = [
+            DifficultyRange::new(4.0, 4.0),
+            DifficultyRange::new(4.0, 4.0),
+        ];
+        assert!(Sudoku::generate_curriculum(&levels, 1, &mut StdRng::seed_from_u64(2)).is_err());
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(
+            "3.0..5.5".parse::<DifficultyRange>(),
+            Ok(DifficultyRange::new(3.0, 5.5))
+        );
+        assert_eq!(
+            "4..4".parse::<DifficultyRange>(),
+            Ok(DifficultyRange::new(4.0, 4.0))
+        );
+        for invalid in ["3.0", "5..3", "a..b", "..5"] {
+            assert!(invalid.parse::<DifficultyRange>().is_err());
+        }
+    }
+}
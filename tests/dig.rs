@@ -0,0 +1,105 @@
+#[cfg(all(test, feature = "generate"))]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rate_my_sudoku::{Board, DifficultyRange, DigOptions, EMPTY, GridSpec, Sudoku, Symmetry};
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    fn solution() -> Board {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.solve_by_backtracking();
+        Board::from_givens(GridSpec::CLASSIC, sudoku.board)
+    }
+
+    #[test]
+    fn test_dig() {
+        let solution = solution();
+        let mut rng = StdRng::seed_from_u64(7);
+        let puzzle = Sudoku::dig(&solution, &DigOptions::new(), &mut rng).unwrap();
+        assert_eq!(puzzle.count_solutions(2), 1);
+        assert!(puzzle.givens_count() < 40);
+        assert_eq!(puzzle.original_board, puzzle.board);
+        for coord in puzzle.given_cells() {
+            assert_eq!(
+                puzzle.get_num(coord.row, coord.col),
+                solution.get(coord.row, coord.col)
+            );
+        }
+        // Removing any further given breaks uniqueness
+        for coord in puzzle.given_cells() {
+            let mut fewer = puzzle.clone();
+            fewer.board[coord.row][coord.col] = EMPTY;
+            assert!(fewer.count_solutions(2) > 1);
+        }
+    }
+
+    #[test]
+    fn test_dig_options() {
+        let solution = solution();
+        let mut rng = StdRng::seed_from_u64(7);
+        let options = DigOptions::new()
+            .with_min_givens(30)
+            .with_symmetry(Symmetry::Rotational);
+        let puzzle = Sudoku::dig(&solution, &options, &mut rng).unwrap();
+        assert!(puzzle.givens_count() >= 30);
+        assert_eq!(puzzle.count_solutions(2), 1);
+        for row in 0..9 {
+            for col in 0..9 {
+                assert_eq!(
+                    puzzle.board[row][col] == EMPTY,
+                    puzzle.board[8 - row][8 - col] == EMPTY
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_solution() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let incomplete: Board = PUZZLE.parse().unwrap();
+        assert!(Sudoku::dig(&incomplete, &DigOptions::new(), &mut rng).is_err());
+        let ones: Board = "1".repeat(81).parse().unwrap();
+        assert!(Sudoku::dig(&ones, &DigOptions::new(), &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_orbit() {
+        assert_eq!(Symmetry::None.orbit(9, 1, 2), [(1, 2)]);
+        assert_eq!(Symmetry::Rotational.orbit(9, 1, 2), [(1, 2), (7, 6)]);
+        assert_eq!(Symmetry::Rotational.orbit(9, 4, 4), [(4, 4)]);
+        assert_eq!(Symmetry::Horizontal.orbit(9, 1, 2), [(1, 2), (7, 2)]);
+        assert_eq!(Symmetry::Vertical.orbit(9, 1, 2), [(1, 2), (1, 6)]);
+        assert_eq!(Symmetry::Diagonal.orbit(9, 1, 2), [(1, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn test_generate_in_range() {
+        let range = DifficultyRange::new(4.0, 6.0);
+        let options = DigOptions::new()
+            .with_min_givens(26)
+            .with_symmetry(Symmetry::Rotational);
+        let generate = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20)
+                .find_map(|_| {
+                    Sudoku::generate_in_range(GridSpec::CLASSIC, &options, &range, &mut rng)
+                })
+                .unwrap()
+        };
+        let rated = generate(7);
+        assert!(rated.solved);
+        assert!(range.contains(rated.difficulty));
+        assert!(rated.givens >= 26);
+        let puzzle = Sudoku::from_string(&rated.puzzle);
+        assert_eq!(puzzle.count_solutions(2), 1);
+        assert!(
+            puzzle
+                .analyze_pattern()
+                .symmetries
+                .contains(&Symmetry::Rotational)
+        );
+        assert_eq!(generate(7).puzzle, rated.puzzle);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{DigitSet, Strategy, Sudoku};
+
+    #[test]
+    fn test_digit_set() {
+        let mut digits = DigitSet::from([5, 2, 8]);
+        assert_eq!(digits.len(), 3);
+        assert!(digits.contains(&2));
+        assert!(!digits.contains(&3));
+        assert!(!digits.insert(5));
+        assert!(digits.insert(1));
+        assert_eq!(digits.iter().collect::<Vec<_>>(), vec![1, 2, 5, 8]);
+        assert!(digits.remove(&1));
+        assert!(!digits.remove(&1));
+        assert_eq!(format!("{:?}", digits), "{2, 5, 8}");
+        assert_eq!(digits.first(), Some(2));
+
+        let other = DigitSet::from([2, 3]);
+        assert_eq!(digits.union(&other), DigitSet::from([2, 3, 5, 8]));
+        assert_eq!(digits & other, DigitSet::from([2]));
+        assert_eq!(digits - other, DigitSet::from([5, 8]));
+        assert!(DigitSet::from([2, 8]).is_subset(&digits));
+        assert!(DigitSet::from([1, 3]).is_disjoint(&digits));
+        assert_eq!(DigitSet::all(9).len(), 9);
+        assert!(!DigitSet::all(9).contains(&0));
+        assert!(DigitSet::all(16).contains(&16));
+
+        digits.retain(|&num| num > 4);
+        assert_eq!(digits, DigitSet::from([5, 8]));
+        digits.clear();
+        assert!(digits.is_empty());
+    }
+
+    #[test]
+    fn test_ordered_notes_and_rating() {
+        let mut sudoku = Sudoku::new();
+        sudoku.set_board_string(
+            "000000010400000000020000000000050407008000300001090000300400200050100000000806000",
+        );
+        sudoku.calc_all_notes();
+        let notes: Vec<u8> = sudoku.get_notes(0, 0).iter().collect();
+        let mut sorted = notes.clone();
+        sorted.sort();
+        assert_eq!(notes, sorted);
+
+        sudoku.solve_human_like();
+        // Ratings iterate in the order the strategies are declared
+        let strategies: Vec<Strategy> = sudoku.rating().keys().cloned().collect();
+        let mut sorted = strategies.clone();
+        sorted.sort();
+        assert_eq!(strategies, sorted);
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Constraints, Edge, EdgeKind, Strategy, Sudoku};
+
+    const XV_PUZZLE: &str =
+        "000000000000000000000000000000000000000000080000000000000000000000000000000000900";
+    const XV_CLUES: &str = "V:r1c4-r1c5 X:r1c6-r2c6 V:r2c2-r3c2 X:r2c4-r2c5 V:r3c1-r4c1 X:r4c1-r4c2 X:r4c5-r4c6 V:r4c6-r4c7 X:r4c8-r4c9 V:r4c9-r5c9 X:r5c1-r5c2 X:r5c5-r5c6 V:r6c3-r6c4 X:r6c4-r6c5 X:r6c5-r7c5 X:r6c8-r6c9 X:r7c2-r8c2 V:r7c3-r7c4 X:r7c7-r8c7 X:r8c1-r9c1 V:r8c2-r8c3 X:r8c6-r8c7 X:r8c8-r9c8 X:r9c6-r9c7";
+    const XV_SOLUTION: &str =
+        "548326179726194853139758426465873291372519684981462537693245718214987365857631942";
+
+    const KROPKI_PUZZLE: &str =
+        "000100000000000000000000000000000000000000000000000000000000000000000000000000000";
+    const KROPKI_CLUES: &str = "W:r1c1-r1c2 W:r1c3-r1c4 B:r1c3-r2c3 W:r1c7-r1c8 B:r1c8-r2c8 W:r2c3-r3c3 W:r2c4-r3c4 W:r2c5-r2c6 W:r2c5-r3c5 W:r2c8-r3c8 B:r3c3-r3c4 B:r3c5-r4c5 W:r4c1-r5c1 W:r4c2-r5c2 B:r4c4-r4c5 W:r4c4-r5c4 W:r4c5-r5c5 W:r4c6-r4c7 W:r4c6-r5c6 W:r4c9-r5c9 B:r5c1-r6c1 W:r5c3-r6c3 W:r5c6-r6c6 B:r5c7-r6c7 W:r6c1-r7c1 W:r6c3-r7c3 W:r6c4-r6c5 W:r6c4-r7c4 W:r6c5-r6c6 W:r7c2-r8c2 W:r7c3-r7c4 W:r7c5-r8c5 W:r7c6-r8c6 W:r7c7-r8c7 W:r7c8-r7c9 B:r8c2-r9c2 W:r8c3-r8c4 W:r8c7-r9c7 W:r8c8-r8c9 B:r8c8-r9c8 W:r8c9-r9c9 W:r9c8-r9c9";
+    const KROPKI_SOLUTION: &str =
+        "762158349194732586853649172379425618285316497416987253537864921941273865628591734";
+
+    #[test]
+    fn test_parse_edges() {
+        let edges = Edge::parse_edges("X:r1c1-r1c2 b:r4c7-r5c7").unwrap();
+        assert_eq!(
+            edges,
+            vec![
+                Edge {
+                    cells: [(0, 0), (0, 1)],
+                    kind: EdgeKind::X
+                },
+                Edge {
+                    cells: [(3, 6), (4, 6)],
+                    kind: EdgeKind::BlackDot
+                },
+            ]
+        );
+        assert!(Edge::parse_edges("X:r1c1-r2c2").is_err());
+        assert!(Edge::parse_edges("Q:r1c1-r1c2").is_err());
+        assert!(Edge::parse_edges("X:r0c1-r1c1").is_err());
+        assert!(Sudoku::new().set_edges_string("X:r9c9-r10c9").is_err());
+    }
+
+    #[test]
+    fn test_edge_kinds() {
+        assert!(EdgeKind::X.allows(3, 7));
+        assert!(EdgeKind::V.allows(1, 4));
+        assert!(EdgeKind::WhiteDot.allows(5, 4));
+        assert!(EdgeKind::BlackDot.allows(3, 6));
+        assert!(!EdgeKind::BlackDot.allows(3, 5));
+    }
+
+    #[test]
+    fn test_edges_restrict_placement() {
+        let mut sudoku = Sudoku::new();
+        sudoku.set_edges_string("X:r1c1-r1c2").unwrap();
+        sudoku.board[0][0] = 3;
+        assert!(sudoku.can_place(0, 1, 7));
+        assert!(!sudoku.can_place(0, 1, 6));
+        // Negative constraint: no X between r1c1 and r2c1
+        assert!(sudoku.can_place(1, 0, 7));
+        sudoku.set_constraints(Constraints::NEGATIVE_XV);
+        assert!(!sudoku.can_place(1, 0, 7));
+        assert!(!sudoku.can_place(1, 0, 2));
+        sudoku.board[0][1] = 6;
+        assert!(sudoku.has_conflicts());
+    }
+
+    #[test]
+    fn test_solve_xv() {
+        let mut sudoku = Sudoku::from_string(XV_PUZZLE);
+        sudoku.set_edges_string(XV_CLUES).unwrap();
+        // Without the negative constraint, the clues aren't sufficient
+        assert!(!sudoku.clone().solve_human_like().is_solved());
+        sudoku.set_constraints(Constraints::NEGATIVE_XV);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), XV_SOLUTION);
+        assert!(sudoku.rating.contains_key(&Strategy::XV));
+    }
+
+    #[test]
+    fn test_solve_kropki() {
+        let mut sudoku = Sudoku::from_string(KROPKI_PUZZLE);
+        sudoku.set_edges_string(KROPKI_CLUES).unwrap();
+        // Without the negative constraint, the clues aren't sufficient
+        assert!(!sudoku.clone().solve_human_like().is_solved());
+        sudoku.set_constraints(Constraints::NEGATIVE_KROPKI);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), KROPKI_SOLUTION);
+        assert!(sudoku.rating.contains_key(&Strategy::Kropki));
+    }
+}
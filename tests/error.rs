@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{
+        Budget, Contradiction, Coord, Error, PlacementError, Sudoku, SudokuError,
+    };
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_parse_errors() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert!(matches!(
+            sudoku.try_set_board_string("123"),
+            Err(SudokuError::Parse(_))
+        ));
+        assert_eq!(sudoku.original_board(), PUZZLE);
+        assert!(matches!(
+            sudoku.set_cages_string("a=3"),
+            Err(SudokuError::Parse(_))
+        ));
+        assert!(matches!(
+            sudoku.add_unit(vec![(0, 0), (0, 0)]),
+            Err(SudokuError::InvalidBoard(_))
+        ));
+    }
+
+    #[test]
+    fn test_variant_unsupported() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.set_thermometers_string("r1c1-r1c2-r1c3").unwrap();
+        assert!(matches!(
+            sudoku.canonical_form(),
+            Err(SudokuError::VariantUnsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_solve_outcomes() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert_eq!(
+            sudoku
+                .solve_human_like_within(&Budget::unlimited().with_max_steps(1))
+                .into_result(),
+            Err(SudokuError::BudgetExceeded)
+        );
+        assert_eq!(sudoku.solve_human_like().into_result(), Ok(()));
+        let contradiction = Contradiction::EmptyCell(Coord::new(0, 0));
+        assert_eq!(
+            SudokuError::from(contradiction.clone()).to_string(),
+            format!("The position can't be solved: {}", contradiction)
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            SudokuError::from(Error::NoSolution),
+            SudokuError::NotSolvable
+        );
+        assert_eq!(
+            SudokuError::from(Error::Parse("too short".to_string())),
+            SudokuError::Parse("too short".to_string())
+        );
+        assert!(matches!(
+            SudokuError::from(PlacementError::Given),
+            SudokuError::InvalidBoard(_)
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{DifficultyLevel, Sudoku};
+
+    /// Solved with obvious singles only
+    const EASY: &str =
+        "407500002000076850801009000000030908005060041040000320000001400000020070370000000";
+    /// Needs an X-Wing
+    const HARD: &str =
+        "580200603000007001000004080070600300000350000100078005063000000009000106720000000";
+
+    #[test]
+    fn test_estimate() {
+        let easy = Sudoku::from_string(EASY).estimate();
+        let hard = Sudoku::from_string(HARD).estimate();
+        assert_eq!((easy.givens, hard.givens), (27, 25));
+        assert!(easy.difficulty < hard.difficulty);
+        assert_eq!(easy.level(), DifficultyLevel::Medium);
+        assert_eq!(hard.level(), DifficultyLevel::Hard);
+        assert!(easy.candidates_per_cell > 1.0 && easy.candidates_per_cell < 9.0);
+        assert!(easy.single_density + easy.bivalue_density <= 1.0);
+    }
+
+    #[test]
+    fn test_estimate_ignores_progress() {
+        let mut sudoku = Sudoku::from_string(HARD);
+        let estimate = sudoku.estimate();
+        sudoku.solve_human_like();
+        assert_eq!(sudoku.estimate(), estimate);
+        let solution = sudoku.serialized();
+        let solved = Sudoku::from_string(&solution).estimate();
+        assert_eq!((solved.givens, solved.difficulty), (81, 0.0));
+    }
+}
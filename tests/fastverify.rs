@@ -0,0 +1,59 @@
+#[cfg(all(test, feature = "fast-verify"))]
+mod tests {
+    use rate_my_sudoku::{Error, Sudoku, verify_batch, verify_unique};
+
+    /// Puzzles from easy to beyond the implemented strategies.
+    const CORPUS: [&str; 4] = [
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+        "610040000090008273000705146100000000400609051005480900000010009740000300020000600",
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+        "000000010400000000020000000000050407008000300001090000300400200050100000000806000",
+    ];
+
+    #[test]
+    fn test_unique() {
+        for puzzle in CORPUS {
+            assert_eq!(verify_unique(puzzle), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(verify_unique("12"), Err(Error::Parse(_))));
+        assert!(matches!(
+            verify_unique(&"0".repeat(36)),
+            Err(Error::Parse(_))
+        ));
+        assert_eq!(
+            verify_unique(&"0".repeat(81)),
+            Err(Error::MultipleSolutions)
+        );
+        let mut conflict = "0".repeat(81);
+        conflict.replace_range(0..2, "11");
+        assert_eq!(verify_unique(&conflict), Err(Error::Conflict));
+        // r1c9 can only hold the 9 that r2c9 holds
+        let unsolvable = format!("123456780000000009{}", "0".repeat(63));
+        assert_eq!(verify_unique(&unsolvable), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn test_agrees_with_backtracker() {
+        let mut puzzles: Vec<String> = CORPUS.iter().map(|puzzle| puzzle.to_string()).collect();
+        // Remove givens to get puzzles with several solutions
+        for puzzle in CORPUS {
+            let first = puzzle.find(|c| c != '0').unwrap();
+            let mut fewer = puzzle.to_string();
+            fewer.replace_range(first..first + 1, "0");
+            puzzles.push(fewer);
+        }
+        let refs: Vec<&str> = puzzles.iter().map(String::as_str).collect();
+        for (puzzle, result) in refs.iter().zip(verify_batch(&refs)) {
+            let expected = match Sudoku::from_string(puzzle).count_solutions(2) {
+                0 => Err(Error::NoSolution),
+                1 => Ok(()),
+                _ => Err(Error::MultipleSolutions),
+            };
+            assert_eq!(result, expected, "{}", puzzle);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::fixtures::{assert_fixtures, parse_fixtures};
+    use rate_my_sudoku::{Candidate, Strategy};
+
+    #[test]
+    fn test_strategy_fixtures() {
+        assert_fixtures(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/strategies.txt"
+        ));
+    }
+
+    #[test]
+    fn test_parse_fixtures() {
+        let fixtures = parse_fixtures(
+            "# A claiming pair\n\
+             strategy: ClaimingPair\n\
+             board: 318005406000603810006080503864952137123476958795318264030500780000007305000039641\n\
+             eliminations: r3c2<>7\n\
+             \n\
+             name: wrong\n\
+             strategy: ClaimingPair\n\
+             board: 318005406000603810006080503864952137123476958795318264030500780000007305000039641\n\
+             eliminations: r3c2<>7, r3c3<>7\n",
+        )
+        .unwrap();
+        assert_eq!(fixtures.len(), 2);
+        assert_eq!(fixtures[0].name, "line 2");
+        assert_eq!(fixtures[0].strategy, Strategy::ClaimingPair);
+        assert!(fixtures[0].check().is_ok());
+        let expected = fixtures[1].eliminations.as_ref().unwrap();
+        assert!(expected.contains(&Candidate {
+            row: 2,
+            col: 2,
+            num: 7
+        }));
+        assert_eq!(
+            fixtures[1].check().unwrap_err(),
+            "wrong (ClaimingPair): eliminations missing: r3c3<>7"
+        );
+        assert!(parse_fixtures("strategy: Unknown\neliminations: none").is_err());
+        assert!(parse_fixtures("strategy: XWing\neliminations: none").is_err());
+    }
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Coord, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_givens() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert_eq!(sudoku.givens_count(), 24);
+        assert!(sudoku.is_given(0, 2));
+        assert!(!sudoku.is_given(0, 0));
+        assert_eq!(sudoku.given_cells().next(), Some(Coord::new(0, 2)));
+        assert_eq!(sudoku.empty_cells().count(), 57);
+        assert_eq!(sudoku.solved_cells().count(), 0);
+        sudoku.calc_all_notes();
+        sudoku.set_cell(0, 0, 4).unwrap();
+        assert_eq!(
+            sudoku.solved_cells().collect::<Vec<_>>(),
+            [Coord::new(0, 0)]
+        );
+        assert_eq!(sudoku.empty_cells().count(), 56);
+        assert_eq!(sudoku.givens_count(), 24);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.solved_cells().count(), 57);
+        assert_eq!(sudoku.empty_cells().count(), 0);
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Move, PlacementError, Strategy, Sudoku, Verdict};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    /// The moves the solver makes, one per placed digit or removed candidate.
+    fn solver_moves(sudoku: &Sudoku) -> Vec<Move> {
+        let mut sudoku = sudoku.clone();
+        sudoku.calc_all_notes();
+        let mut moves = Vec::new();
+        while sudoku.unsolved() {
+            let result = sudoku.next_step();
+            assert_ne!(result.strategy, Strategy::None);
+            if let Some(cell) = &result.removals.sets_cell {
+                moves.push(Move::Place {
+                    row: cell.row,
+                    col: cell.col,
+                    num: cell.num,
+                });
+            } else {
+                moves.extend(result.removals.candidates_about_to_be_removed.iter().map(
+                    |candidate| Move::Eliminate {
+                        row: candidate.row,
+                        col: candidate.col,
+                        num: candidate.num,
+                    },
+                ));
+            }
+            sudoku.apply(&result).unwrap();
+        }
+        moves
+    }
+
+    #[test]
+    fn test_grade_solver_path() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let moves = solver_moves(&sudoku);
+        let grade = sudoku.grade(&moves);
+        assert_eq!(grade.moves.len(), moves.len());
+        assert_eq!(grade.guesses(), 0);
+        assert!(
+            grade
+                .moves
+                .iter()
+                .all(|graded| matches!(graded.verdict, Verdict::Deduced(_)))
+        );
+        assert!(grade.optimal_steps > 0);
+        assert!(grade.efficiency > 0.0 && grade.efficiency <= 1.0);
+    }
+
+    #[test]
+    fn test_grade_guesses() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let moves = [
+            // r1c1 is a 4
+            Move::Place {
+                row: 0,
+                col: 0,
+                num: 9,
+            },
+            Move::Eliminate {
+                row: 0,
+                col: 1,
+                num: 9,
+            },
+            Move::Place {
+                row: 0,
+                col: 1,
+                num: 8,
+            },
+        ];
+        let grade = sudoku.grade(&moves);
+        assert_eq!(grade.moves[0].verdict, Verdict::Guess { correct: false });
+        assert_eq!(grade.moves[1].verdict, Verdict::Redundant);
+        assert_eq!(
+            grade.moves[2].verdict,
+            Verdict::Invalid(PlacementError::Conflict)
+        );
+        assert_eq!(grade.guesses(), 1);
+        assert_eq!(grade.mistakes(), 1);
+        assert_eq!(grade.efficiency, 1.0);
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::Sudoku;
+
+    #[test]
+    fn test_no_guesses_needed() {
+        let sudoku = Sudoku::from_string(
+            "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+        );
+        let stats = sudoku.guess_stats();
+        assert!(stats.solved);
+        assert_eq!(stats.guesses, 0);
+        assert_eq!(stats.depth, 0);
+        assert_eq!(stats.max_depth, 0);
+    }
+
+    #[test]
+    fn test_guesses_beyond_strategies() {
+        // Needs strategies beyond the implemented ones
+        let sudoku = Sudoku::from_string(
+            "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+        );
+        let stats = sudoku.guess_stats();
+        assert!(stats.solved);
+        assert!(stats.depth >= 1);
+        assert!(stats.guesses >= stats.depth);
+        assert!(stats.max_depth >= stats.depth);
+        assert!(stats.max_branching >= 2);
+    }
+
+    #[test]
+    fn test_no_solution() {
+        // Two 1s in the first row
+        let mut puzzle = "0".repeat(81);
+        puzzle.replace_range(0..2, "11");
+        let stats = Sudoku::from_string(&puzzle).guess_stats();
+        assert!(!stats.solved);
+    }
+}
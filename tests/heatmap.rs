@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::Sudoku;
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    #[test]
+    fn test_heatmap() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let heatmap = sudoku.heatmap();
+        assert!(heatmap.solved);
+        for row in 0..9 {
+            for col in 0..9 {
+                assert_eq!(heatmap.get(row, col).is_none(), sudoku.is_given(row, col));
+            }
+        }
+        let mut steps: Vec<usize> = heatmap
+            .cells
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|heat| heat.step)
+            .collect();
+        steps.sort();
+        steps.dedup();
+        assert_eq!(steps.len(), 81 - sudoku.givens_count());
+        assert!(heatmap.max_effort() > 0);
+        assert_eq!(
+            heatmap.max_effort(),
+            heatmap
+                .cells
+                .iter()
+                .flatten()
+                .flatten()
+                .map(|heat| heat.effort)
+                .max()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unsolved_cells_stay_cold() {
+        // Needs strategies beyond the implemented ones
+        let sudoku = Sudoku::from_string(
+            "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+        );
+        let heatmap = sudoku.heatmap();
+        assert!(!heatmap.solved);
+        assert!(heatmap.cells.iter().flatten().any(Option::is_none));
+    }
+}
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Strategy, StrategyCategory};
+
+    #[test]
+    fn test_info() {
+        let info = Strategy::XWing.info();
+        assert_eq!(info.category, StrategyCategory::Fish);
+        assert!(info.url.unwrap().starts_with("https://"));
+        assert_eq!(
+            Strategy::HiddenSingle.info().category,
+            StrategyCategory::Single
+        );
+        assert_eq!(
+            Strategy::PointingPair.info().category,
+            StrategyCategory::Intersection
+        );
+        assert_eq!(Strategy::Kropki.info().category, StrategyCategory::Variant);
+        for strategy in Strategy::ALL {
+            assert!(!strategy.info().description.is_empty(), "{}", strategy);
+        }
+        assert_eq!(StrategyCategory::Uniqueness.to_string(), "uniqueness");
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Candidate, Strategy, Sudoku};
+
+    const REGIONS: &str = "
+        111222333
+        111222333
+        114222333
+        144555666
+        444555666
+        444555666
+        777888999
+        777888999
+        777888999";
+
+    #[test]
+    fn test_parse_regions() {
+        let mut sudoku = Sudoku::new();
+        assert!(!sudoku.is_jigsaw());
+        sudoku.set_regions_string(REGIONS).unwrap();
+        assert!(sudoku.is_jigsaw());
+        assert_eq!(sudoku.regions[2][2], 3);
+        assert_eq!(sudoku.regions[3][0], 0);
+        assert!(sudoku.region_cells[0].contains(&(3, 0)));
+        assert!(sudoku.set_regions_string("111").is_err());
+        assert!(
+            sudoku
+                .set_regions_string(&REGIONS.replacen("1", "2", 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_jigsaw_law_of_leftovers() {
+        let mut sudoku = Sudoku::from_string(
+            "000000700000080003080120000002045600305000090600000040090000060030807002000002000",
+        );
+        sudoku.set_regions_string(REGIONS).unwrap();
+        assert_eq!(sudoku.count_solutions(2), 1);
+        sudoku.calc_all_notes();
+        let result = sudoku.find_law_of_leftovers();
+        assert_eq!(result.strategy, Strategy::LawOfLeftovers);
+        let removals = result.removals.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 3);
+        for col in 3..6 {
+            assert!(removals.contains(&Candidate {
+                row: 0,
+                col,
+                num: 3
+            }));
+        }
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(
+            sudoku.serialized(),
+            "123456789456789123789123456912345678345678291678291345291534867534867912867912534"
+        );
+    }
+}
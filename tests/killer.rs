@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Cage, Strategy, Sudoku, SudokuError, Unit};
+
+    const CAGES: &str = "
+        aabeefiij
+        abbeffijj
+        ccdgghkkl
+        mmnqqruuv
+        mnnqrruvv
+        oopsstwwx
+        yyzCCDGGH
+        yzzCDDGHH
+        AABEEFIIJ
+        a=12 b=13 c=11 d=9 e=12 f=19 g=7 h=7 i=20 j=14 k=3 l=8 m=24 n=9 o=7 p=5 q=17 r=12
+        s=15 t=1 u=8 v=20 w=15 x=2 y=22 z=11 A=8 B=4 C=11 D=23 E=8 F=3 G=11 H=12 I=13 J=9";
+
+    #[test]
+    fn test_parse_cages() {
+        let cages = Cage::parse_cages(CAGES).unwrap();
+        assert_eq!(cages.len(), 36);
+        assert!(cages.contains(&Cage {
+            cells: vec![(0, 0), (0, 1), (1, 0)],
+            sum: 12
+        }));
+        assert!(matches!(
+            Cage::parse_cages("aab"),
+            Err(SudokuError::Parse(_))
+        ));
+        assert!(Cage::parse_cages(&CAGES.replace("a=12", "")).is_err());
+    }
+
+    #[test]
+    fn test_killer_backtracking() {
+        let mut sudoku = Sudoku::from_string(
+            "000100000030080040000000000000000000000000000000000000000000000000000000000000000",
+        );
+        sudoku.set_cages_string(CAGES).unwrap();
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(sudoku.solve_by_backtracking().is_solved());
+        assert_eq!(
+            sudoku.serialized(),
+            "478152963132689547659347128986274315721835496345961782597428631813596274264713859"
+        );
+    }
+
+    #[test]
+    fn test_killer_human_like() {
+        let mut sudoku = Sudoku::from_string(
+            "000100000030080040000000000000000000000000000000000000000000000000000000000000000",
+        );
+        sudoku.set_cages_string(CAGES).unwrap();
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(
+            sudoku.serialized(),
+            "478152963132689547659347128986274315721835496345961782597428631813596274264713859"
+        );
+        assert!(sudoku.rating().contains_key(&Strategy::CageCombination));
+    }
+
+    #[test]
+    fn test_cage_combination_16x16() {
+        let mut sudoku = Sudoku::from_string(&".".repeat(256));
+        assert_eq!(sudoku.size(), 16);
+        // A 2-cell cage adding up to 3 takes 1 and 2, an 8-cell cage adding up to 36
+        // takes 1 to 8
+        let layout = format!("aabbbbbbbb{}", ".".repeat(246));
+        sudoku
+            .set_cages_string(&format!("{} a=3 b=36", layout))
+            .unwrap();
+        sudoku.calc_all_notes();
+        let result = sudoku.find_cage_combination();
+        assert_eq!(result.strategy, Strategy::CageCombination);
+        let removed = &result.removals.candidates_about_to_be_removed;
+        assert_eq!(removed.len(), 2 * 14);
+        assert!(removed.iter().all(|candidate| candidate.num > 2));
+    }
+
+    #[test]
+    fn test_innie() {
+        // Row 0 is covered by the cages a, b, e, f, i and j, which also stick out
+        // into row 1; with row 1 filled except for one cell, the outie is determined
+        let mut sudoku = Sudoku::from_string(
+            "000000000102689547000000000000000000000000000000000000000000000000000000000000000",
+        );
+        sudoku.set_cages_string(CAGES).unwrap();
+        sudoku.calc_all_notes();
+        let result = sudoku.find_innies_outies();
+        assert_eq!(result.strategy, Strategy::InniesOuties);
+        assert_eq!(result.removals.unit, Some(Unit::Row));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[0][..]));
+        let cell = result.removals.sets_cell.unwrap();
+        assert_eq!((cell.row, cell.col, cell.num), (1, 1, 3));
+    }
+}
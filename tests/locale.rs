@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Locale, Strategy, Sudoku, Unit};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!("de".parse::<Locale>(), Ok(Locale::German));
+        assert_eq!("fr-CA".parse::<Locale>(), Ok(Locale::French));
+        assert_eq!("EN_us".parse::<Locale>(), Ok(Locale::English));
+        assert!("xx".parse::<Locale>().is_err());
+        assert_eq!(Locale::German.to_string(), "de");
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(
+            Strategy::HiddenSingle.name(Locale::English),
+            "Hidden Single"
+        );
+        assert_eq!(
+            Strategy::HiddenSingle.name(Locale::English),
+            Strategy::HiddenSingle.to_string()
+        );
+        assert_eq!(
+            Strategy::HiddenSingle.name(Locale::German),
+            "Versteckter Single"
+        );
+        assert_eq!(
+            Strategy::HiddenSingle.name(Locale::French),
+            "Singleton caché"
+        );
+        assert_eq!(Unit::Box.name(Locale::German), "Block");
+        assert_eq!(Unit::Row.name(Locale::French), "ligne");
+    }
+
+    #[test]
+    fn test_explain_placement() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let result = sudoku.find_hidden_single();
+        let cell = result.removals.sets_cell.clone().unwrap();
+        let unit = result.removals.unit.clone().unwrap();
+        let index = result.removals.unit_index.as_ref().unwrap()[0] + 1;
+        assert_eq!(
+            result.explain(Locale::English),
+            format!(
+                "Hidden Single in {} {}: place {} in {}",
+                unit,
+                index,
+                cell.num,
+                cell.coord()
+            )
+        );
+        assert!(
+            result
+                .explain(Locale::German)
+                .starts_with("Versteckter Single in ")
+        );
+        assert!(result.explain(Locale::French).contains(" : placer "));
+    }
+
+    #[test]
+    fn test_explain_eliminations() {
+        let mut sudoku = Sudoku::from_string(
+            "318005406000603810006080503864952137123476958795318264030500780000007305000039641",
+        );
+        sudoku.calc_all_notes();
+        let result = sudoku.find_claiming_pair();
+        let removed = result
+            .removals
+            .candidates_about_to_be_removed
+            .iter()
+            .next()
+            .unwrap();
+        assert_eq!(
+            result.explain(Locale::English),
+            format!("Claiming Pair in Row 2: remove {}", removed)
+        );
+        assert_eq!(
+            result.explain(Locale::German),
+            format!("Beanspruchendes Paar in Zeile 2: {} entfernen", removed)
+        );
+        assert_eq!(
+            result.explain(Locale::French),
+            format!("Paire revendiquante dans la ligne 2 : éliminer {}", removed)
+        );
+    }
+}
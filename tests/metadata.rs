@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Board, Metadata, Solver, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_metadata_passthrough() {
+        let metadata = Metadata::new()
+            .with("id", "17")
+            .with("source", "newspaper")
+            .with("difficulty_db", "4");
+        assert_eq!(metadata.id(), Some("17"));
+        assert_eq!(metadata.author(), None);
+        assert_eq!(metadata.len(), 3);
+        assert_eq!(
+            metadata.to_string(),
+            "difficulty_db=4,id=17,source=newspaper"
+        );
+
+        let board = PUZZLE
+            .parse::<Board>()
+            .unwrap()
+            .with_metadata(metadata.clone());
+        let sudoku = Sudoku::from_board(&board);
+        assert_eq!(sudoku.metadata, metadata);
+        assert_eq!(sudoku.to_board().metadata, metadata);
+
+        let solver = Solver::new();
+        assert_eq!(solver.rate(&board).unwrap().metadata, metadata);
+        assert_eq!(solver.solve(&board).unwrap().metadata, metadata);
+
+        let mut solved = Sudoku::from_string(PUZZLE).with_metadata(metadata.clone());
+        solved.solve_human_like();
+        assert_eq!(solved.metadata.source(), Some("newspaper"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_metadata_json() {
+        let board = PUZZLE
+            .parse::<Board>()
+            .unwrap()
+            .with_metadata(Metadata::new().with("id", "17"));
+        let rating = Solver::new().rate(&board).unwrap();
+        let json = serde_json::to_value(&rating).unwrap();
+        assert_eq!(json["metadata"], serde_json::json!({"id": "17"}));
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{EMPTY, Sudoku, SudokuError, Symmetry};
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    fn assert_minimal(puzzle: &Sudoku) {
+        assert_eq!(puzzle.count_solutions(2), 1);
+        for coord in puzzle.given_cells() {
+            let mut fewer = puzzle.clone();
+            fewer.board[coord.row][coord.col] = EMPTY;
+            assert!(fewer.count_solutions(2) > 1);
+        }
+    }
+
+    #[test]
+    fn test_minimized() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let minimized = sudoku.minimized(Symmetry::None).unwrap();
+        assert!(minimized.givens_count() < sudoku.givens_count());
+        assert_eq!(minimized.original_board, minimized.board);
+        for coord in minimized.given_cells() {
+            assert!(sudoku.is_given(coord.row, coord.col));
+            assert_eq!(
+                minimized.get_num(coord.row, coord.col),
+                sudoku.get_num(coord.row, coord.col)
+            );
+        }
+        assert_minimal(&minimized);
+        assert_eq!(
+            minimized.minimized(Symmetry::None).unwrap().serialized(),
+            minimized.serialized()
+        );
+    }
+
+    #[test]
+    fn test_minimized_symmetric() {
+        let mut solution = Sudoku::from_string(PUZZLE);
+        solution.solve_by_backtracking();
+        solution.original_board = solution.board.clone();
+        let minimized = solution.minimized(Symmetry::Rotational).unwrap();
+        assert_eq!(minimized.count_solutions(2), 1);
+        assert!(
+            minimized
+                .analyze_pattern()
+                .symmetries
+                .contains(&Symmetry::Rotational)
+        );
+    }
+
+    #[test]
+    fn test_minimized_ambiguous() {
+        let sudoku = Sudoku::from_string(&format!("{}{}", &PUZZLE[..27], "0".repeat(54)));
+        assert!(matches!(
+            sudoku.minimized(Symmetry::None),
+            Err(SudokuError::InvalidBoard(_))
+        ));
+    }
+}
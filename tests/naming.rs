@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Locale, NamingScheme, Strategy, StrategyResult, Sudoku};
+
+    #[test]
+    fn test_obvious_names() {
+        assert_eq!(NamingScheme::default(), NamingScheme::Obvious);
+        assert_eq!(Strategy::ObviousSingle.to_string(), "Obvious Single");
+        assert_eq!(Strategy::LastDigit.to_string(), "Last Digit");
+        assert_eq!(
+            Strategy::ObviousSingle.name_in(Locale::English, NamingScheme::Obvious),
+            "Obvious Single"
+        );
+    }
+
+    #[test]
+    fn test_naked_names() {
+        let naked = |strategy: Strategy, locale| strategy.name_in(locale, NamingScheme::Naked);
+        assert_eq!(
+            naked(Strategy::ObviousSingle, Locale::English),
+            "Naked Single"
+        );
+        assert_eq!(naked(Strategy::ObviousPair, Locale::English), "Naked Pair");
+        assert_eq!(naked(Strategy::ObviousQuad, Locale::English), "Naked Quad");
+        assert_eq!(
+            naked(Strategy::HiddenTriple, Locale::English),
+            "Hidden Triple"
+        );
+        assert_eq!(naked(Strategy::LastDigit, Locale::English), "Full House");
+        assert_eq!(
+            naked(Strategy::ClaimingPair, Locale::English),
+            "Box/Line Reduction"
+        );
+        assert_eq!(
+            naked(Strategy::HiddenSingle, Locale::English),
+            "Hidden Single"
+        );
+        assert_eq!(
+            naked(Strategy::ObviousSingle, Locale::German),
+            "Nackter Single"
+        );
+        assert_eq!(
+            naked(Strategy::HiddenSingle, Locale::German),
+            "Versteckter Single"
+        );
+        assert_eq!(naked(Strategy::ObviousPair, Locale::French), "Paire nue");
+        // Displaying is unaffected
+        assert_eq!(Strategy::ObviousSingle.to_string(), "Obvious Single");
+    }
+
+    #[test]
+    fn test_explain_in_scheme() {
+        let mut sudoku = Sudoku::from_string(
+            "008000063030000000000047120006000000001830400000901700000408031000500204200000000",
+        );
+        sudoku.calc_all_notes();
+        let step: StrategyResult = sudoku.find_obvious_single();
+        assert!(step.explain(Locale::English).starts_with("Obvious Single"));
+        assert!(
+            step.explain_in(Locale::English, NamingScheme::Naked)
+                .starts_with("Naked Single")
+        );
+    }
+
+    #[test]
+    fn test_parse_naming_scheme() {
+        assert_eq!("wiki".parse::<NamingScheme>(), Ok(NamingScheme::Naked));
+        assert_eq!("Coach".parse::<NamingScheme>(), Ok(NamingScheme::Obvious));
+        assert!("other".parse::<NamingScheme>().is_err());
+    }
+}
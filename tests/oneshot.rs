@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{
+        Board, Budget, DifficultyLevel, Error, RATING_ALGORITHM, Solver, Strategy, Sudoku,
+    };
+    use std::sync::Arc;
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_rate() {
+        let rating = rate_my_sudoku::rate(PUZZLE).unwrap();
+        assert_eq!(rating.puzzle, PUZZLE);
+        assert!(rating.solved);
+        assert!((rating.difficulty - 11.9272727273).abs() < 1e-6);
+        assert_eq!(rating.level, DifficultyLevel::Expert);
+        assert_eq!(rating.algorithm, RATING_ALGORITHM);
+        assert!(rating.strategies.contains_key(&Strategy::HiddenSingle));
+    }
+
+    #[test]
+    fn test_solve() {
+        let solution = rate_my_sudoku::solve(PUZZLE).unwrap();
+        assert_eq!(solution.puzzle, PUZZLE);
+        assert!(solution.solved_by_logic);
+        assert!(!solution.solution.contains('0'));
+        assert!(
+            PUZZLE
+                .chars()
+                .zip(solution.solution.chars())
+                .all(|(given, digit)| given == '0' || given == digit)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            rate_my_sudoku::rate("12345"),
+            Err(Error::Parse(_))
+        ));
+        let conflict = PUZZLE.replacen('0', "8", 1);
+        assert_eq!(
+            rate_my_sudoku::rate(&conflict).unwrap_err(),
+            Error::Conflict
+        );
+        assert_eq!(
+            rate_my_sudoku::solve(&"0".repeat(81)).unwrap_err(),
+            Error::MultipleSolutions
+        );
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_types_are_send_and_sync() {
+        assert_send_sync::<Board>();
+        assert_send_sync::<Sudoku>();
+        assert_send_sync::<Solver>();
+        assert_send_sync::<Budget>();
+        assert_send_sync::<Error>();
+    }
+
+    #[test]
+    fn test_solver_shared_between_threads() {
+        let board: Arc<Board> = Arc::new(PUZZLE.parse().unwrap());
+        let solver = Arc::new(Solver::new());
+        let expected = solver.rate(&board).unwrap().difficulty;
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let board = Arc::clone(&board);
+                let solver = Arc::clone(&solver);
+                std::thread::spawn(move || solver.rate(&board).unwrap().difficulty)
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+        assert_eq!(board.serialized(), PUZZLE);
+    }
+
+    #[test]
+    fn test_solver_within_budget() {
+        let board: Board = PUZZLE.parse().unwrap();
+        let solver = Solver::with_budget(Budget::unlimited().with_max_steps(1));
+        assert!(!solver.rate(&board).unwrap().solved);
+        let solution = solver.solve(&board).unwrap();
+        assert!(!solution.solved_by_logic);
+        assert_eq!(
+            solution.solution,
+            rate_my_sudoku::solve(PUZZLE).unwrap().solution
+        );
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{SearchOrder, Strategy, Sudoku, tune_order};
+
+    const PUZZLES: [&str; 4] = [
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+        "610040000090008273000705146100000000400609051005480900000010009740000300020000600",
+        "300000790906001000000579000000102000060000504048050001080400005052000009003800060",
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000",
+    ];
+
+    #[test]
+    fn test_order_profile() {
+        let default = SearchOrder::default();
+        assert_eq!(default.strategies(), Strategy::ALL.to_vec());
+        assert_eq!(default.to_string().parse::<SearchOrder>(), Ok(default));
+
+        let order = "# pairs first\nClaimingPair\nPointingPair\n"
+            .parse::<SearchOrder>()
+            .unwrap();
+        let strategies = order.strategies();
+        assert_eq!(strategies.len(), Strategy::ALL.len());
+        assert_eq!(
+            strategies[..3],
+            [
+                Strategy::ClaimingPair,
+                Strategy::PointingPair,
+                Strategy::LastDigit
+            ]
+        );
+        assert_eq!(order.to_string().parse::<SearchOrder>(), Ok(order));
+
+        assert!("Guessing".parse::<SearchOrder>().is_err());
+        assert!("XWing\nXWing".parse::<SearchOrder>().is_err());
+        assert!(SearchOrder::from_strategies(&[Strategy::None]).is_err());
+    }
+
+    #[test]
+    fn test_next_step_follows_order() {
+        let mut sudoku = Sudoku::from_string(PUZZLES[3]);
+        sudoku.calc_all_notes();
+        let analysis = sudoku.analyze();
+        assert!(analysis.technique_count() > 1);
+        // Each strategy that applies is taken when it's tried first
+        for (strategy, steps) in &analysis.strategies {
+            let order = SearchOrder::from_strategies(std::slice::from_ref(strategy)).unwrap();
+            let mut reordered = sudoku.clone().with_search_order(order);
+            let step = reordered.next_step();
+            assert_eq!(&step.strategy, strategy);
+            assert_eq!(
+                step.removals.candidates_about_to_be_removed,
+                steps[0].removals.candidates_about_to_be_removed
+            );
+        }
+        let step = sudoku.next_step();
+        assert_eq!(step.strategy, analysis.strategies[0].0);
+    }
+
+    #[test]
+    fn test_tune_order() {
+        let puzzles: Vec<Sudoku> = PUZZLES.iter().map(|p| Sudoku::from_string(p)).collect();
+        let tuning = tune_order(&puzzles);
+        assert_eq!(tuning.puzzles, PUZZLES.len());
+        assert!(tuning.positions > 100);
+        assert_eq!(tuning.detectors.len(), Strategy::ALL.len());
+
+        let order = tuning.order.strategies();
+        let hits = |strategy: &Strategy| {
+            tuning
+                .detectors
+                .iter()
+                .find(|detector| &detector.strategy == strategy)
+                .unwrap()
+                .hits
+        };
+        assert!(hits(&order[0]) > 0);
+        // The variant strategies never apply to classic puzzles and come last
+        let missed: Vec<Strategy> = order.iter().filter(|s| hits(s) == 0).cloned().collect();
+        assert!(missed.contains(&Strategy::Kropki));
+        assert_eq!(order[order.len() - missed.len()..], missed);
+        let in_default_order: Vec<Strategy> = Strategy::ALL
+            .into_iter()
+            .filter(|strategy| missed.contains(strategy))
+            .collect();
+        assert_eq!(missed, in_default_order);
+
+        let hit_rate = tuning.hit_rate(&tuning.detectors[0]);
+        assert!(hit_rate > 0.0 && hit_rate <= 1.0);
+        assert!(tuning.time_per_step_before > std::time::Duration::ZERO);
+        assert!(tuning.to_string().contains("Time per step"));
+
+        // The tuned order still solves the puzzles the strategies can solve
+        for puzzle in PUZZLES {
+            let mut expected = Sudoku::from_string(puzzle);
+            if !expected.solve_human_like().is_solved() {
+                continue;
+            }
+            let mut sudoku = Sudoku::from_string(puzzle).with_search_order(tuning.order);
+            assert!(sudoku.solve_human_like().is_solved());
+            assert_eq!(sudoku.board, expected.board);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Parity, Sudoku};
+
+    const PUZZLE: &str =
+        "052000000610000000000000060000000000000000010400000675000035900020000000000600003";
+    const MASK: &str =
+        ".o...oo.....oeeo......e...o.o......o...o..e.....o.o....e...o.....e.oo.eo...eo....";
+    const SOLUTION: &str =
+        "852167394619342758734589261275416839963758412481923675147235986326891547598674123";
+
+    #[test]
+    fn test_parse_parity() {
+        let mut sudoku = Sudoku::new();
+        sudoku.set_parity_string(MASK).unwrap();
+        assert_eq!(sudoku.parity(0, 0), Parity::Any);
+        assert_eq!(sudoku.parity(0, 1), Parity::Odd);
+        assert_eq!(sudoku.parity(1, 4), Parity::Even);
+        assert!(sudoku.set_parity_string("o.e").is_err());
+        assert!(
+            sudoku
+                .set_parity_string(&MASK.replacen('o', "x", 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parity_restricts_candidates() {
+        let mut sudoku = Sudoku::new();
+        sudoku.set_parity_string(MASK).unwrap();
+        assert!(!sudoku.can_place(0, 1, 2));
+        assert!(sudoku.can_place(0, 1, 3));
+        sudoku.calc_all_notes();
+        assert!(sudoku.get_notes(1, 4).iter().all(|num| num % 2 == 0));
+        sudoku.board[0][1] = 4;
+        assert!(sudoku.has_conflicts());
+    }
+
+    #[test]
+    fn test_solve_odd_even() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert_eq!(sudoku.count_solutions(2), 2);
+        sudoku.set_parity_string(MASK).unwrap();
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), SOLUTION);
+        assert!(sudoku.difficulty() > 0.0);
+    }
+}
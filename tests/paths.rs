@@ -0,0 +1,40 @@
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use rate_my_sudoku::{PathOrder, Sudoku};
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    #[test]
+    fn test_easiest_first_matches_solver() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let path = sudoku.rate_path(PathOrder::FirstFound);
+        let mut solved = sudoku.clone();
+        assert!(solved.solve_human_like().is_solved());
+        assert!(path.solved);
+        assert!((path.difficulty - solved.difficulty()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spread() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let orders = PathOrder::first(4);
+        assert_eq!(
+            orders,
+            [
+                PathOrder::FirstFound,
+                PathOrder::HardestFirst,
+                PathOrder::Random(1),
+                PathOrder::Random(2)
+            ]
+        );
+        let spread = sudoku.rate_paths(&orders).unwrap();
+        assert_eq!(spread.paths.len(), 4);
+        assert!(spread.paths.iter().all(|path| path.solved));
+        assert!(spread.min <= spread.mean && spread.mean <= spread.max);
+        assert!(spread.std_dev >= 0.0);
+        // Random paths are reproducible
+        assert_eq!(sudoku.rate_path(PathOrder::Random(2)), spread.paths[3]);
+        assert!(sudoku.rate_paths(&[]).is_none());
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Sudoku, Symmetry};
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    #[test]
+    fn test_counts_and_mask() {
+        let pattern = Sudoku::from_string(PUZZLE).analyze_pattern();
+        assert_eq!(pattern.givens, 30);
+        assert_eq!(pattern.bands, [9, 10, 11]);
+        assert_eq!(pattern.stacks.iter().sum::<usize>(), 30);
+        assert!(pattern.symmetries.is_empty());
+        let mask: Vec<&str> = pattern.mask.lines().collect();
+        assert_eq!(mask.len(), 9);
+        assert_eq!(mask[0], ".XX..X..X");
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let mut corners = "0".repeat(81);
+        corners.replace_range(0..1, "1");
+        corners.replace_range(80..81, "2");
+        let pattern = Sudoku::from_string(&corners).analyze_pattern();
+        assert_eq!(
+            pattern.symmetries,
+            [
+                Symmetry::Rotational,
+                Symmetry::Diagonal,
+                Symmetry::AntiDiagonal
+            ]
+        );
+
+        let mut sides = "0".repeat(81);
+        sides.replace_range(4..5, "1");
+        sides.replace_range(76..77, "2");
+        let pattern = Sudoku::from_string(&sides).analyze_pattern();
+        assert_eq!(
+            pattern.symmetries,
+            [
+                Symmetry::Rotational,
+                Symmetry::Horizontal,
+                Symmetry::Vertical
+            ]
+        );
+        assert_eq!(pattern.bands, [1, 0, 1]);
+        assert_eq!(pattern.stacks, [0, 2, 0]);
+    }
+
+    #[test]
+    fn test_parse_symmetry() {
+        for symmetry in Symmetry::ALL {
+            let name = format!("{:?}", symmetry).to_lowercase();
+            assert_eq!(name.parse::<Symmetry>(), Ok(symmetry));
+        }
+        assert_eq!(
+            "Anti-Diagonal".parse::<Symmetry>(),
+            Ok(Symmetry::AntiDiagonal)
+        );
+        assert_eq!("none".parse::<Symmetry>(), Ok(Symmetry::None));
+        assert!("spiral".parse::<Symmetry>().is_err());
+    }
+}
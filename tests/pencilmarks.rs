@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Strategy, Sudoku, SudokuError, rate_pencilmarks};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_round_trip() {
+        let positions = Sudoku::from_string(PUZZLE).practice_positions(&Strategy::PointingPair);
+        let position = &positions[0];
+        let grid = position.pencilmarks();
+        let mut sudoku = Sudoku::from_pencilmarks(&grid).unwrap();
+        assert_eq!(sudoku.serialized(), position.sudoku.serialized());
+        assert_eq!(sudoku.pencilmarks(), grid);
+        assert_eq!(sudoku.next_step().strategy, Strategy::PointingPair);
+
+        let rating = rate_pencilmarks(&grid).unwrap();
+        assert!(rating.solved);
+        assert!(rating.strategies.contains_key(&Strategy::PointingPair));
+        assert!(rating.difficulty > 0.0);
+    }
+
+    #[test]
+    fn test_decorated_grid() {
+        // Filled cells as single digits, candidates without brackets, and grid lines
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let mut grid = String::from(".---------.\n");
+        for line in sudoku.pencilmarks().lines() {
+            grid += &format!("| {} |\n", line.replace(['[', ']'], ""));
+        }
+        grid += "'---------'\n";
+        let read = Sudoku::from_pencilmarks(&grid).unwrap();
+        assert_eq!(read.serialized(), sudoku.serialized());
+        assert_eq!(read.get_notes(0, 0), sudoku.get_notes(0, 0));
+    }
+
+    #[test]
+    fn test_inconsistent_grids() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let grid = sudoku.pencilmarks();
+        let cells: Vec<&str> = grid.split_whitespace().collect();
+
+        // r1c1 may hold 6 although r1c8 holds it
+        let mut bad = cells.clone();
+        let with_six = format!("[6{}]", &cells[0][1..cells[0].len() - 1]);
+        bad[0] = &with_six;
+        assert!(matches!(
+            Sudoku::from_pencilmarks(&bad.join(" ")),
+            Err(SudokuError::InvalidBoard(_))
+        ));
+
+        // An empty cell without candidates
+        let mut bad = cells.clone();
+        bad[0] = "[]";
+        assert!(matches!(
+            Sudoku::from_pencilmarks(&bad.join(" ")),
+            Err(SudokuError::Contradiction(_))
+        ));
+
+        assert!(matches!(
+            Sudoku::from_pencilmarks(&cells[..80].join(" ")),
+            Err(SudokuError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Cell, Constraints, PlacementError, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_set_cell() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        assert_eq!(sudoku.set_cell(9, 0, 1), Err(PlacementError::OutOfBounds));
+        assert_eq!(
+            sudoku.set_cell(0, 0, 10),
+            Err(PlacementError::InvalidDigit(10))
+        );
+        assert_eq!(sudoku.set_cell(0, 2, 1), Err(PlacementError::Given));
+        assert_eq!(sudoku.set_cell(0, 0, 8), Err(PlacementError::Conflict));
+        assert!(sudoku.candidates[0][1].contains(&1));
+        sudoku.set_cell(0, 0, 1).unwrap();
+        assert_eq!(sudoku.board[0][0], 1);
+        assert!(sudoku.candidates[0][0].is_empty());
+        assert!(!sudoku.candidates[0][1].contains(&1));
+        assert!(!sudoku.candidates[5][0].contains(&1));
+        assert_eq!(sudoku.set_cell(0, 0, 4), Err(PlacementError::Occupied));
+        sudoku.undo();
+        assert_eq!(sudoku.board[0][0], 0);
+        assert!(sudoku.candidates[0][1].contains(&1));
+    }
+
+    #[test]
+    fn test_clear_cell() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let notes = sudoku.candidates.clone();
+        sudoku.set_cell(0, 0, 1).unwrap();
+        assert_eq!(sudoku.clear_cell(0, 2), Err(PlacementError::Given));
+        sudoku.clear_cell(0, 0).unwrap();
+        assert_eq!(sudoku.board[0][0], 0);
+        assert_eq!(sudoku.candidates, notes);
+        assert_eq!(sudoku.undo_stack.len(), 2);
+    }
+
+    #[test]
+    fn test_toggle_note() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        assert!(sudoku.candidates[0][0].contains(&1));
+        assert_eq!(sudoku.toggle_note(0, 0, 1), Ok(false));
+        assert!(!sudoku.candidates[0][0].contains(&1));
+        // Notes may break the rules
+        assert_eq!(sudoku.toggle_note(0, 0, 8), Ok(true));
+        assert!(sudoku.candidates[0][0].contains(&8));
+        assert_eq!(sudoku.toggle_note(0, 2, 1), Err(PlacementError::Given));
+        assert_eq!(
+            sudoku.toggle_note(0, 0, 0),
+            Err(PlacementError::InvalidDigit(0))
+        );
+        sudoku.undo();
+        sudoku.undo();
+        assert!(sudoku.candidates[0][0].contains(&1));
+        assert!(!sudoku.candidates[0][0].contains(&8));
+        sudoku.set_cell(0, 0, 1).unwrap();
+        assert_eq!(sudoku.toggle_note(0, 0, 2), Err(PlacementError::Occupied));
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert!(sudoku.conflicts().is_empty());
+        // 8 is given in r1c3 and r5c4
+        sudoku.board[4][2] = 8;
+        let cell = |row, col| Cell { row, col, num: 8 };
+        assert_eq!(
+            sudoku.conflicts(),
+            vec![(cell(0, 2), cell(4, 2)), (cell(4, 2), cell(4, 3))]
+        );
+        sudoku.board[4][2] = 0;
+        sudoku.set_constraints(Constraints::ANTI_KNIGHT);
+        // A knight's move from the given in r5c4
+        sudoku.board[5][1] = 8;
+        assert_eq!(sudoku.conflicts(), vec![(cell(4, 3), cell(5, 1))]);
+    }
+}
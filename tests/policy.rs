@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{
+        Analysis, EasiestFirst, FirstFound, HardestFirst, SeededRandom, StepPolicy, StrategyResult,
+        Sudoku,
+    };
+
+    const PUZZLE: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    #[test]
+    fn test_first_found_matches_solver() {
+        let mut solver = Sudoku::from_string(PUZZLE);
+        assert!(solver.solve_human_like().is_solved());
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert!(sudoku.solve_with_policy(&mut FirstFound).is_solved());
+        assert_eq!(sudoku.rating(), solver.rating());
+        assert!((sudoku.difficulty() - solver.difficulty()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_policies() {
+        let mut easiest = Sudoku::from_string(PUZZLE);
+        assert!(easiest.solve_with_policy(&mut EasiestFirst).is_solved());
+        let mut hardest = Sudoku::from_string(PUZZLE);
+        assert!(hardest.solve_with_policy(&mut HardestFirst).is_solved());
+        assert!(hardest.difficulty() >= easiest.difficulty());
+
+        // The same seed leads to the same steps
+        let mut first = Sudoku::from_string(PUZZLE);
+        assert!(
+            first
+                .solve_with_policy(&mut SeededRandom::new(7))
+                .is_solved()
+        );
+        let mut second = Sudoku::from_string(PUZZLE);
+        second.solve_with_policy(&mut SeededRandom::new(7));
+        assert_eq!(first.rating(), second.rating());
+    }
+
+    /// Stops after the given number of steps.
+    struct Stop(usize);
+
+    impl StepPolicy for Stop {
+        fn choose<'a>(&mut self, analysis: &'a Analysis) -> Option<&'a StrategyResult> {
+            self.0 = self.0.checked_sub(1)?;
+            FirstFound.choose(analysis)
+        }
+    }
+
+    #[test]
+    fn test_custom_policy() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let outcome = sudoku.solve_with_policy(&mut Stop(3));
+        assert!(!outcome.is_solved());
+        assert!(sudoku.unsolved());
+    }
+}
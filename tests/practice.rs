@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Strategy, Sudoku};
+
+    const PUZZLE: &str =
+        "000100078100020000083000000304000200070201060008790500041005080090007006030000900";
+
+    #[test]
+    fn test_practice_positions() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let positions = sudoku.practice_positions(&Strategy::PointingPair);
+        assert!(!positions.is_empty());
+        for position in &positions {
+            assert_eq!(position.step.strategy, Strategy::PointingPair);
+            // The position is a puzzle of its own, with the notes of the solver
+            let drill = &position.sudoku;
+            assert_eq!(drill.original_board(), drill.serialized());
+            assert!(drill.rating().is_empty());
+            let mut next = drill.clone();
+            assert_eq!(next.next_step().strategy, Strategy::PointingPair);
+        }
+        assert!(
+            positions
+                .windows(2)
+                .all(|w| w[0].steps_before < w[1].steps_before)
+        );
+
+        let pencilmarks = positions[0].pencilmarks();
+        assert_eq!(pencilmarks.lines().count(), 9);
+        assert!(pencilmarks.lines().all(|line| line.split(' ').count() == 9));
+        assert!(pencilmarks.contains('['));
+        assert!(sudoku.practice_positions(&Strategy::Arrow).is_empty());
+    }
+}
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::prelude::*;
+
+    #[test]
+    fn test_prelude_steps_through_solver() {
+        let mut sudoku = Sudoku::from_string(
+            "008000063030000000000047120006000000001830400000901700000408031000500204200000000",
+        );
+        sudoku.calc_all_notes();
+        let step: StrategyResult = sudoku.next_step();
+        assert_ne!(step.strategy, Strategy::None);
+        let cell: &Cell = step.removals.sets_cell.as_ref().unwrap();
+        assert_eq!(sudoku.get_num(cell.row, cell.col), EMPTY);
+        sudoku.apply(&step).unwrap();
+        assert_eq!(sudoku.solve_human_like(), SolveOutcome::Solved);
+        let rating: Rating = rate(&sudoku.original_board()).unwrap();
+        assert_eq!(DifficultyLevel::of(sudoku.difficulty()), rating.level);
+    }
+}
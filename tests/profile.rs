@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Profile, Strategy, Sudoku};
+
+    const PUZZLES: [&str; 2] = [
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000",
+    ];
+
+    #[test]
+    fn test_solve_profiled() {
+        let mut profile = Profile::new();
+        for puzzle in PUZZLES {
+            let mut expected = Sudoku::from_string(puzzle);
+            let outcome = expected.solve_human_like();
+            let mut sudoku = Sudoku::from_string(puzzle);
+            assert_eq!(sudoku.solve_profiled(&mut profile), outcome);
+            assert_eq!(sudoku.board, expected.board);
+            assert_eq!(sudoku.rating(), expected.rating());
+        }
+        assert_eq!((profile.puzzles, profile.solved), (2, 2));
+        assert_eq!(profile.strategies[0].strategy, Strategy::LastDigit);
+        let last_digit = &profile.strategies[0];
+        assert!(last_digit.calls >= last_digit.steps && last_digit.steps > 0);
+        // The first detector runs once per step
+        let steps: usize = profile.strategies.iter().map(|s| s.steps).sum();
+        assert_eq!(last_digit.calls, steps);
+        let share: f64 = profile
+            .strategies
+            .iter()
+            .map(|strategy| profile.time_share(strategy))
+            .sum();
+        assert!(share > 0.0 && share <= 1.0);
+        assert!(profile.to_string().starts_with("Strategy"));
+    }
+}
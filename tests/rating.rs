@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Board, RATING_ALGORITHM, RatingMode, Solver, Strategy, Sudoku};
+
+    /// Ratings computed by the current algorithm. If they change, the algorithm has
+    /// changed, and `RATING_ALGORITHM` must be bumped along with these values.
+    const RATED: [(&str, f64); 2] = [
+        (
+            "008000063030000000000047120006000000001830400000901700000408031000500204200000000",
+            11.9272727273,
+        ),
+        (
+            "000100078100020000083000000304000200070201060008790500041005080090007006030000900",
+            24.5517241379,
+        ),
+    ];
+
+    #[test]
+    fn test_ratings_match_algorithm() {
+        assert_eq!(RATING_ALGORITHM, "rms-3");
+        for (puzzle, difficulty) in RATED {
+            let mut sudoku = Sudoku::from_string(puzzle);
+            sudoku.solve_human_like();
+            assert!(
+                (sudoku.difficulty() - difficulty).abs() < 1e-6,
+                "{} rated {} instead of {}",
+                puzzle,
+                sudoku.difficulty(),
+                difficulty
+            );
+        }
+    }
+
+    #[test]
+    fn test_hardest_mode() {
+        let mut sudoku = Sudoku::from_string(RATED[0].0);
+        assert_eq!(sudoku.hardest_technique(), None);
+        assert_eq!(sudoku.difficulty_in(RatingMode::Hardest), 0.0);
+        sudoku.solve_human_like();
+        let hardest = sudoku.hardest_technique().unwrap();
+        assert!(sudoku.rating().contains_key(&hardest));
+        assert!(sudoku.difficulty_in(RatingMode::Hardest) >= sudoku.difficulty());
+        assert_eq!(
+            sudoku.difficulty_in(RatingMode::Average),
+            sudoku.difficulty()
+        );
+
+        let board: Board = RATED[0].0.parse().unwrap();
+        let rating = Solver::new()
+            .with_rating_mode(RatingMode::Hardest)
+            .rate(&board)
+            .unwrap();
+        assert_eq!(rating.mode, RatingMode::Hardest);
+        assert_eq!(rating.difficulty, sudoku.hardest_difficulty());
+        assert_ne!(hardest, Strategy::None);
+        assert_eq!("hardest".parse::<RatingMode>(), Ok(RatingMode::Hardest));
+        assert_eq!(RatingMode::default().to_string(), "average");
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{RenderFormat, RenderOptions, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_svg() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let svg = sudoku.to_svg(&RenderOptions::new());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<text").count(), 24);
+        assert_eq!(svg.matches("font-weight=\"bold\"").count(), 24);
+
+        sudoku.calc_all_notes();
+        let step = sudoku.next_step();
+        let placed = step.removals.sets_cell.clone().unwrap();
+        let notes: usize = sudoku
+            .candidates
+            .iter()
+            .flatten()
+            .map(|notes| notes.len())
+            .sum();
+        let svg = sudoku.to_svg(&RenderOptions::new().with_candidates());
+        assert_eq!(svg.matches("<text").count(), 24 + notes);
+        let svg = sudoku.to_svg(&RenderOptions::new().with_highlight(step.clone()));
+        assert_eq!(svg.matches("<text").count(), 24 + notes);
+        // The cell placed in is shaded, the candidates removed elsewhere are red
+        assert_eq!(svg.matches("fill=\"#c8f0c8\"").count(), 1);
+        let removed_elsewhere = step
+            .removals
+            .candidates_about_to_be_removed
+            .iter()
+            .filter(|candidate| (candidate.row, candidate.col) != (placed.row, placed.col))
+            .count();
+        let removed_in_cell = sudoku.candidates[placed.row][placed.col].len() - 1;
+        assert_eq!(
+            svg.matches("fill=\"#dc2828\"").count(),
+            removed_elsewhere + removed_in_cell
+        );
+
+        sudoku.apply(&step).unwrap();
+        let svg = sudoku.to_svg(&RenderOptions::new());
+        assert_eq!(svg.matches("<text").count(), 25);
+        assert_eq!(svg.matches("fill=\"#1e50c8\"").count(), 1);
+    }
+
+    #[test]
+    fn test_html() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let step = sudoku.next_step();
+        let html = sudoku.to_html(&RenderOptions::new().with_highlight(step.clone()));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(&sudoku.to_svg(&RenderOptions::new().with_highlight(step.clone()))));
+        assert!(html.contains(&format!(
+            "<p>{}: {}</p>",
+            step.strategy,
+            step.removals.sets_cell.unwrap()
+        )));
+        assert!(!sudoku.to_html(&RenderOptions::new()).contains("<p>"));
+    }
+
+    #[test]
+    fn test_ansi() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let plain = sudoku.to_ansi(&RenderOptions::new());
+        assert_eq!(plain.lines().count(), 1 + 9 * 2);
+        assert_eq!(plain.matches("\x1b[1m").count(), 24);
+        sudoku.calc_all_notes();
+        let notes = sudoku.to_ansi(&RenderOptions::new().with_candidates());
+        assert_eq!(notes.lines().count(), 1 + 9 * 4);
+        let step = sudoku.next_step();
+        let highlighted = sudoku.to_ansi(&RenderOptions::new().with_highlight(step));
+        assert!(highlighted.contains("\x1b[42m"));
+        assert!(highlighted.contains("\x1b[31m"));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("svg".parse::<RenderFormat>(), Ok(RenderFormat::Svg));
+        assert_eq!("PNG".parse::<RenderFormat>(), Ok(RenderFormat::Png));
+        assert_eq!("ansi".parse::<RenderFormat>(), Ok(RenderFormat::Ansi));
+        assert_eq!("html".parse::<RenderFormat>(), Ok(RenderFormat::Html));
+        assert!("gif".parse::<RenderFormat>().is_err());
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_png() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let png = sudoku.to_png(&RenderOptions::new().with_candidates());
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        // Width and height of the header
+        assert_eq!(png[16..24], [0, 0, 1, 192, 0, 0, 1, 192]);
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Board, Cell, EMPTY, Error, RatingMode, Rerater, Solver};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+    const SOLUTION: &str =
+        "478152963132689547659347128986274315721835496345961782597428631813596274264713859";
+
+    fn digit(text: &str, row: usize, col: usize) -> u8 {
+        text.as_bytes()[9 * row + col] - b'0'
+    }
+
+    /// Rate the edited board with the rerater and from scratch, and compare.
+    fn check(rerater: &mut Rerater, solver: &Solver, cell: Cell) -> Result<(), Error> {
+        let rating = rerater.rerate_after_edit(cell.clone());
+        let expected = solver.rate(rerater.board());
+        match (rating, expected) {
+            (Ok(rating), Ok(expected)) => {
+                assert_eq!(rating.puzzle, expected.puzzle);
+                assert_eq!(rating.solved, expected.solved, "after {}", cell);
+                assert_eq!(rating.strategies, expected.strategies, "after {}", cell);
+                assert_eq!(rating.difficulty, expected.difficulty);
+                assert_eq!(rating.level, expected.level);
+                Ok(())
+            }
+            (Err(error), Err(expected)) => {
+                assert_eq!(error, expected, "after {}", cell);
+                Err(error)
+            }
+            (rating, expected) => panic!("after {}: {:?} vs {:?}", cell, rating, expected),
+        }
+    }
+
+    #[test]
+    fn test_rerate_matches_rate() {
+        let board: Board = PUZZLE.parse().unwrap();
+        let solver = Solver::new().with_rating_mode(RatingMode::Hardest);
+        let mut rerater = Rerater::new(board.clone()).with_solver(solver.clone());
+        let rating = rerater.rate().unwrap();
+        assert_eq!(rating.difficulty, solver.rate(&board).unwrap().difficulty);
+        let cached = rerater.cached_positions();
+        assert!(cached > 0);
+
+        // Add each digit of the solution in turn, then take them out again
+        let empty: Vec<(usize, usize)> = (0..81)
+            .map(|idx| (idx / 9, idx % 9))
+            .filter(|&(row, col)| digit(PUZZLE, row, col) == 0)
+            .collect();
+        for &(row, col) in empty.iter().step_by(7) {
+            let num = digit(SOLUTION, row, col);
+            check(&mut rerater, &solver, Cell { row, col, num }).unwrap();
+        }
+        for &(row, col) in empty.iter().step_by(7) {
+            let num = EMPTY;
+            check(&mut rerater, &solver, Cell { row, col, num }).unwrap();
+        }
+        assert_eq!(rerater.board().original_board(), PUZZLE);
+        // Having seen the puzzle before, nothing is solved again
+        let cached = rerater.cached_positions();
+        rerater.rate().unwrap();
+        assert_eq!(rerater.cached_positions(), cached);
+    }
+
+    #[test]
+    fn test_rerate_cache_limit() {
+        let board: Board = PUZZLE.parse().unwrap();
+        let solver = Solver::new();
+        let mut rerater = Rerater::new(board)
+            .with_solver(solver.clone())
+            .with_cache_limit(10);
+        rerater.rate().unwrap();
+        assert_eq!(rerater.cached_positions(), 10);
+        let (row, col) = (0, 0);
+        let num = digit(SOLUTION, row, col);
+        check(&mut rerater, &solver, Cell { row, col, num }).unwrap();
+        assert!(rerater.cached_positions() <= 10);
+    }
+
+    #[test]
+    fn test_rerate_errors() {
+        let board: Board = PUZZLE.parse().unwrap();
+        let solver = Solver::new();
+        let mut rerater = Rerater::new(board);
+        rerater.rate().unwrap();
+
+        // Removing a given leaves several solutions
+        let cell = Cell {
+            row: 0,
+            col: 2,
+            num: EMPTY,
+        };
+        assert_eq!(
+            check(&mut rerater, &solver, cell),
+            Err(Error::MultipleSolutions)
+        );
+        // A digit clashing with another given
+        let cell = Cell {
+            row: 0,
+            col: 2,
+            num: 6,
+        };
+        assert_eq!(check(&mut rerater, &solver, cell), Err(Error::Conflict));
+        let cell = Cell {
+            row: 0,
+            col: 2,
+            num: 8,
+        };
+        check(&mut rerater, &solver, cell).unwrap();
+        // A digit that fits the givens but not the solution
+        let cell = Cell {
+            row: 0,
+            col: 0,
+            num: 1,
+        };
+        assert_eq!(check(&mut rerater, &solver, cell), Err(Error::NoSolution));
+        let cell = Cell {
+            row: 0,
+            col: 0,
+            num: EMPTY,
+        };
+        check(&mut rerater, &solver, cell).unwrap();
+
+        let outside = Cell {
+            row: 9,
+            col: 0,
+            num: 1,
+        };
+        assert!(matches!(
+            rerater.rerate_after_edit(outside),
+            Err(Error::Parse(_))
+        ));
+        assert_eq!(rerater.board().original_board(), PUZZLE);
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Samurai, Strategy};
+
+    const PUZZLE: &str = "
+        ...7..1..   ...3.....
+        3........   7...8....
+        ..7..6..5   849......
+        ..49.....   ...95.8..
+        5.1.3..4.   56.7.....
+        ...4..8..   ......41.
+        6....2.....8..7....41
+        9................2.3.
+        2....35............7.
+              .8..2...9
+              ..5....4.
+              12...5...
+        1...78..9.........5.9
+        ...2.......4..5..8...
+        .6.........7..4.6....
+        ...8.37..   ..2.4.9..
+        ..5......   ..36.....
+        .9......5   ...7...42
+        .1.......   .4.95....
+        672..4...   .....1..7
+        .5...142.   68.......";
+
+    const SOLUTION: &str = "
+        456789132   125346789
+        389125674   736589124
+        127346985   849127356
+        864957321   312954867
+        591238746   564718293
+        732461859   978263415
+        675892413658297835641
+        913574268739451672938
+        248613597142683491572
+              786421539
+              935876142
+              124395876
+        123578649513728134569
+        589246371284965278134
+        467139852967314569278
+        241853796   172843956
+        735692184   453692781
+        896417235   896715342
+        314725968   247956813
+        672984513   539481627
+        958361427   681327495";
+
+    #[test]
+    fn test_parse_layout() {
+        let samurai = Samurai::from_layout(PUZZLE).unwrap();
+        assert_eq!(samurai.grids[0].board[0][3], 7);
+        assert_eq!(samurai.grids[1].board[1][0], 7);
+        // The shared boxes hold the same digits in both grids
+        assert_eq!(samurai.grids[0].board[8][6], 5);
+        assert_eq!(samurai.grids[2].board[2][0], 5);
+        assert_eq!(Samurai::grids_at(7, 7), vec![(0, 7, 7), (2, 1, 1)]);
+        assert!(Samurai::grids_at(2, 10).is_empty());
+        assert!(!samurai.has_conflicts());
+        let reparsed = Samurai::from_layout(&samurai.to_layout()).unwrap();
+        assert_eq!(reparsed.to_layout(), samurai.to_layout());
+        assert!(Samurai::from_layout("123").is_err());
+        assert!(Samurai::from_layout(&PUZZLE.replacen("7", "x", 1)).is_err());
+    }
+
+    #[test]
+    fn test_joint_solving() {
+        let mut samurai = Samurai::from_layout(PUZZLE).unwrap();
+        // None of the grids has a unique solution on its own
+        for grid in &samurai.grids {
+            assert_eq!(grid.count_solutions(2), 2);
+        }
+        assert!(samurai.solve_human_like().is_solved());
+        assert_eq!(
+            samurai.to_layout(),
+            Samurai::from_layout(SOLUTION).unwrap().to_layout()
+        );
+        let rating = samurai.rating();
+        assert!(rating.contains_key(&Strategy::HiddenSingle));
+        assert!(samurai.difficulty() > 0.0);
+    }
+}
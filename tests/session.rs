@@ -0,0 +1,64 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use rate_my_sudoku::{Sudoku, SudokuError};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_round_trip() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        for _ in 0..5 {
+            let step = sudoku.next_step();
+            sudoku.apply(&step).unwrap();
+        }
+        let session = sudoku.save_session();
+        let mut restored = Sudoku::new();
+        restored.load_session(&session).unwrap();
+        assert_eq!(restored.original_board, sudoku.original_board);
+        assert_eq!(restored.board, sudoku.board);
+        assert_eq!(restored.candidates, sudoku.candidates);
+        assert_eq!(restored.rating, sudoku.rating);
+        assert_eq!(restored.undo_stack.len(), sudoku.undo_stack.len());
+        assert_eq!(restored.save_session(), session);
+        while !sudoku.undo_stack.is_empty() {
+            sudoku.undo();
+            restored.undo();
+            assert_eq!(restored.snapshot(), sudoku.snapshot());
+            assert_eq!(restored.rating, sudoku.rating);
+        }
+        restored.solve_human_like();
+        assert!(restored.is_valid_solution());
+    }
+
+    #[test]
+    fn test_invalid_sessions() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let session = sudoku.save_session();
+        assert!(session.contains("\"board\": \"008"));
+        let mut other = Sudoku::new();
+        assert!(matches!(
+            other.load_session("{}"),
+            Err(SudokuError::Parse(_))
+        ));
+        assert!(
+            other
+                .load_session(&session.replace("\"version\": 1", "\"version\": 2"))
+                .is_err()
+        );
+        // The given 8 in r1c3 overwritten
+        assert!(
+            other
+                .load_session(&session.replace("\"board\": \"008", "\"board\": \"009"))
+                .is_err()
+        );
+        assert!(
+            other
+                .load_session(&session.replace("\"notes\": [", "\"notes\": [\"x\","))
+                .is_err()
+        );
+        assert_eq!(other.serialized(), Sudoku::new().serialized());
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{GridSpec, Sudoku};
+
+    const MINI: &str = "206305050000030060000010300000002401";
+    const MINI_SOLUTION: &str = "216345453126135264624513341652562431";
+    const HEXA: &str = "4.0.5....9.C.6.F..3E14...5....B.B1.D.C.3.06E75.2.C.6A..7....0...6..429..C.1...739...C368..4.1......C4..F.2398E....E3.15.76F84AC..6B.7.143F..9.......9.3.A8.5B4.7..C10.8..EB....6...9.FDE61..A.5.3.4.E..0...A...1C..5.B...7...F.8.E.BF.A.2...5.....D.35C...86E.9.";
+    const HEXA_SOLUTION: &str = "48075DB219AC36EFAF3E14068572C9BDB19D8CF3406E75A22C56AE97B3DF0184658429EACD1BF0739B7FC368EA401D25DA1C407F52398E6B02E3D15B76F84AC956BA72143FCD980EEDF0963CA825B41773C10A859EB4D2F68429BFDE6107A35C3948E720FB5A6CD1C0A56B49D7E12F381E6BF8AD2C935740F7D235C10486EB9A";
+
+    #[test]
+    fn test_grid_spec() {
+        assert_eq!(GridSpec::MINI.size(), 6);
+        assert_eq!(GridSpec::HEXA.house_sum(), 136);
+        assert_eq!(GridSpec::from_cell_count(36), Some(GridSpec::MINI));
+        assert_eq!(GridSpec::from_cell_count(80), None);
+        assert_eq!(GridSpec::MINI.box_index(2, 3), 3);
+        assert_eq!(GridSpec::HEXA.parse_cell('0'), Some(1));
+        assert_eq!(GridSpec::HEXA.parse_cell('F'), Some(16));
+        assert_eq!(GridSpec::HEXA.parse_cell('.'), Some(0));
+        assert_eq!(GridSpec::MINI.parse_cell('7'), None);
+        assert_eq!(GridSpec::HEXA.digit_char(11), 'A');
+    }
+
+    #[test]
+    fn test_parse_sizes() {
+        let sudoku = Sudoku::from_string(MINI);
+        assert_eq!(sudoku.spec, GridSpec::MINI);
+        assert_eq!(sudoku.serialized(), MINI);
+        let sudoku: Sudoku = HEXA.parse().unwrap();
+        assert_eq!(sudoku.spec, GridSpec::HEXA);
+        assert_eq!(sudoku.board[0][0], 5);
+        assert_eq!(sudoku.serialized(), HEXA);
+        assert!(
+            "207305050000030060000010300000002401"
+                .parse::<Sudoku>()
+                .is_err()
+        );
+        assert!("12345".parse::<Sudoku>().is_err());
+    }
+
+    #[test]
+    fn test_solve_mini() {
+        let mut sudoku = Sudoku::from_string(MINI);
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), MINI_SOLUTION);
+        assert!(sudoku.difficulty() > 0.0);
+    }
+
+    #[test]
+    fn test_solve_hexa() {
+        let mut sudoku = Sudoku::from_string(HEXA);
+        let mut backtracked = sudoku.clone();
+        assert!(backtracked.solve_by_backtracking().is_solved());
+        assert_eq!(backtracked.serialized(), HEXA_SOLUTION);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), HEXA_SOLUTION);
+    }
+
+    #[test]
+    #[cfg(feature = "generate")]
+    fn test_generate_mini() {
+        let sudoku = (0..100)
+            .find_map(|_| Sudoku::generate_with_spec(GridSpec::MINI, 14))
+            .unwrap();
+        assert_eq!(sudoku.spec, GridSpec::MINI);
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(!sudoku.has_conflicts());
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Candidate, Cell, Sudoku, render_diff, render_diff_ansi};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_diff_after_step() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let before = sudoku.snapshot();
+        assert!(sudoku.diff(&before).is_empty());
+        let result = sudoku.next_step();
+        let set = result.removals.sets_cell.clone().unwrap();
+        sudoku.apply(&result).unwrap();
+        let diff = sudoku.diff(&before);
+        assert_eq!(diff.placed, std::slice::from_ref(&set));
+        assert!(diff.cleared.is_empty());
+        assert!(diff.added_candidates.is_empty());
+        assert_eq!(
+            diff.removed_candidates.len(),
+            result.removals.candidates_about_to_be_removed.len()
+        );
+        assert!(diff.removed_candidates.contains(&Candidate {
+            row: set.row,
+            col: set.col,
+            num: set.num,
+        }));
+        sudoku.undo();
+        let undo_diff = sudoku.diff(&before);
+        assert!(undo_diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_after_undo() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        sudoku.set_cell(0, 0, 4).unwrap();
+        let after = sudoku.snapshot();
+        sudoku.undo();
+        let diff = sudoku.diff(&after);
+        assert_eq!(
+            diff.cleared,
+            [Cell {
+                row: 0,
+                col: 0,
+                num: 4
+            }]
+        );
+        assert!(diff.added_candidates.contains(&Candidate {
+            row: 0,
+            col: 1,
+            num: 4
+        }));
+        assert!(diff.removed_candidates.is_empty());
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let before = sudoku.snapshot();
+        assert_eq!(render_diff(&before, &before).lines().count(), 1 + 9 * 4);
+        let result = sudoku.next_step();
+        let set = result.removals.sets_cell.clone().unwrap();
+        sudoku.apply(&result).unwrap();
+        let after = sudoku.snapshot();
+        let text = render_diff(&before, &after);
+        let lines: Vec<&str> = text.lines().collect();
+        // Each row takes three lines of notes and a frame line, the digit is in the middle
+        assert!(lines[1 + 4 * set.row + 1].contains(&format!("*{}*", set.num)));
+        assert_eq!(text.matches('*').count(), 2);
+        // The notes of the cell placed in aren't shown anymore
+        let removed_elsewhere = result.removals.candidates_about_to_be_removed.len()
+            - before.candidates[set.row][set.col].iter().count();
+        assert_eq!(text.matches('-').count(), removed_elsewhere);
+        assert!(text.ends_with(&sudoku.diff(&before).to_string()));
+        assert!(text.contains(&format!("Placed: {}\n", set)));
+
+        let ansi = render_diff_ansi(&before, &after);
+        assert!(ansi.contains("\x1b[1;32m"));
+        assert!(!ansi.contains('*'));
+        assert!(!render_diff_ansi(&before, &before).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_position_hash() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let solution = sudoku.solutions().next().unwrap();
+        let start = sudoku.position_hash();
+        assert_eq!(sudoku.snapshot().position_hash(), start);
+        let (mut first, mut second) = (sudoku.clone(), sudoku.clone());
+        first.set_cell(0, 0, solution[0][0]).unwrap();
+        first.set_cell(0, 1, solution[0][1]).unwrap();
+        second.set_cell(0, 1, solution[0][1]).unwrap();
+        assert_ne!(second.position_hash(), start);
+        second.set_cell(0, 0, solution[0][0]).unwrap();
+        // Reached in another order, but the same position
+        assert_eq!(first.position_hash(), second.position_hash());
+        let note = second.get_notes(1, 0).first().unwrap();
+        second.toggle_note(1, 0, note).unwrap();
+        assert_ne!(first.position_hash(), second.position_hash());
+    }
+}
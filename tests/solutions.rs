@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rand")]
+    use rand::SeedableRng;
+    #[cfg(feature = "rand")]
+    use rand::rngs::StdRng;
+    #[cfg(feature = "rand")]
+    use rate_my_sudoku::GridSpec;
+    use rate_my_sudoku::{Budget, CancelToken, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    fn board_string(board: &[Vec<u8>]) -> String {
+        board.iter().flatten().map(|num| num.to_string()).collect()
+    }
+
+    #[test]
+    fn test_unique_solution() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let solutions: Vec<_> = sudoku.solutions().collect();
+        assert_eq!(solutions.len(), 1);
+        let mut solved = sudoku.clone();
+        solved.solve_by_backtracking();
+        assert_eq!(board_string(&solutions[0]), solved.serialized());
+    }
+
+    #[test]
+    fn test_multiple_solutions() {
+        // Without the 4 in r8c9 the puzzle has four solutions
+        let puzzle = format!("{}0{}", &PUZZLE[..71], &PUZZLE[72..]);
+        let sudoku = Sudoku::from_string(&puzzle);
+        let count = sudoku.count_solutions(10);
+        assert_eq!(count, 4);
+        let solutions: Vec<String> = sudoku.solutions().map(|b| board_string(&b)).collect();
+        assert_eq!(solutions.len(), count);
+        for (i, solution) in solutions.iter().enumerate() {
+            assert!(!solutions[..i].contains(solution));
+            let solved = Sudoku::from_string(solution);
+            assert!(solved.is_solved());
+            assert!(!solved.has_conflicts());
+            for (given, digit) in puzzle.chars().zip(solution.chars()) {
+                assert!(given == '0' || given == digit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_take_is_lazy() {
+        let sudoku = Sudoku::from_string(&"0".repeat(81));
+        assert_eq!(sudoku.solutions().take(3).count(), 3);
+    }
+
+    #[test]
+    fn test_solutions_within_budget() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let mut solutions = sudoku
+            .solutions()
+            .within(&Budget::unlimited().with_max_steps(0));
+        assert_eq!(solutions.by_ref().count(), 1);
+        assert!(!solutions.budget_exceeded());
+
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let mut solutions = sudoku
+            .solutions()
+            .within(&Budget::unlimited().with_cancel(cancel));
+        assert_eq!(solutions.next(), None);
+        assert!(solutions.budget_exceeded());
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let sudoku = Sudoku::from_string(&format!("5{}", &PUZZLE[1..]));
+        assert_eq!(sudoku.solutions().next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random_solution() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let empty = Sudoku::from_string(&"0".repeat(81));
+        let first = empty.random_solution(&mut rng).unwrap();
+        let second = empty.random_solution(&mut rng).unwrap();
+        assert_ne!(first, second);
+        for board in [first, second] {
+            let grid = Sudoku::from_string(&board_string(&board));
+            assert!(grid.is_solved());
+            assert!(!grid.has_conflicts());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random_solution_of_puzzle() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let board = sudoku.random_solution(&mut rng).unwrap();
+        assert_eq!(Some(board), sudoku.solutions().next());
+        let broken = Sudoku::from_string(&format!("5{}", &PUZZLE[1..]));
+        assert_eq!(broken.random_solution(&mut rng), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random_solution_of_mini_grid() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let board = Sudoku::with_spec(GridSpec::MINI)
+            .random_solution(&mut rng)
+            .unwrap();
+        assert_eq!(board.len(), 6);
+        assert!(board.iter().flatten().all(|&num| (1..=6).contains(&num)));
+    }
+}
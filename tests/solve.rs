@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{
+        Budget, CancelToken, Cell, Contradiction, Coord, SolveObserver, SolveOutcome, Strategy,
+        StrategyResult, Sudoku, Unit,
+    };
+    use std::time::Duration;
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_unlimited_budget_solves() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let outcome = sudoku.solve_human_like_within(&Budget::unlimited());
+        assert_eq!(outcome, SolveOutcome::Solved);
+        assert!(sudoku.is_solved());
+    }
+
+    #[test]
+    fn test_step_budget_exceeded() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let outcome = sudoku.solve_human_like_within(&Budget::unlimited().with_max_steps(5));
+        assert_eq!(outcome, SolveOutcome::BudgetExceeded);
+        assert!(sudoku.unsolved());
+        let placed = sudoku.solved_cells().count();
+        assert!(placed <= 5);
+        assert!(!sudoku.rating().is_empty());
+    }
+
+    #[test]
+    fn test_zero_duration_exceeded() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let outcome =
+            sudoku.solve_human_like_within(&Budget::unlimited().with_max_duration(Duration::ZERO));
+        assert_eq!(outcome, SolveOutcome::BudgetExceeded);
+        assert_eq!(
+            sudoku.serialized(),
+            Sudoku::from_string(PUZZLE).serialized()
+        );
+    }
+
+    #[test]
+    fn test_stuck() {
+        // Needs strategies beyond the implemented ones
+        let mut sudoku = Sudoku::from_string(
+            "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+        );
+        let outcome = sudoku.solve_human_like_within(&Budget::unlimited());
+        assert!(matches!(outcome, SolveOutcome::StuckAt(empty) if empty > 0));
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let cancel = CancelToken::new();
+        let budget = Budget::unlimited().with_cancel(cancel.clone());
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        cancel.cancel();
+        assert_eq!(
+            sudoku.solve_human_like_within(&budget),
+            SolveOutcome::Cancelled
+        );
+        assert!(sudoku.rating().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "generate")]
+    fn test_generate_cancelled() {
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        assert!(
+            Sudoku::generate_cancellable(rate_my_sudoku::GridSpec::CLASSIC, 30, &cancel).is_none()
+        );
+    }
+
+    #[test]
+    fn test_contradiction_empty_cell() {
+        // r1c1 must be 4, but 5 doesn't clash with any given
+        let mut sudoku = Sudoku::from_string(&format!("5{}", &PUZZLE[1..]));
+        assert!(!sudoku.has_conflicts());
+        assert_eq!(
+            sudoku.solve_human_like_within(&Budget::unlimited()),
+            SolveOutcome::Contradiction(Contradiction::EmptyCell(Coord::new(0, 4)))
+        );
+    }
+
+    #[test]
+    fn test_contradiction_missing_digit() {
+        let mut sudoku = Sudoku::from_string(&format!("1{}", &PUZZLE[1..]));
+        assert_eq!(
+            sudoku.solve_human_like_within(&Budget::unlimited()),
+            SolveOutcome::Contradiction(Contradiction::MissingDigit {
+                unit: Unit::Row,
+                index: 0,
+                num: 7
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_contradiction() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        assert_eq!(sudoku.find_contradiction(), None);
+    }
+
+    #[test]
+    fn test_backtracking_outcomes() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert_eq!(sudoku.solve_by_backtracking(), SolveOutcome::Solved);
+        assert!(sudoku.is_solved());
+
+        let mut sudoku = Sudoku::from_string(&"0".repeat(81));
+        assert_eq!(
+            sudoku.solve_by_backtracking(),
+            SolveOutcome::MultipleSolutions
+        );
+        assert!(sudoku.is_solved());
+        assert!(!sudoku.has_conflicts());
+
+        let mut sudoku = Sudoku::from_string(&format!("5{}", &PUZZLE[1..]));
+        assert_eq!(
+            sudoku.solve_by_backtracking(),
+            SolveOutcome::Contradiction(Contradiction::NoSolution)
+        );
+        assert!(sudoku.unsolved());
+    }
+
+    /// Records what the solver reports.
+    #[derive(Default)]
+    struct Recorder {
+        steps: usize,
+        placements: Vec<Cell>,
+        contradictions: Vec<Contradiction>,
+    }
+
+    impl SolveObserver for Recorder {
+        fn on_step(&mut self, sudoku: &Sudoku, step: &StrategyResult) {
+            self.steps += 1;
+            if let Some(cell) = &step.removals.sets_cell {
+                assert_eq!(sudoku.get_num(cell.row, cell.col), cell.num);
+                assert_eq!(self.placements.last(), Some(cell));
+            }
+        }
+
+        fn on_placement(&mut self, cell: &Cell) {
+            self.placements.push(cell.clone());
+        }
+
+        fn on_contradiction(&mut self, contradiction: &Contradiction) {
+            self.contradictions.push(contradiction.clone());
+        }
+    }
+
+    #[test]
+    fn test_observer_sees_every_step() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let mut recorder = Recorder::default();
+        let outcome = sudoku.solve_human_like_observed(&Budget::unlimited(), &mut recorder);
+        assert_eq!(outcome, SolveOutcome::Solved);
+        assert_eq!(recorder.placements.len(), sudoku.solved_cells().count());
+        assert!(recorder.steps >= recorder.placements.len());
+        assert!(recorder.contradictions.is_empty());
+    }
+
+    #[test]
+    fn test_observer_sees_contradiction() {
+        let mut sudoku = Sudoku::from_string(&format!("5{}", &PUZZLE[1..]));
+        let mut recorder = Recorder::default();
+        let outcome = sudoku.solve_human_like_observed(&Budget::unlimited(), &mut recorder);
+        assert_eq!(
+            recorder.contradictions,
+            [Contradiction::EmptyCell(Coord::new(0, 4))]
+        );
+        assert_eq!(
+            outcome,
+            SolveOutcome::Contradiction(recorder.contradictions[0].clone())
+        );
+    }
+
+    #[test]
+    fn test_fill_singles() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let placed = sudoku.fill_singles(false);
+        assert!(placed > 0);
+        assert_eq!(sudoku.solved_cells().count(), placed);
+        assert!(sudoku.rating().is_empty());
+        assert!(
+            sudoku.find_obvious_single().removals.sets_cell.is_none()
+                && sudoku.find_hidden_single().removals.sets_cell.is_none()
+        );
+        sudoku.undo();
+        assert_eq!(sudoku.solved_cells().count(), placed - 1);
+
+        let mut recorded = Sudoku::from_string(PUZZLE);
+        recorded.calc_all_notes();
+        assert_eq!(recorded.fill_singles(true), placed);
+        let singles = [
+            Strategy::LastDigit,
+            Strategy::ObviousSingle,
+            Strategy::HiddenSingle,
+        ];
+        assert!(!recorded.rating().is_empty());
+        assert!(
+            recorded
+                .rating()
+                .keys()
+                .all(|strategy| singles.contains(strategy))
+        );
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{CorpusStats, Coverage, DifficultyLevel, Error, RatedPuzzle, Strategy};
+
+    const PUZZLES: [&str; 4] = [
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+        "610040000090008273000705146100000000400609051005480900000010009740000300020000600",
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000",
+        // Solution of the first puzzle
+        "417523689869147253325869147193754826648291375752386491534918762281675934976432518",
+    ];
+
+    #[test]
+    fn test_corpus_stats() {
+        let rated: Vec<RatedPuzzle> = PUZZLES
+            .iter()
+            .map(|puzzle| RatedPuzzle::rate(puzzle).unwrap())
+            .collect();
+        assert_eq!(rated[0].givens, 30);
+        assert!(rated[0].solved);
+        let stats = CorpusStats::new(&rated);
+        assert_eq!(stats.puzzles, 4);
+        assert_eq!(stats.rated, 3);
+        let histogram_total: usize = stats.difficulty_histogram.iter().map(|&(_, n)| n).sum();
+        assert_eq!(histogram_total, 3);
+        assert!(
+            stats
+                .difficulty_histogram
+                .windows(2)
+                .all(|bins| bins[0].0 < bins[1].0)
+        );
+        let level_total: usize = stats.level_counts.iter().map(|&(_, n)| n).sum();
+        assert_eq!(level_total, 3);
+        assert_eq!(stats.strategies[0], Strategy::LastDigit);
+        assert!(!stats.strategies.contains(&Strategy::Arrow));
+        let last_digit: usize = stats
+            .strategy_frequency
+            .iter()
+            .map(|(_, counts)| counts[0])
+            .sum();
+        assert_eq!(last_digit, 3);
+        assert_eq!(stats.strategy_frequency[0].0, DifficultyLevel::Easy);
+        assert!((-1.0..=1.0).contains(&stats.givens_correlation));
+        let table = stats.to_string();
+        assert!(table.starts_with("Puzzles: 4, rated: 3\n"));
+        assert!(table.contains("Last Digit"));
+    }
+
+    #[test]
+    fn test_rate_invalid() {
+        assert!(matches!(RatedPuzzle::rate("0123"), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn test_oneline() {
+        let rated = RatedPuzzle::rate(PUZZLES[2]).unwrap();
+        assert_eq!(rated.hardest_technique(), Some(Strategy::ClaimingPair));
+        assert_eq!(
+            rated.oneline(),
+            format!(
+                "{} solved=yes difficulty={:.2} effort=50 hardest=Claiming-Pair clues=24",
+                PUZZLES[2], rated.difficulty
+            )
+        );
+        let grid = RatedPuzzle::rate(PUZZLES[3]).unwrap();
+        assert_eq!(grid.hardest_technique(), None);
+        assert!(grid.oneline().ends_with(" effort=0 hardest=none clues=81"));
+    }
+
+    #[test]
+    fn test_coverage() {
+        // Arto Inkala's puzzle needs strategies beyond the implemented ones
+        let hardest =
+            "800000000003600000070090200050007000000045700000100030001000068008500010090000400";
+        let rated: Vec<RatedPuzzle> = PUZZLES[..3]
+            .iter()
+            .chain([&hardest])
+            .map(|puzzle| RatedPuzzle::rate(puzzle).unwrap())
+            .collect();
+        let coverage = Coverage::new(&rated);
+        assert_eq!(coverage.puzzles, 4);
+        assert_eq!(coverage.unsolved, vec![hardest.to_string()]);
+        assert_eq!(coverage.strategies[0].strategy, Strategy::LastDigit);
+        assert_eq!(coverage.strategies[0].used, 3);
+        let hardest_total: usize = coverage.strategies.iter().map(|s| s.hardest).sum();
+        assert_eq!(hardest_total, 3);
+        assert_eq!(coverage.share(1), 0.25);
+        let report = coverage.to_string();
+        assert!(report.starts_with("Puzzles: 4, unsolved: 1 (25.0%)"));
+        assert!(report.ends_with(&format!("Unsolved:\n{}\n", hardest)));
+
+        let empty = Coverage::new(&[]);
+        assert_eq!(empty.share(0), 0.0);
+        assert!(!empty.to_string().contains("NaN"));
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use rate_my_sudoku::{Budget, StepLog, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_one_line_per_step() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let mut log = StepLog::new(Vec::new());
+        assert!(
+            sudoku
+                .solve_human_like_observed(&Budget::default(), &mut log)
+                .is_solved()
+        );
+        let output = String::from_utf8(log.finish().unwrap()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(!lines.is_empty());
+        for (i, line) in lines.iter().enumerate() {
+            assert_eq!(line["step"], i + 1);
+            assert!(line["strategy"].is_string());
+            assert!(line["state_hash"].is_u64());
+            assert!(line["eliminations"].is_array());
+        }
+        assert_eq!(lines.last().unwrap()["state_hash"], sudoku.state_hash());
+    }
+
+    #[test]
+    fn test_state_hash() {
+        let mut a = Sudoku::from_string(PUZZLE);
+        let mut b = Sudoku::from_string(PUZZLE);
+        assert_eq!(a.state_hash(), b.state_hash());
+        a.calc_all_notes();
+        assert_ne!(a.state_hash(), b.state_hash());
+        b.calc_all_notes();
+        assert_eq!(a.state_hash(), b.state_hash());
+    }
+}
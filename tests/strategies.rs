@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use rate_my_sudoku::{Candidate, Strategy, Sudoku, Unit};
+    use rate_my_sudoku::{Candidate, LinkKind, Strategy, Sudoku, Unit};
 
     #[test]
     fn test_claiming_pair1() {
@@ -12,7 +12,7 @@ mod tests {
         assert_eq!(result.strategy, Strategy::ClaimingPair);
         assert_eq!(result.removals.candidates_about_to_be_removed.len(), 1);
         assert_eq!(result.removals.unit, Some(Unit::Row));
-        assert_eq!(result.removals.unit_index, Some(vec![1]));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[1][..]));
         assert!(result.removals.sets_cell.is_none());
         let removals = result.removals.candidates_about_to_be_removed;
         assert!(removals.contains(&Candidate {
@@ -45,7 +45,7 @@ mod tests {
         assert_eq!(result.strategy, Strategy::ClaimingPair);
         assert_eq!(result.removals.candidates_about_to_be_removed.len(), 6);
         assert_eq!(result.removals.unit, Some(Unit::Column));
-        assert_eq!(result.removals.unit_index, Some(vec![5]));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[5][..]));
         assert!(result.removals.sets_cell.is_none());
         let removals = result.removals.candidates_about_to_be_removed;
         assert_eq!(removals.len(), 6);
@@ -103,7 +103,7 @@ mod tests {
         println!("{:?}", result);
         assert_eq!(result.strategy, Strategy::PointingPair);
         assert_eq!(result.removals.unit, Some(Unit::Row));
-        assert_eq!(result.removals.unit_index, Some(vec![2]));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[2][..]));
         assert!(result.removals.sets_cell.is_none());
         let removals = result.removals.candidates_about_to_be_removed;
         assert_eq!(removals.len(), 1);
@@ -136,7 +136,7 @@ mod tests {
         println!("{:?}", result);
         assert_eq!(result.strategy, Strategy::PointingPair);
         assert_eq!(result.removals.unit, Some(Unit::Row));
-        assert_eq!(result.removals.unit_index, Some(vec![6]));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[6][..]));
         let removals = result.removals.candidates_about_to_be_removed;
         assert_eq!(removals.len(), 6);
         assert!(removals.contains(&Candidate {
@@ -182,4 +182,163 @@ mod tests {
             num: 1
         }));
     }
+
+    #[test]
+    fn test_claiming_triple() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "000100078107020000083070000304000207070201060008790500041005080090007006030000900",
+        );
+        sudoku.calc_all_notes();
+        let result = sudoku.find_claiming_pair();
+        assert_eq!(result.strategy, Strategy::ClaimingPair);
+        assert_eq!(result.removals.unit, Some(Unit::Row));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[0][..]));
+        let removals = result.removals.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 1);
+        assert!(removals.contains(&Candidate {
+            row: 2,
+            col: 0,
+            num: 2
+        }));
+        let candidates_affected = result.removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 3);
+        for col in 0..3 {
+            assert!(candidates_affected.contains(&Candidate {
+                row: 0,
+                col,
+                num: 2
+            }));
+        }
+    }
+
+    #[test]
+    fn test_pointing_triple() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "610040000090008273000705146100000000400609051005480900000010009740000300020000600",
+        );
+        sudoku.calc_all_notes();
+        let result = sudoku.find_pointing_pair();
+        assert_eq!(result.strategy, Strategy::PointingPair);
+        assert_eq!(result.removals.unit, Some(Unit::Row));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[0][..]));
+        let removals = result.removals.candidates_about_to_be_removed;
+        assert_eq!(removals.len(), 1);
+        assert!(removals.contains(&Candidate {
+            row: 0,
+            col: 2,
+            num: 8
+        }));
+        let candidates_affected = result.removals.candidates_affected;
+        assert_eq!(candidates_affected.len(), 3);
+        for col in 6..9 {
+            assert!(candidates_affected.contains(&Candidate {
+                row: 0,
+                col,
+                num: 8
+            }));
+        }
+    }
+
+    #[test]
+    fn test_pointing_pair_eliminates() {
+        let mut sudoku: Sudoku = Sudoku::from_string(
+            "984000000002500040001904002006097230003602000209035610195768423427351896638009751",
+        );
+        sudoku.calc_all_notes();
+        let result = sudoku.find_pointing_pair();
+        assert_eq!(result.strategy, Strategy::PointingPair);
+        let resolution = sudoku.apply(&result).unwrap();
+        assert_eq!(resolution.nums_removed, 1);
+        assert!(!sudoku.get_notes(2, 6).contains(&5));
+        // The pattern keeps its candidates, so the same step isn't found again
+        assert!(sudoku.get_notes(2, 0).contains(&5));
+        assert!(sudoku.get_notes(2, 1).contains(&5));
+        let next = sudoku.find_pointing_pair();
+        assert!(
+            !next
+                .removals
+                .candidates_about_to_be_removed
+                .contains(&Candidate {
+                    row: 2,
+                    col: 6,
+                    num: 5
+                })
+        );
+    }
+
+    /// Check that each step separates its pattern from its changes.
+    fn assert_pattern_and_changes(sudoku: &Sudoku) {
+        for (_, steps) in &sudoku.analyze().strategies {
+            for step in steps {
+                let removals = &step.removals;
+                assert!(!removals.cells_affected.is_empty(), "{:?}", step.strategy);
+                assert!(!removals.candidates_about_to_be_removed.is_empty());
+                for candidate in &removals.candidates_affected {
+                    assert!(
+                        removals
+                            .cells_affected
+                            .iter()
+                            .any(|cell| (cell.row, cell.col) == (candidate.row, candidate.col))
+                    );
+                    let placed = removals.sets_cell.as_ref().is_some_and(|cell| {
+                        (cell.row, cell.col, cell.num)
+                            == (candidate.row, candidate.col, candidate.num)
+                    });
+                    assert!(
+                        placed || !removals.candidates_about_to_be_removed.contains(candidate),
+                        "{:?} removes its own pattern",
+                        step.strategy
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_steps_report_pattern_and_changes() {
+        for puzzle in [
+            "020000689000500001400000020100005708030016090000007003003002157009700842200800000",
+            "000100078107020000083070000314000297579201860008790500041905780890007006730002900",
+        ] {
+            let mut sudoku = Sudoku::from_string(puzzle);
+            sudoku.calc_all_notes();
+            loop {
+                assert_pattern_and_changes(&sudoku);
+                let step = sudoku.next_step();
+                if step.strategy == Strategy::None {
+                    break;
+                }
+                sudoku.apply(&step).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_xwing_links() {
+        let sudoku = Sudoku::from_pencilmarks(
+            "6 8 7 3 [1249] [124] 5 [129] [19] 4 [29] 3 7 5 [12] [128] 6 [189] 1 5 [29] 6 [29] 8 \
+             4 3 7 2 [19] [189] 5 [46] [46] [18] 7 3 3 7 4 1 8 9 6 5 2 [58] [16] [1568] 2 7 3 9 \
+             [18] 4 7 [126] [12568] 4 3 [126] [128] [1289] [1589] 9 3 [12] 8 [12] 5 7 4 6 [58] 4 \
+             [12568] 9 [126] 7 3 [128] [158]",
+        )
+        .unwrap();
+        let step = sudoku.find_xwing();
+        assert_eq!(step.strategy, Strategy::XWing);
+        let links = &step.removals.links;
+        assert_eq!(links.len(), 4);
+        for (i, link) in links.iter().enumerate() {
+            let next = &links[(i + 1) % links.len()];
+            assert_eq!(link.to, next.from);
+            assert_ne!(link.kind, next.kind);
+            assert_eq!(link.from.num, 2);
+            assert!(step.removals.candidates_affected.contains(&link.from));
+            // Strong links run along the rows of the X-Wing, weak ones across them
+            match link.kind {
+                LinkKind::Strong => assert_eq!(link.from.row, link.to.row),
+                LinkKind::Weak => assert_eq!(link.from.col, link.to.col),
+            }
+        }
+        assert_eq!(links[0].kind, LinkKind::Strong);
+        assert!(sudoku.find_hidden_pair().removals.links.is_empty());
+    }
 }
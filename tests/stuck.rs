@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{EMPTY, Strategy, Sudoku};
+
+    #[test]
+    fn test_stuck_report() {
+        // Arto Inkala's puzzle needs strategies beyond the implemented ones
+        let sudoku = Sudoku::from_string(
+            "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+        );
+        let report = sudoku.stuck_report().unwrap();
+        let empty: Vec<(usize, usize)> = (0..81)
+            .map(|idx| (idx / 9, idx % 9))
+            .filter(|&(row, col)| report.position.board[row][col] == EMPTY)
+            .collect();
+        assert_eq!(report.empty_cells, empty.len());
+        let notes: Vec<usize> = empty
+            .iter()
+            .map(|&(row, col)| report.position.candidates[row][col].len())
+            .collect();
+        assert_eq!(report.candidates, notes.iter().sum::<usize>());
+        assert_eq!(
+            report.bivalue_cells,
+            notes.iter().filter(|&&count| count == 2).count()
+        );
+        assert_eq!(report.strategies_tried[0], Strategy::LastDigit);
+        assert!(report.strategies_tried.contains(&Strategy::XWing));
+        let path = report.shortest_path.clone().unwrap();
+        let solution = sudoku.solutions().next().unwrap();
+        assert!(
+            path.iter()
+                .all(|cell| solution[cell.row][cell.col] == cell.num)
+        );
+        assert!(
+            report
+                .to_string()
+                .starts_with(&format!("Stuck with {} empty cells", report.empty_cells))
+        );
+    }
+
+    #[test]
+    fn test_stuck_report_solved() {
+        let sudoku = Sudoku::from_string(
+            "017003009000000250300800040000004020008091005750000091530008060200600900900400018",
+        );
+        assert!(sudoku.stuck_report().is_none());
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{
+        Candidate, MAX_SUBSET_SIZE, MIN_SUBSET_SIZE, Strategy, SubsetKind, Sudoku, Unit,
+    };
+
+    /// Has a hidden quad of 2, 4, 7 and 8 in row 4, but no smaller subset
+    const HIDDEN_QUAD: &str = "[18] [67] 4 3 9 [67] [258] [125] [158] 2 [368] [36] 5 1 [68] 9 7 4 \
+        [189] [1789] 5 2 4 [78] 6 [13] [138] [139] [1569] [169] [48] [35] [19] [2578] [2345] \
+        [3578] 7 [19] 2 [48] [35] [19] [58] [345] 6 [34] [45] 8 6 7 2 1 9 [35] [48] [48] [17] 9 \
+        6 3 [57] [15] 2 6 [139] [139] 7 2 5 4 8 [19] 5 2 [79] 1 8 4 3 6 [79]";
+
+    #[test]
+    fn test_subset_strategies() {
+        for kind in [SubsetKind::Obvious, SubsetKind::Hidden] {
+            for size in MIN_SUBSET_SIZE..=MAX_SUBSET_SIZE {
+                let strategy = Strategy::subset(kind, size).unwrap();
+                assert_eq!(strategy.subset_kind(), Some((kind, size)));
+            }
+            assert_eq!(Strategy::subset(kind, 1), None);
+            assert_eq!(Strategy::subset(kind, 5), None);
+        }
+        assert_eq!(
+            Strategy::subset(SubsetKind::Hidden, 3),
+            Some(Strategy::HiddenTriple)
+        );
+        assert_eq!(Strategy::XWing.subset_kind(), None);
+    }
+
+    #[test]
+    fn test_find_subset() {
+        let sudoku = Sudoku::from_pencilmarks(HIDDEN_QUAD).unwrap();
+        for size in MIN_SUBSET_SIZE..MAX_SUBSET_SIZE {
+            for kind in [SubsetKind::Obvious, SubsetKind::Hidden] {
+                let result = sudoku.find_subset(kind, size);
+                assert!(result.removals.candidates_about_to_be_removed.is_empty());
+            }
+        }
+
+        let result = sudoku.find_subset(SubsetKind::Hidden, 4);
+        assert_eq!(result.strategy, Strategy::HiddenQuad);
+        assert_eq!(result.removals.unit, Some(Unit::Row));
+        assert_eq!(result.removals.unit_index.as_deref(), Some(&[3][..]));
+        let removed: Vec<String> = result
+            .removals
+            .candidates_about_to_be_removed
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            removed,
+            ["r4c7<>5", "r4c8<>3", "r4c8<>5", "r4c9<>3", "r4c9<>5"]
+        );
+        // The pattern consists of the quad's digits in its four cells
+        assert_eq!(result.removals.cells_affected.len(), 4);
+        assert!(result.removals.candidates_affected.contains(&Candidate {
+            row: 3,
+            col: 3,
+            num: 4
+        }));
+        assert!(
+            result
+                .removals
+                .candidates_affected
+                .iter()
+                .all(|c| [2, 4, 7, 8].contains(&c.num))
+        );
+        assert_eq!(
+            sudoku
+                .find_hidden_quad()
+                .removals
+                .candidates_about_to_be_removed,
+            result.removals.candidates_about_to_be_removed
+        );
+
+        // The cells other than the quad form an obvious quintuple, too large to look for
+        assert!(
+            sudoku
+                .find_subset(SubsetKind::Obvious, 5)
+                .removals
+                .candidates_about_to_be_removed
+                .is_empty()
+        );
+        assert_eq!(
+            sudoku.find_subset(SubsetKind::Obvious, 5).strategy,
+            Strategy::None
+        );
+    }
+}
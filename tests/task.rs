@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Board, CancelToken, Solver, Task};
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::Thread;
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Poll the future on the current thread, parking it until woken.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_spawn_rate_awaited() {
+        let board: Board = PUZZLE.parse().unwrap();
+        let expected = Solver::new().rate(&board).unwrap();
+        let rating = block_on(Solver::new().spawn_rate(board)).unwrap();
+        assert_eq!(rating.difficulty, expected.difficulty);
+        assert!(rating.solved);
+    }
+
+    #[test]
+    fn test_spawn_solve_waited() {
+        let board: Board = PUZZLE.parse().unwrap();
+        let task = Solver::new().spawn_solve(board);
+        let solution = task.wait().unwrap();
+        assert_eq!(solution, rate_my_sudoku::solve(PUZZLE).unwrap());
+    }
+
+    #[test]
+    fn test_cancel_task() {
+        let cancel = CancelToken::new();
+        let token = cancel.clone();
+        let task = Task::spawn(cancel, move || {
+            while !token.is_cancelled() {
+                std::thread::yield_now();
+            }
+            42
+        });
+        assert!(!task.is_finished());
+        task.cancel();
+        assert_eq!(block_on(task), 42);
+    }
+
+    #[test]
+    fn test_drop_cancels_task() {
+        let cancel = CancelToken::new();
+        let token = cancel.clone();
+        let task = Task::spawn(cancel.clone(), move || {
+            while !token.is_cancelled() {
+                std::thread::yield_now();
+            }
+        });
+        drop(task);
+        assert!(cancel.is_cancelled());
+    }
+
+    #[test]
+    fn test_panicking_task() {
+        let task = Task::spawn(CancelToken::new(), || -> u32 { panic!("broken puzzle") });
+        let panic = std::panic::catch_unwind(|| task.wait()).unwrap_err();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"broken puzzle"));
+
+        let task = Task::spawn(CancelToken::new(), || -> u32 { panic!("broken puzzle") });
+        let panic = std::panic::catch_unwind(|| block_on(task)).unwrap_err();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"broken puzzle"));
+    }
+}
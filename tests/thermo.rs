@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Strategy, Sudoku, Thermometer};
+
+    const PUZZLE: &str =
+        "009000300500080000040200000000506000430000000600090051000009002000000000006030000";
+    const THERMOMETERS: &str = "r9c6-r8c6-r9c7-r9c8 r9c2-r8c2-r8c1 r8c3-r8c4-r9c4 r2c2-r2c1-r1c2 r5c6-r6c6-r5c7 r1c7-r2c8-r1c9-r1c8 r3c8-r4c8-r5c9";
+    const SOLUTION: &str =
+        "269451387517983246348267519891546723435172968672398451754819632983625174126734895";
+
+    #[test]
+    fn test_parse_thermometers() {
+        let thermometers = Thermometer::parse_thermometers("r1c1-r2c2-r2c3 r9c9-r8c9").unwrap();
+        assert_eq!(thermometers.len(), 2);
+        assert_eq!(thermometers[0].cells, vec![(0, 0), (1, 1), (1, 2)]);
+        assert!(Thermometer::parse_thermometers("r1c1").is_err());
+        assert!(Thermometer::parse_thermometers("r1c1-r1c3").is_err());
+        assert!(Thermometer::parse_thermometers("r1c1-x").is_err());
+    }
+
+    #[test]
+    fn test_thermometer_restricts_placement() {
+        let mut sudoku = Sudoku::new();
+        sudoku.set_thermometers_string("r1c1-r1c2-r1c3").unwrap();
+        // The bulb needs room for two greater digits, the tip for two smaller ones
+        assert!(!sudoku.can_place(0, 0, 8));
+        assert!(sudoku.can_place(0, 0, 7));
+        assert!(!sudoku.can_place(0, 2, 2));
+        sudoku.board[0][0] = 4;
+        assert!(!sudoku.can_place(0, 1, 4));
+        assert!(!sudoku.can_place(0, 2, 5));
+        assert!(sudoku.can_place(0, 2, 6));
+        sudoku.board[0][1] = 3;
+        assert!(sudoku.has_conflicts());
+    }
+
+    #[test]
+    fn test_solve_thermometer() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert!(!sudoku.clone().solve_human_like().is_solved());
+        sudoku.set_thermometers_string(THERMOMETERS).unwrap();
+        assert_eq!(sudoku.count_solutions(2), 1);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), SOLUTION);
+        assert!(sudoku.rating.contains_key(&Strategy::Thermometer));
+    }
+}
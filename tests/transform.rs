@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Sudoku, SudokuError, Transform};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_rotation() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let rotated = sudoku
+            .transformed(&Transform::new().with_rotation(1))
+            .unwrap();
+        for row in 0..9 {
+            for col in 0..9 {
+                assert_eq!(rotated.board[row][col], sudoku.board[8 - col][row]);
+            }
+        }
+        assert!(rotated.is_given(0, 0));
+        let full_turn = Transform::new().with_rotation(3).with_rotation(1);
+        assert_eq!(full_turn, Transform::new());
+        let back = rotated
+            .transformed(&Transform::new().with_rotation(3))
+            .unwrap();
+        assert_eq!(back.serialized(), PUZZLE);
+    }
+
+    #[test]
+    fn test_isomorphic() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        let transform = Transform::new()
+            .with_transpose()
+            .with_rotation(2)
+            .with_relabel_seed(42);
+        let mut transformed = sudoku.transformed(&transform).unwrap();
+        assert_ne!(transformed.serialized(), PUZZLE);
+        assert_eq!(
+            transformed.canonical_form().unwrap(),
+            sudoku.canonical_form().unwrap()
+        );
+        assert_eq!(
+            sudoku.transformed(&transform).unwrap().serialized(),
+            transformed.serialized()
+        );
+        assert!(sudoku.solve_human_like().is_solved());
+        assert!(transformed.solve_human_like().is_solved());
+        assert_eq!(transformed.hardest_technique(), sudoku.hardest_technique());
+    }
+
+    #[test]
+    fn test_relabeling() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.calc_all_notes();
+        let mapping = vec![2, 3, 4, 5, 6, 7, 8, 9, 1];
+        let relabeled = sudoku
+            .transformed(&Transform::new().with_relabeling(mapping))
+            .unwrap();
+        assert_eq!(relabeled.board[0][2], 9);
+        assert_eq!(relabeled.board[0][7], 7);
+        let notes: Vec<u8> = relabeled.candidates[0][0].iter().collect();
+        let expected: Vec<u8> = sudoku.candidates[0][0]
+            .iter()
+            .map(|num| num % 9 + 1)
+            .collect::<rate_my_sudoku::DigitSet>()
+            .iter()
+            .collect();
+        assert_eq!(notes, expected);
+        for mapping in [vec![1, 2, 3], vec![1, 1, 3, 4, 5, 6, 7, 8, 9], vec![0; 9]] {
+            assert!(matches!(
+                sudoku.transformed(&Transform::new().with_relabeling(mapping)),
+                Err(SudokuError::Parse(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rectangular_boxes() {
+        let sudoku: Sudoku = "120000000300000040005000000600000001".parse().unwrap();
+        assert!(matches!(
+            sudoku.transformed(&Transform::new().with_transpose()),
+            Err(SudokuError::VariantUnsupported(_))
+        ));
+        let turned = sudoku
+            .transformed(&Transform::new().with_rotation(2))
+            .unwrap();
+        assert_eq!(turned.serialized(), "100000006000000500040000003000000021");
+    }
+}
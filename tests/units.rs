@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Constraints, Coord, EMPTY, House, Sudoku, Unit};
+
+    const HYPER: &str =
+        "700014000000508400000600000010002000000000000000080000030007920290000040800090000";
+    const HYPER_SOLUTION: &str =
+        "765914382123578469489623175314762598678359214952481637536847921297135846841296753";
+    const DIAGONAL: &str =
+        "600010000000089000000230040000000000000100000300000500000000020800902000001006030";
+    const DIAGONAL_SOLUTION: &str =
+        "652714398134689257789235146297563481465128973318497562546371829873942615921856734";
+
+    #[test]
+    fn test_groups() {
+        let mut sudoku = Sudoku::new();
+        assert_eq!(sudoku.groups().len(), 27);
+        sudoku.set_constraints(Constraints::DIAGONAL | Constraints::WINDOWS);
+        assert_eq!(sudoku.groups().len(), 33);
+        assert_eq!(
+            sudoku.unit_cells(&Unit::Diagonal, 1)[..3],
+            [(0, 8), (1, 7), (2, 6)]
+        );
+        assert_eq!(
+            sudoku.unit_cells(&Unit::Window, 3)[..4],
+            [(5, 5), (5, 6), (5, 7), (6, 5)]
+        );
+        assert!(sudoku.add_unit(vec![(0, 0), (4, 4), (0, 0)]).is_err());
+        assert!(sudoku.add_unit(vec![(0, 0), (9, 4)]).is_err());
+        sudoku.add_unit(vec![(0, 0), (4, 5), (8, 1)]).unwrap();
+        assert_eq!(sudoku.groups().len(), 34);
+        assert_eq!(sudoku.complete_groups().len(), 33);
+    }
+
+    #[test]
+    fn test_houses() {
+        let sudoku = Sudoku::new();
+        assert_eq!(sudoku.houses().count(), 27);
+        assert_eq!(sudoku.lines().nth(9), Some(House::Column(0)));
+        let cells: Vec<_> = sudoku.cells_of(House::Box(5)).collect();
+        assert_eq!(cells[..4], [(3, 6), (3, 7), (3, 8), (4, 6)]);
+        assert!(sudoku.house_contains(House::Box(5), 5, 8));
+        assert!(!sudoku.house_contains(House::Column(4), 5, 8));
+        assert_eq!(House::Column(4).unit(), Unit::Column);
+        assert_eq!(House::Column(4).index(), 4);
+    }
+
+    #[test]
+    fn test_house_views() {
+        let mut sudoku = Sudoku::from_string(HYPER);
+        let cells: Vec<(Coord, u8)> = sudoku.cells().collect();
+        assert_eq!(cells.len(), 81);
+        assert_eq!(cells[0], (Coord::new(0, 0), 7));
+        assert_eq!(cells[80], (Coord::new(8, 8), EMPTY));
+        assert_eq!(sudoku.to_board().cells().collect::<Vec<_>>(), cells);
+        assert_eq!(
+            sudoku.rows().map(|row| row.house()).collect::<Vec<_>>(),
+            (0..9).map(House::Row).collect::<Vec<_>>()
+        );
+        let row = sudoku.rows().next().unwrap();
+        assert_eq!(row.digits().iter().collect::<Vec<_>>(), [1, 4, 7]);
+        assert_eq!(row.empty_cells().count(), 6);
+        let col = sudoku.cols().nth(1).unwrap();
+        assert_eq!(col.house(), House::Column(1));
+        assert_eq!(
+            col.cells().find(|&(_, num)| num != EMPTY),
+            Some((Coord::new(3, 1), 1))
+        );
+        let first_box = sudoku.boxes().next().unwrap();
+        assert_eq!(first_box.cells().nth(3), Some((Coord::new(1, 0), EMPTY)));
+        sudoku.calc_all_notes();
+        let places: Vec<Coord> = sudoku.house_view(House::Box(0)).places_for(7).collect();
+        assert!(places.is_empty());
+        let places: Vec<Coord> = sudoku.house_view(House::Row(0)).places_for(2).collect();
+        assert!(
+            places
+                .iter()
+                .all(|c| sudoku.candidates[c.row][c.col].contains(&2))
+        );
+        assert!(!places.is_empty());
+    }
+
+    #[test]
+    fn test_custom_unit_rules() {
+        let mut sudoku = Sudoku::new();
+        sudoku.add_unit(vec![(0, 0), (4, 5), (8, 1)]).unwrap();
+        sudoku.board[0][0] = 3;
+        assert!(!sudoku.can_place(4, 5, 3));
+        assert!(sudoku.can_place(4, 6, 3));
+        sudoku.calc_all_notes();
+        assert!(!sudoku.candidates[8][1].contains(&3));
+        sudoku.board[8][1] = 3;
+        assert!(sudoku.has_conflicts());
+    }
+
+    #[test]
+    fn test_solve_hyper() {
+        let mut sudoku = Sudoku::from_string(HYPER);
+        assert!(sudoku.count_solutions(2) > 1);
+        sudoku.set_constraints(Constraints::WINDOWS);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), HYPER_SOLUTION);
+        assert!(!sudoku.has_conflicts());
+    }
+
+    #[test]
+    fn test_solve_diagonal() {
+        let mut sudoku = Sudoku::from_string(DIAGONAL);
+        assert!(sudoku.count_solutions(2) > 1);
+        sudoku.set_constraints(Constraints::DIAGONAL);
+        assert!(sudoku.solve_human_like().is_solved());
+        assert_eq!(sudoku.serialized(), DIAGONAL_SOLUTION);
+    }
+}
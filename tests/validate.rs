@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Coord, SudokuError, Unit, validate};
+
+    const MINIMAL: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+    const NOT_MINIMAL: &str =
+        "017003009000000250300800040000004020008091005750000091530008060200600900900400018";
+
+    #[test]
+    fn test_validate_minimal() {
+        let validation = validate(MINIMAL).unwrap();
+        assert_eq!(validation.givens, 24);
+        assert!(validation.duplicates.is_empty());
+        assert_eq!(validation.solutions, Some(1));
+        assert!(validation.is_valid());
+        assert!(validation.is_minimal());
+        assert_eq!(
+            validation.to_string(),
+            "Clues: 24\nSolutions: 1\nMinimal: yes\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_not_minimal() {
+        let validation = validate(NOT_MINIMAL).unwrap();
+        assert!(validation.is_valid());
+        assert!(!validation.is_minimal());
+        let redundant = validation.redundant_givens.unwrap();
+        assert!(redundant.contains(&Coord::new(0, 8)));
+        assert!(redundant.len() < validation.givens);
+    }
+
+    #[test]
+    fn test_validate_duplicates() {
+        let puzzle = format!("1{}", &MINIMAL[1..]).replacen("0", "1", 1);
+        let validation = validate(&puzzle).unwrap();
+        assert!(!validation.is_valid());
+        assert_eq!(validation.solutions, None);
+        assert_eq!(validation.redundant_givens, None);
+        let row = &validation.duplicates[0];
+        assert_eq!((row.unit.clone(), row.index, row.num), (Unit::Row, 0, 1));
+        assert_eq!(row.cells, vec![Coord::new(0, 0), Coord::new(0, 1)]);
+        assert!(
+            validation
+                .to_string()
+                .contains("Duplicate 1 in row 1: r1c1, r1c2")
+        );
+    }
+
+    #[test]
+    fn test_validate_solutions() {
+        let validation = validate(&format!("{}1", "0".repeat(80))).unwrap();
+        assert_eq!(validation.solutions, Some(2));
+        assert!(!validation.is_valid());
+        assert!(!validation.is_minimal());
+        let unsolvable = format!("12345678{}9{}", "0".repeat(9), "0".repeat(63));
+        assert_eq!(validate(&unsolvable).unwrap().solutions, Some(0));
+    }
+
+    #[test]
+    fn test_validate_parse_errors() {
+        let puzzle = format!("{}x{}?", &MINIMAL[..8], &MINIMAL[9..80]);
+        match validate(&puzzle) {
+            Err(SudokuError::Parse(message)) => {
+                assert_eq!(
+                    message,
+                    "invalid cells 'x' at position 9, '?' at position 81"
+                )
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        let spaced = MINIMAL.replace("000", "000 ");
+        assert!(validate(&spaced).is_ok());
+        assert!(matches!(
+            validate(&MINIMAL[1..]),
+            Err(SudokuError::Parse(_))
+        ));
+        match validate(&format!("x{}", &MINIMAL[2..])) {
+            Err(SudokuError::Parse(message)) => {
+                assert_eq!(
+                    message,
+                    "must contain exactly 36, 81 or 256 cells, found 80; \
+                     invalid cells 'x' at position 1"
+                )
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
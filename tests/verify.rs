@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Coord, Mismatch, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    fn solution() -> String {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        sudoku.solve_by_backtracking();
+        sudoku.serialized()
+    }
+
+    #[test]
+    fn test_is_valid_solution() {
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert!(!sudoku.is_valid_solution());
+        sudoku.solve_by_backtracking();
+        assert!(sudoku.is_valid_solution());
+        // Swapping two digits of a row keeps the grid filled, but breaks the columns
+        let (a, b) = (sudoku.board[0][0], sudoku.board[0][1]);
+        sudoku.board[0][0] = b;
+        sudoku.board[0][1] = a;
+        assert!(sudoku.is_solved());
+        assert!(!sudoku.is_valid_solution());
+        // A grid of ones is filled as well
+        let ones = Sudoku::from_string(&"1".repeat(81));
+        assert!(ones.is_solved());
+        assert!(!ones.is_valid_solution());
+    }
+
+    #[test]
+    fn test_verify_against() {
+        let solution = solution();
+        let mut sudoku = Sudoku::from_string(PUZZLE);
+        assert_eq!(
+            sudoku.verify_against(&solution),
+            Err(Mismatch::Empty(Coord::new(0, 0)))
+        );
+        sudoku.solve_by_backtracking();
+        assert_eq!(sudoku.verify_against(&solution), Ok(()));
+
+        let found = sudoku.board[8][8] % 9 + 1;
+        sudoku.board[8][8] = found;
+        assert!(matches!(
+            sudoku.verify_against(&solution),
+            Err(Mismatch::Digit { cell, found: f, .. }) if cell == Coord::new(8, 8) && f == found
+        ));
+    }
+
+    #[test]
+    fn test_invalid_solutions() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        assert!(matches!(
+            sudoku.verify_against("12x"),
+            Err(Mismatch::Parse(_))
+        ));
+        assert_eq!(
+            sudoku.verify_against(&"1".repeat(36)),
+            Err(Mismatch::Size {
+                expected: 9,
+                found: 6
+            })
+        );
+        assert_eq!(
+            sudoku.verify_against(&"1".repeat(81)),
+            Err(Mismatch::InvalidSolution)
+        );
+        assert_eq!(
+            sudoku.verify_against(PUZZLE),
+            Err(Mismatch::InvalidSolution)
+        );
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{SolveOutcome, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_walkthrough() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let text = sudoku.walkthrough_markdown();
+        assert!(text.starts_with("# Walkthrough\n"));
+        assert!(text.contains(PUZZLE));
+        // The Sudoku itself isn't changed
+        assert_eq!(sudoku.serialized(), PUZZLE);
+
+        let mut solved = sudoku.clone();
+        assert_eq!(solved.solve_human_like(), SolveOutcome::Solved);
+        let steps = text.matches("\n## Step ").count();
+        assert!(steps >= PUZZLE.matches('0').count());
+        assert!(text.contains("\n## Step 1: Hidden Single\n\nHidden Single in Box 2: "));
+        // One grid for the start and one per step
+        assert_eq!(text.matches("```text\n").count(), steps + 1);
+        // Each empty cell is marked as placed once, besides the legend
+        assert_eq!(
+            text.matches('*').count(),
+            2 * PUZZLE.matches('0').count() + 2
+        );
+        assert!(text.contains(&format!(
+            "Solved in {} steps. Difficulty {:.2}",
+            steps,
+            solved.difficulty()
+        )));
+        assert!(text.ends_with("hardest strategy: Claiming Pair.\n"));
+        let last_grid = text.rsplit("```text\n").next().unwrap();
+        for row in solved.board.iter().take(3) {
+            let digits: Vec<String> = row.iter().map(ToString::to_string).collect();
+            assert!(last_grid.contains(&digits[..3].join("  ")), "{}", last_grid);
+        }
+    }
+
+    #[test]
+    fn test_walkthrough_stuck() {
+        let sudoku = Sudoku::from_string(
+            "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+        );
+        let text = sudoku.walkthrough_markdown();
+        assert!(text.contains("\n## Result\n\nThe strategies get stuck after "));
+        assert!(text.contains("cells left empty."));
+    }
+}
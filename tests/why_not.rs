@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use rate_my_sudoku::{Candidate, Coord, Explanation, Sudoku};
+
+    const PUZZLE: &str =
+        "008000063030000000000047120006000000001830400000901700000408031000500204200000000";
+
+    #[test]
+    fn test_why_not_placed_digits() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        assert!(matches!(
+            sudoku.why_not(0, 2, 5),
+            Some(Explanation::Filled(8))
+        ));
+        assert!(sudoku.why_not(0, 2, 8).is_none());
+        // r1c8 holds a 6
+        assert!(matches!(
+            sudoku.why_not(0, 0, 6),
+            Some(Explanation::SeenFrom(coord)) if coord == Coord::new(0, 7)
+        ));
+    }
+
+    #[test]
+    fn test_why_not_by_steps() {
+        let sudoku = Sudoku::from_string(PUZZLE);
+        let mut solved = sudoku.clone();
+        solved.solve_by_backtracking();
+        let mut explained = 0;
+        for Coord { row, col } in sudoku.empty_cells().take(10) {
+            let solution = solved.get_num(row, col);
+            assert!(sudoku.why_not(row, col, solution).is_none());
+            for num in (1..=9).filter(|&num| num != solution) {
+                if let Some(Explanation::Steps(steps)) = sudoku.why_not(row, col, num) {
+                    let last = steps.last().unwrap();
+                    assert!(
+                        last.removals
+                            .candidates_about_to_be_removed
+                            .contains(&Candidate { row, col, num })
+                    );
+                    explained += 1;
+                }
+            }
+        }
+        assert!(explained > 0);
+    }
+}